@@ -0,0 +1,5 @@
+-- Migration: 006_add_live_until_to_projects
+-- Purpose: Track the ledger until which a project's storage entries are guaranteed
+-- live, as reported by the contract's `project_bumped` event.
+
+ALTER TABLE projects ADD COLUMN live_until_ledger INTEGER;
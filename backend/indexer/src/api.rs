@@ -130,6 +130,18 @@ pub struct TopProjectsQuery {
     pub limit: Option<u32>,
 }
 
+#[derive(Deserialize)]
+pub struct ArchivalRiskQuery {
+    pub within_ledgers: Option<i64>,
+}
+
+#[derive(Serialize)]
+pub struct ArchivalRiskResponse {
+    pub current_ledger: i64,
+    pub count: usize,
+    pub projects: Vec<db::ArchivalRisk>,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct TopProjectsResponse {
     pub count: usize,
@@ -536,6 +548,53 @@ pub async fn get_active_projects_count(State(state): State<Arc<ApiState>>) -> im
     }
 }
 
+/// `GET /projects/archival-risk?within_ledgers=120960`
+///
+/// Returns projects whose contract storage is estimated to be archived within
+/// `within_ledgers` (default: 7 days) of the last indexed ledger, so keepers
+/// know which projects need a `bump_project` pass.
+pub async fn get_archival_risk(
+    State(state): State<Arc<ApiState>>,
+    Query(query): Query<ArchivalRiskQuery>,
+) -> impl IntoResponse {
+    let within = query
+        .within_ledgers
+        .unwrap_or(7 * 17_280)
+        .clamp(0, db::PERSISTENT_TTL_LEDGERS);
+
+    let current_ledger = match db::get_last_ledger(&state.pool).await {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!(ErrorResponse {
+                    error: e.to_string()
+                })),
+            )
+                .into_response()
+        }
+    };
+
+    match db::get_archival_risk(&state.pool, current_ledger, within).await {
+        Ok(projects) => (
+            StatusCode::OK,
+            Json(ArchivalRiskResponse {
+                current_ledger,
+                count: projects.len(),
+                projects,
+            }),
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!(ErrorResponse {
+                error: e.to_string()
+            })),
+        )
+            .into_response(),
+    }
+}
+
 /// `GET /stats`
 ///
 /// Returns pre-calculated global protocol statistics.
@@ -151,6 +151,23 @@ pub async fn insert_events_with_new(
                             .execute(pool)
                             .await?;
                     }
+                    "project_bumped" => {
+                        let live_until = ev
+                            .extra_data
+                            .as_deref()
+                            .and_then(|v| v.parse::<i64>().ok());
+                        sqlx::query(
+                            r#"
+                            UPDATE projects
+                            SET live_until_ledger = MAX(COALESCE(live_until_ledger, 0), ?2)
+                            WHERE project_id = ?1
+                            "#,
+                        )
+                        .bind(id)
+                        .bind(live_until)
+                        .execute(pool)
+                        .await?;
+                    }
                     _ => {}
                 }
             }
@@ -210,7 +227,7 @@ pub async fn update_global_stats(pool: &SqlitePool, event: &PifpEvent) -> Result
                 .execute(&mut *tx)
                 .await?;
         }
-        "project_expired" | "project_cancelled" => {
+        "project_expired" | "project_cancelled" | "project_failed" => {
             sqlx::query("UPDATE project_stats SET failed_projects = failed_projects + 1, updated_at = strftime('%s', 'now') WHERE id = 1")
                 .execute(&mut *tx)
                 .await?;
@@ -479,7 +496,8 @@ pub async fn get_active_projects_count(pool: &SqlitePool) -> Result<i64> {
                   'project_active',
                   'project_verified',
                   'project_expired',
-                  'project_cancelled'
+                  'project_cancelled',
+                  'project_failed'
               )
         ),
         ranked AS (
@@ -546,6 +564,53 @@ pub async fn list_projects(
     Ok(rows)
 }
 
+/// Ledgers a persistent contract entry stays live after it is written or
+/// bumped (30 days), mirroring `PERSISTENT_BUMP_AMOUNT` in the contract.
+pub const PERSISTENT_TTL_LEDGERS: i64 = 30 * 17_280;
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ArchivalRisk {
+    pub project_id: String,
+    pub status: String,
+    pub live_until_ledger: i64,
+    pub ledgers_remaining: i64,
+}
+
+/// List projects whose contract storage is estimated to be archived within
+/// `within_ledgers` of `current_ledger`, soonest first.
+///
+/// A project is assumed live until the ledger reported by its latest
+/// `project_bumped` event or, if it was never bumped, one full TTL after
+/// registration. Entries touched by later activity may live longer, so the
+/// estimate errs on the side of flagging a project. Completed projects hold
+/// no funds and are skipped.
+pub async fn get_archival_risk(
+    pool: &SqlitePool,
+    current_ledger: i64,
+    within_ledgers: i64,
+) -> Result<Vec<ArchivalRisk>> {
+    let rows = sqlx::query_as::<_, ArchivalRisk>(
+        r#"
+        SELECT project_id, status, live_until_ledger,
+               live_until_ledger - ?1 AS ledgers_remaining
+        FROM (
+            SELECT project_id, status,
+                   COALESCE(live_until_ledger, created_ledger + ?3) AS live_until_ledger
+            FROM projects
+            WHERE status != 'Completed'
+        )
+        WHERE live_until_ledger <= ?1 + ?2
+        ORDER BY live_until_ledger ASC, project_id ASC
+        "#,
+    )
+    .bind(current_ledger)
+    .bind(within_ledgers)
+    .bind(PERSISTENT_TTL_LEDGERS)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
 /// Fetch project history with pagination.
 pub async fn get_project_history(
     pool: &SqlitePool,
@@ -678,6 +743,7 @@ mod tests {
                 timestamp INTEGER NOT NULL,
                 contract_id TEXT NOT NULL,
                 tx_hash TEXT,
+                extra_data TEXT,
                 created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now'))
             );",
         )
@@ -755,6 +821,10 @@ mod tests {
             "CREATE TABLE unique_donors (address TEXT PRIMARY KEY, created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now')));",
         ).execute(&pool).await.unwrap();
 
+        sqlx::query(
+            "CREATE TABLE projects (project_id TEXT PRIMARY KEY, creator TEXT NOT NULL, status TEXT NOT NULL DEFAULT 'Funding', goal TEXT NOT NULL, primary_token TEXT NOT NULL, created_ledger INTEGER NOT NULL, created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now')), live_until_ledger INTEGER);",
+        ).execute(&pool).await.unwrap();
+
         pool
     }
 
@@ -856,6 +926,46 @@ mod tests {
         assert_eq!(count, 1);
     }
 
+    #[tokio::test]
+    async fn test_get_archival_risk() {
+        let pool = setup_test_db().await;
+        for (id, status, created_ledger) in [
+            ("1", "Funding", 100i64),
+            ("2", "Active", 100i64),
+            ("3", "Completed", 100i64),
+            ("4", "Funding", 400_000i64),
+        ] {
+            sqlx::query("INSERT INTO projects (project_id, creator, status, goal, primary_token, created_ledger) VALUES (?1, 'c', ?2, '100', 't', ?3)")
+                .bind(id)
+                .bind(status)
+                .bind(created_ledger)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+        let bumped = PifpEvent {
+            event_type: "project_bumped".to_string(),
+            project_id: Some("2".to_string()),
+            actor: None,
+            amount: None,
+            ledger: 300_000,
+            timestamp: 0,
+            contract_id: "c".to_string(),
+            tx_hash: Some("TX_BUMP".to_string()),
+            extra_data: Some((300_000 + PERSISTENT_TTL_LEDGERS).to_string()),
+        };
+        insert_events_with_new(&pool, &[bumped]).await.unwrap();
+
+        // Project 1 was never bumped and runs out one TTL after registration;
+        // project 2 was bumped, project 3 is completed and project 4 is recent.
+        let current = PERSISTENT_TTL_LEDGERS - 1_000;
+        let risk = get_archival_risk(&pool, current, 2_000).await.unwrap();
+        assert_eq!(risk.len(), 1);
+        assert_eq!(risk[0].project_id, "1");
+        assert_eq!(risk[0].live_until_ledger, 100 + PERSISTENT_TTL_LEDGERS);
+        assert_eq!(risk[0].ledgers_remaining, 1_100);
+    }
+
     #[tokio::test]
     async fn test_create_and_list_webhooks() {
         let pool = setup_test_db().await;
@@ -21,11 +21,13 @@ pub enum EventKind {
     ProjectExpired,
     /// A project was manually cancelled (`cancelled` topic).
     ProjectCancelled,
+    /// Oracles rejected a project's proof of impact (`failed` topic).
+    ProjectFailed,
     /// Verified funds were released to the creator (`released` topic).
     FundsReleased,
     /// Donator funds were refunded from an expired project (`refunded` topic).
     DonatorRefunded,
-    /// A role was granted or replaced (`role_set` topic).
+    /// A role was granted or renewed (`role_set` topic).
     RoleSet,
     /// A role was revoked (`role_del` topic).
     RoleDel,
@@ -33,6 +35,8 @@ pub enum EventKind {
     ProtocolPaused,
     /// Protocol was unpaused (`unpaused` topic).
     ProtocolUnpaused,
+    /// A TTL maintenance pass covered all of a project's entries (`project_bumped` topic).
+    ProjectBumped,
     /// An event from this contract that we don't recognise yet.
     Unknown,
 }
@@ -47,12 +51,14 @@ impl EventKind {
             "verified" => Self::ProjectVerified,
             "expired" => Self::ProjectExpired,
             "cancelled" => Self::ProjectCancelled,
+            "failed" => Self::ProjectFailed,
             "released" => Self::FundsReleased,
             "refunded" => Self::DonatorRefunded,
             "role_set" => Self::RoleSet,
             "role_del" => Self::RoleDel,
             "paused" => Self::ProtocolPaused,
             "unpaused" => Self::ProtocolUnpaused,
+            "project_bumped" => Self::ProjectBumped,
             _ => Self::Unknown,
         }
     }
@@ -66,12 +72,14 @@ impl EventKind {
             Self::ProjectVerified => "project_verified",
             Self::ProjectExpired => "project_expired",
             Self::ProjectCancelled => "project_cancelled",
+            Self::ProjectFailed => "project_failed",
             Self::FundsReleased => "funds_released",
             Self::DonatorRefunded => "donator_refunded",
             Self::RoleSet => "role_set",
             Self::RoleDel => "role_del",
             Self::ProtocolPaused => "protocol_paused",
             Self::ProtocolUnpaused => "protocol_unpaused",
+            Self::ProjectBumped => "project_bumped",
             Self::Unknown => "unknown",
         }
     }
@@ -90,6 +90,7 @@ async fn main() -> anyhow::Result<()> {
         .route("/projects", get(api::get_projects))
         .route("/projects/:id/history", get(api::get_project_history_paged))
         .route("/projects/top", get(api::get_top_projects))
+        .route("/projects/archival-risk", get(api::get_archival_risk))
         .route(
             "/projects/active/count",
             get(api::get_active_projects_count),
@@ -25,12 +25,14 @@ const PIFP_TOPIC_SYMBOLS: &[&str] = &[
     "verified",
     "expired",
     "cancelled",
+    "failed",
     "released",
     "refunded",
     "role_set",
     "role_del",
     "paused",
     "unpaused",
+    "project_bumped",
 ];
 
 // ─────────────────────────────────────────────────────────
@@ -310,6 +312,11 @@ fn decode_data(
             let actor = extract_field(value, &["cancelled_by", "address"]);
             (actor, None, None)
         }
+        EventKind::ProjectFailed => {
+            let actor = extract_field(value, &["oracle", "address"]);
+            let extra = extract_field(value, &["reason_hash"]);
+            (actor, None, extra)
+        }
         EventKind::ProjectVerified => {
             let actor = extract_field(value, &["oracle", "verifier", "address"]);
             let extra = extract_field(value, &["proof_hash", "hash", "data"]);
@@ -349,6 +356,10 @@ fn decode_data(
                 .or_else(|| extract_field(value, &["address"]));
             (actor, None, None)
         }
+        EventKind::ProjectBumped => {
+            let extra = extract_field(value, &["live_until_ledger"]);
+            (None, None, extra)
+        }
         EventKind::Unknown => (None, None, None),
     }
 }
@@ -446,6 +457,7 @@ mod tests {
             EventKind::from_topic("verified"),
             EventKind::ProjectVerified
         );
+        assert_eq!(EventKind::from_topic("failed"), EventKind::ProjectFailed);
         assert_eq!(EventKind::from_topic("released"), EventKind::FundsReleased);
         assert_eq!(
             EventKind::from_topic("refunded"),
@@ -458,6 +470,10 @@ mod tests {
             EventKind::from_topic("unpaused"),
             EventKind::ProtocolUnpaused
         );
+        assert_eq!(
+            EventKind::from_topic("project_bumped"),
+            EventKind::ProjectBumped
+        );
         assert_eq!(EventKind::from_topic("something_else"), EventKind::Unknown);
     }
 
@@ -560,6 +576,31 @@ mod tests {
         assert_eq!(ev.amount.as_deref(), Some("750"));
     }
 
+    #[test]
+    fn decode_project_bumped_event() {
+        let raw = RawEvent {
+            topic: vec![
+                r#"{"type":"symbol","value":"project_bumped"}"#.to_string(),
+                r#"{"type":"u64","value":"7"}"#.to_string(),
+            ],
+            value: serde_json::json!({ "project_id": 7, "live_until_ledger": 620_000 }),
+            contract_id: Some("CONTRACT1".to_string()),
+            tx_hash: Some("TX4".to_string()),
+            id: None,
+            ledger: Some(101_600),
+            ledger_closed_at: Some("2024-01-01T00:00:03Z".to_string()),
+            in_successful_contract_call: Some(true),
+            paging_token: None,
+        };
+
+        let events = decode_events(&[raw], &["CONTRACT1".to_string()]);
+        assert_eq!(events.len(), 1);
+        let ev = &events[0];
+        assert_eq!(ev.event_type, "project_bumped");
+        assert_eq!(ev.project_id.as_deref(), Some("7"));
+        assert_eq!(ev.extra_data.as_deref(), Some("620000"));
+    }
+
     #[test]
     fn parse_iso_timestamp() {
         let ts = parse_iso_to_unix("2024-01-01T00:00:00Z").unwrap();
@@ -9,15 +9,15 @@
 //! | Code | Variant                  | Typical trigger                                             |
 //! |------|--------------------------|-------------------------------------------------------------|
 //! |  1   | `ProjectNotFound`        | Querying or operating on a project ID that does not exist   |
-//! |  2   | `MilestoneNotFound`      | Reserved for future milestone-level operations              |
-//! |  3   | `MilestoneAlreadyReleased` | Calling `verify_and_release` on an already-completed project |
+//! |  2   | `MilestoneNotFound`      | `verify_milestone` called with an index past the project's milestone list |
+//! |  3   | `MilestoneAlreadyReleased` | Releasing an already-completed project or an already-released milestone |
 //! |  4   | `InsufficientBalance`    | Refund requested but donator has zero balance for that token |
-//! |  5   | `InvalidMilestones`      | Reserved for future milestone validation                    |
+//! |  5   | `InvalidMilestones`      | Milestone shares do not sum to 10 000 bps, or wrong release entry point |
 //! |  6   | `NotAuthorized`          | Caller lacks the RBAC role required for the operation       |
 //! |  7   | `InvalidGoal`            | Goal is ≤ 0 or exceeds the 10^30 upper bound               |
 //! |  8   | `AlreadyInitialized`     | `init` called more than once                                |
-//! |  9   | `RoleNotFound`           | Reserved for role-query edge cases                          |
-//! | 10   | `TooManyTokens`          | `accepted_tokens` list exceeds the 10-token cap             |
+//! |  9   | `RoleNotFound`           | Role renewed for an address that does not hold it           |
+//! | 10   | `TooManyTokens`          | `accepted_tokens` over 10, or token registry full (50)      |
 //! | 11   | `InvalidAmount`          | Deposit or transfer amount is ≤ 0                           |
 //! | 12   | `DuplicateToken`         | `accepted_tokens` contains the same address twice           |
 //! | 13   | `InvalidDeadline`        | Deadline is in the past or more than 5 years in the future  |
@@ -33,10 +33,57 @@
 //! | 23   | `TokenNotAccepted`       | Deposit attempted with a token not in the project's accepted list |
 //! | 24   | `RefundWindowActive`     | Creator tried to reclaim funds before the 6-month refund window expired |
 //! | 25   | `RefundWindowExpired`    | Donor tried to refund after the 6-month refund window expired |
-//! | 24   | `ProtocolNotInitialized` | Contract state has not been initialized                     |
-//! | 25   | `ReleaseAmountExceedsBalance` | The requested release amount exceeds the project's current on-chain balance |
 //! | 26   | `MetadataCidInvalid`     | IPFS CID byte string was empty or exceeded max length       |
 //! | 27   | `FeeBpsExceedsMaximum`   | Configured fee in basis points exceeds the 10_000 hard cap  |
+//! | 28   | `AlreadyAttested`        | Oracle attested the same proof hash for a project twice     |
+//! | 29   | `InvalidQuorum`          | Oracle quorum set to zero                                   |
+//! | 30   | `OracleKeyNotSet`        | Signed attestation relayed for an oracle with no registered key |
+//! | 31   | `NonceAlreadyUsed`       | Signed attestation nonce was already consumed by that oracle |
+//! | 32   | `AttestationExpired`     | Signed attestation relayed after its expiry timestamp       |
+//! | 33   | `CommitmentExists`       | `deposit_committed` reused a commitment already stored for the project |
+//! | 34   | `CommitmentNotFound`     | Reveal or refund of a commitment that is not stored         |
+//! | 35   | `InvalidCommitment`      | Owner address and salt do not hash to the commitment        |
+//! | 36   | `InvalidTokenRate`       | Token conversion rate is zero or negative                   |
+//! | 37   | `NoPendingUpgrade`       | Upgrade executed or cancelled without a proposal            |
+//! | 38   | `UpgradeAlreadyPending`  | Upgrade proposed while another one is pending               |
+//! | 39   | `TimelockNotElapsed`     | Timelocked action executed before its ETA                   |
+//! | 40   | `MigrationPending`       | Storage must be migrated to the current schema first        |
+//! | 41   | `ProposalNotFound`       | Governance proposal does not exist or was already executed/cancelled |
+//! | 42   | `TimelockRequired`       | Change must be queued through `propose_action`              |
+//! | 43   | `InvalidCouncil`         | Council signers empty, duplicated or too many, or bad threshold |
+//! | 44   | `AlreadyApproved`        | Council signer approved the same proposal twice             |
+//! | 45   | `ThresholdNotMet`        | Council proposal executed with too few signer approvals     |
+//! | 46   | `WithdrawalLocked`       | Pledge withdrawn after the project reached its lock-in point |
+//! | 47   | `InvalidRound`           | Matching round times, projects or token are invalid         |
+//! | 48   | `RoundNotFound`          | No matching round with this ID                              |
+//! | 49   | `RoundClosed`            | Round already ended or was finalized                        |
+//! | 50   | `RoundNotEnded`          | Round finalized before its end time                         |
+//! | 51   | `PledgeNotFound`         | No recurring pledge with this ID                            |
+//! | 52   | `InvalidPledge`          | Pledge period or number of periods is zero                  |
+//! | 53   | `BelowMinDonation`       | Donation smaller than the project's minimum                 |
+//! | 54   | `DonorCapExceeded`       | Donation would exceed the project's per-donor limit         |
+//! | 55   | `HardCapReached`         | Donation would exceed the project's hard cap                |
+//! | 56   | `InvalidDonationLimits`  | Negative limit, hard cap below goal, or cap below minimum   |
+//! | 57   | `DonationLimitsLocked`   | Limits changed after the project received donations         |
+//! | 58   | `InvalidFeeSplits`       | Fee split shares zero, too many, or not summing to 10 000   |
+//! | 59   | `ReleasePending`         | Verification attempted while a release awaits its dispute window |
+//! | 60   | `NoPendingRelease`       | Dispute or finalization of a project with no queued release |
+//! | 61   | `DisputeWindowClosed`    | Dispute filed after the dispute window ended                |
+//! | 62   | `DisputeWindowOpen`      | Release finalized before the dispute window ended           |
+//! | 63   | `InvalidDisputeWindow`   | Dispute window longer than 30 days                          |
+//! | 64   | `ProjectFrozen`          | Deposit or verification attempted on a frozen project       |
+//! | 65   | `InvalidBeneficiaries`   | Beneficiary shares empty, zero, too many or not summing to 10 000 |
+//! | 66   | `NoPendingTransfer`      | Ownership accepted without a matching pending transfer      |
+//! | 67   | `InvalidRoleExpiry`      | Role expiry not in the future, or set on a SuperAdmin grant |
+//! | 68   | `WhitelistBatchTooLarge` | Whitelist batch with more than 50 addresses                 |
+//! | 69   | `TokenNotSupported`      | Token missing from the protocol's token registry            |
+//! | 70   | `DeadlineTooLong`        | Deadline extension beyond the 1-year limit                  |
+//! | 71   | `InvalidFeeBasisPoints`  | Protocol fee above the 10 % maximum                         |
+//! | 72   | `NotWhitelisted`         | Donor not on the project's whitelist                        |
+//! | 73   | `ProtocolNotInitialized` | Contract state has not been initialized                     |
+//! | 74   | `ReleaseAmountExceedsBalance` | The requested release amount exceeds the project's current on-chain balance |
+//! | 75   | `FundingClosed`          | Donation made after a milestone of the project was released |
+//! | 76   | `CouncilProposalExpired` | Council proposal approved or executed after it expired      |
 
 use soroban_sdk::contracterror;
 
@@ -52,16 +99,18 @@ pub enum Error {
     /// The requested project ID does not exist in storage.
     ProjectNotFound = 1,
 
-    /// Reserved — will be used when milestone-level operations are added.
+    /// The requested milestone index does not exist for this project.
     MilestoneNotFound = 2,
 
-    /// `verify_and_release` was called on a project that is already `Completed`.
+    /// The project is already `Completed`, or the milestone was already released.
     MilestoneAlreadyReleased = 3,
 
     /// The donator has no refundable balance for the requested token.
     InsufficientBalance = 4,
 
-    /// Reserved — will be used for milestone-count validation.
+    /// The milestone list is too long, has a zero share, or its shares do not
+    /// sum to 10 000 bps; also returned when `verify_and_release` is called on
+    /// a milestone project.
     InvalidMilestones = 5,
 
     /// The caller does not hold the RBAC role required for this operation.
@@ -73,10 +122,11 @@ pub enum Error {
     /// `init` has already been called; the SuperAdmin is already set.
     AlreadyInitialized = 8,
 
-    /// Reserved — the queried address holds no RBAC role.
+    /// The address holds no unexpired grant of the role being renewed.
     RoleNotFound = 9,
 
-    /// The `accepted_tokens` list exceeds the maximum of 10 tokens.
+    /// The `accepted_tokens` list exceeds the maximum of 10 tokens, or the
+    /// token registry already holds its maximum of 50 tokens.
     TooManyTokens = 10,
 
     /// A deposit or transfer amount is ≤ 0.
@@ -119,11 +169,11 @@ pub enum Error {
     TokenNotAccepted = 23,
 
     /// The new deadline exceeds the 1-year extension limit.
-    DeadlineTooLong = 24,
+    DeadlineTooLong = 70,
     /// Fee basis points exceed the maximum allowed (10%).
-    InvalidFeeBasisPoints = 25,
+    InvalidFeeBasisPoints = 71,
     /// Address is not on the project's whitelist.
-    NotWhitelisted = 26,
+    NotWhitelisted = 72,
     /// The donor refund window is still active; creator cannot reclaim yet.
     RefundWindowActive = 24,
 
@@ -131,10 +181,10 @@ pub enum Error {
     RefundWindowExpired = 25,
     /// A method that requires the protocol to be initialised was called before
     /// `initialize()` had been executed on this contract instance.
-    ProtocolNotInitialized = 24,
+    ProtocolNotInitialized = 73,
 
     /// The requested release amount exceeds the project's current on-chain balance.
-    ReleaseAmountExceedsBalance = 25,
+    ReleaseAmountExceedsBalance = 74,
 
     /// The supplied IPFS CID byte string was either empty or exceeded the
     /// maximum allowed length (`MAX_CID_LEN` = 64 bytes).
@@ -142,4 +192,151 @@ pub enum Error {
 
     /// The proposed fee in basis points exceeds the hard cap of 10 000 (= 100 %).
     FeeBpsExceedsMaximum = 27,
+
+    /// The oracle has already attested this proof hash for the project.
+    AlreadyAttested = 28,
+
+    /// The oracle quorum must be at least 1.
+    InvalidQuorum = 29,
+
+    /// The oracle has no ed25519 public key registered for signed attestations.
+    OracleKeyNotSet = 30,
+
+    /// The signed attestation's nonce has already been used by this oracle.
+    NonceAlreadyUsed = 31,
+
+    /// The signed attestation's expiry timestamp has passed.
+    AttestationExpired = 32,
+
+    /// A committed donation with this commitment already exists for the project.
+    CommitmentExists = 33,
+
+    /// No committed donation is stored under this commitment.
+    CommitmentNotFound = 34,
+
+    /// `sha256(owner.to_xdr() ‖ salt)` does not match the commitment.
+    InvalidCommitment = 35,
+
+    /// A token conversion rate must be strictly positive.
+    InvalidTokenRate = 36,
+
+    /// No contract upgrade has been proposed.
+    NoPendingUpgrade = 37,
+
+    /// An upgrade is already pending; cancel it before proposing another.
+    UpgradeAlreadyPending = 38,
+
+    /// The timelock delay of the action has not elapsed yet.
+    TimelockNotElapsed = 39,
+
+    /// Stored data predates the current schema; call `migrate` first.
+    MigrationPending = 40,
+
+    /// No queued governance proposal with this ID exists.
+    ProposalNotFound = 41,
+
+    /// The change can no longer be applied directly; queue it with `propose_action`.
+    TimelockRequired = 42,
+
+    /// Council signers must be 1–20 unique addresses with 1 ≤ threshold ≤ signers.
+    InvalidCouncil = 43,
+
+    /// This signer already approved the council proposal.
+    AlreadyApproved = 44,
+
+    /// The council proposal has fewer approvals than the threshold.
+    ThresholdNotMet = 45,
+
+    /// The project is no longer `Funding`, released a milestone, or has
+    /// raised 90 % of its goal; pledges can no longer be withdrawn.
+    WithdrawalLocked = 46,
+
+    /// Round must end after it starts and list 1–20 unique, existing,
+    /// non-terminal projects that accept the round token and are not in
+    /// another open round.
+    InvalidRound = 47,
+
+    /// No matching round with this ID exists.
+    RoundNotFound = 48,
+
+    /// The round has ended or was already finalized.
+    RoundClosed = 49,
+
+    /// The round cannot be finalized before its end time.
+    RoundNotEnded = 50,
+
+    /// No recurring pledge with this ID exists (it may have completed,
+    /// lapsed or been cancelled).
+    PledgeNotFound = 51,
+
+    /// A recurring pledge needs a non-zero period and number of periods.
+    InvalidPledge = 52,
+
+    /// The donation is smaller than the project's `min_donation`.
+    BelowMinDonation = 53,
+
+    /// The donor's balance in this token would exceed `max_per_donor`.
+    /// Also returned for committed deposits, whose donor cannot be
+    /// checked, on projects with a per-donor limit.
+    DonorCapExceeded = 54,
+
+    /// The project's funded value would exceed its `hard_cap` under the
+    /// `Reject` policy, or the cap is already reached.
+    HardCapReached = 55,
+
+    /// Limits must be non-negative, `hard_cap` at least `goal` and
+    /// `max_per_donor` at least `min_donation` when both are set.
+    InvalidDonationLimits = 56,
+
+    /// Donation limits can only be changed before the first donation.
+    DonationLimitsLocked = 57,
+
+    /// Fee splits must have 1–10 entries with non-zero shares summing to
+    /// exactly 10 000 bps (or be empty).
+    InvalidFeeSplits = 58,
+
+    /// A verified release is waiting out its dispute window; no further
+    /// attestations are accepted until it is finalized or disputed.
+    ReleasePending = 59,
+
+    /// The project has no release waiting for its dispute window.
+    NoPendingRelease = 60,
+
+    /// The dispute window of the queued release has ended.
+    DisputeWindowClosed = 61,
+
+    /// The queued release cannot be finalized before its dispute window ends.
+    DisputeWindowOpen = 62,
+
+    /// The dispute window may not exceed 30 days.
+    InvalidDisputeWindow = 63,
+
+    /// The project is frozen; deposits and verification are halted.
+    ProjectFrozen = 64,
+
+    /// Beneficiaries must have 1–10 entries with non-zero shares summing to
+    /// exactly 10 000 bps (or be empty).
+    InvalidBeneficiaries = 65,
+
+    /// No ownership transfer to the caller is pending for the project.
+    NoPendingTransfer = 66,
+
+    /// A role expiry must be a future ledger sequence, and SuperAdmin grants
+    /// cannot expire.
+    InvalidRoleExpiry = 67,
+
+    /// A whitelist batch may hold at most 50 addresses.
+    WhitelistBatchTooLarge = 68,
+
+    /// The token is not in the protocol's token registry, so it cannot be
+    /// listed by a project or donated.
+    TokenNotSupported = 69,
+
+    /// The project has released a milestone and no longer accepts donations,
+    /// as refunds only return the unreleased share of each balance.
+    FundingClosed = 75,
+
+    /// The council proposal outlived `COUNCIL_PROPOSAL_LIFETIME` and can only
+    /// be cancelled.
+    CouncilProposalExpired = 76,
 }
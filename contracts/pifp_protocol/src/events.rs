@@ -2,7 +2,8 @@
 
 use soroban_sdk::{contractevent, contracttype, symbol_short, Address, BytesN, Env};
 
-#[contractevent]
+use crate::types::ProtocolConfig;
+
 // ── Event Data Structs ──────────────────────────────────────────────
 //
 // Each event uses a dedicated struct so that indexers can decode every
@@ -10,8 +11,7 @@ use soroban_sdk::{contractevent, contracttype, symbol_short, Address, BytesN, En
 // Topic layout: (event_symbol, project_id) for project-scoped events,
 // (event_symbol, caller) for protocol-level events.
 
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[contractevent]
 pub struct ProjectCreated {
     pub project_id: u64,
     pub creator: Address,
@@ -26,76 +26,309 @@ pub struct ProjectFunded {
     pub amount: i128,
 }
 
+/// Emitted instead of `ProjectFunded` for committed donations; carries no
+/// donor address.
 #[contractevent]
-pub struct ProjectActive {
+pub struct DonationCommitted {
     pub project_id: u64,
+    pub commitment: BytesN<32>,
+    pub token: Address,
+    pub amount: i128,
 }
 
 #[contractevent]
-pub struct ProjectVerified {
+pub struct CommitmentRevealed {
     pub project_id: u64,
-    pub oracle: Address,
-    pub proof_hash: BytesN<32>,
+    pub commitment: BytesN<32>,
+    pub donator: Address,
+    pub token: Address,
+    pub amount: i128,
 }
 
 #[contractevent]
-pub struct ProjectExpired {
+pub struct CommittedRefunded {
     pub project_id: u64,
-    pub deadline: u64,
+    pub commitment: BytesN<32>,
+    pub amount: i128,
 }
 
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct DeadlineExtended {
-    pub project_id: u64,
-    pub old_deadline: u64,
-    pub new_deadline: u64,
+#[contractevent]
+pub struct TokenRateUpdated {
+    pub token: Address,
+    pub old_rate: Option<i128>,
+    pub new_rate: i128,
 }
 
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ProtocolConfigUpdated {
-    pub old_fee_recipient: Option<Address>,
-    pub old_fee_bps: u32,
-    pub new_fee_recipient: Address,
-    pub new_fee_bps: u32,
+#[contractevent]
+pub struct TokenSupported {
+    pub token: Address,
+    pub min_donation: i128,
+    pub decimals: u32,
 }
 
-use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env};
+#[contractevent]
+pub struct TokenUnsupported {
+    pub token: Address,
+}
 
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ProjectCreated {
+#[contractevent]
+pub struct UpgradeProposed {
+    pub wasm_hash: BytesN<32>,
+    pub eta: u64,
+    pub by: Address,
+}
+
+#[contractevent]
+pub struct UpgradeCancelled {
+    pub wasm_hash: BytesN<32>,
+    pub by: Address,
+}
+
+#[contractevent]
+pub struct UpgradeExecuted {
+    pub wasm_hash: BytesN<32>,
+    pub by: Address,
+}
+
+#[contractevent]
+pub struct SchemaMigrated {
+    pub from_version: u32,
+    pub to_version: u32,
+}
+
+#[contractevent]
+pub struct ProposalCreated {
+    pub proposal_id: u64,
+    pub action: crate::types::GovAction,
+    pub proposer: Address,
+    pub eta: u64,
+}
+
+#[contractevent]
+pub struct ProposalCancelled {
+    pub proposal_id: u64,
+    pub by: Address,
+}
+
+#[contractevent]
+pub struct ProposalExecuted {
+    pub proposal_id: u64,
+    pub action: crate::types::GovAction,
+}
+
+#[contractevent]
+pub struct CouncilExecuted {
+    pub proposal_id: u64,
+    pub action: crate::rbac::CouncilAction,
+}
+
+#[contractevent]
+pub struct PledgeWithdrawn {
     pub project_id: u64,
-    pub creator: Address,
+    pub donator: Address,
     pub token: Address,
-    pub goal: i128,
+    pub amount: i128,
 }
 
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ProjectFunded {
+#[contractevent]
+pub struct RoundCreated {
+    pub round_id: u64,
+    pub token: Address,
+    pub start: u64,
+    pub end: u64,
+    pub creator: Address,
+}
+
+#[contractevent]
+pub struct RoundFunded {
+    pub round_id: u64,
+    pub sponsor: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct MatchCredited {
+    pub round_id: u64,
+    pub project_id: u64,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct MatchReturned {
+    pub round_id: u64,
+    pub project_id: u64,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct RoundFinalized {
+    pub round_id: u64,
+    pub matched: i128,
+    pub returned: i128,
+}
+
+#[contractevent]
+pub struct PledgeCreated {
+    pub pledge_id: u64,
     pub project_id: u64,
     pub donator: Address,
+    pub token: Address,
     pub amount: i128,
+    pub period: u64,
+    pub max_periods: u32,
 }
 
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[contractevent]
+pub struct PledgeCancelled {
+    pub pledge_id: u64,
+    pub project_id: u64,
+}
+
+#[contractevent]
+pub struct PledgeCollected {
+    pub pledge_id: u64,
+    pub project_id: u64,
+    pub amount: i128,
+    pub periods: u32,
+}
+
+#[contractevent]
+pub struct PledgeLapsed {
+    pub pledge_id: u64,
+    pub project_id: u64,
+}
+
+#[contractevent]
+pub struct DonationLimitsSet {
+    pub project_id: u64,
+    pub limits: crate::types::DonationLimits,
+}
+
+#[contractevent]
+pub struct FeeSplitsUpdated {
+    pub splits: soroban_sdk::Vec<crate::types::FeeSplit>,
+}
+
+#[contractevent]
+pub struct ProjectFeeSet {
+    pub project_id: u64,
+    pub fee_bps: Option<u32>,
+}
+
+#[contractevent]
+pub struct ReleaseQueued {
+    pub project_id: u64,
+    pub milestone: Option<u32>,
+    pub release_after: u64,
+}
+
+#[contractevent]
+pub struct ReleaseDisputed {
+    pub project_id: u64,
+    pub auditor: Address,
+    pub reason_hash: BytesN<32>,
+    pub cancelled: bool,
+}
+
+#[contractevent]
+pub struct DisputeWindowUpdated {
+    pub window: u64,
+}
+
+#[contractevent]
+pub struct PauseFlagsUpdated {
+    pub flags: crate::types::PauseFlags,
+}
+
+#[contractevent]
+pub struct ProjectFrozen {
+    pub project_id: u64,
+    pub by: Address,
+}
+
+#[contractevent]
+pub struct ProjectUnfrozen {
+    pub project_id: u64,
+    pub by: Address,
+}
+
+#[contractevent]
+pub struct OwnershipTransferProposed {
+    pub project_id: u64,
+    pub owner: Address,
+    pub pending_owner: Address,
+}
+
+#[contractevent]
+pub struct OwnershipTransferred {
+    pub project_id: u64,
+    pub previous_owner: Address,
+    pub new_owner: Address,
+}
+
+#[contractevent]
+pub struct BeneficiariesSet {
+    pub project_id: u64,
+    pub beneficiaries: soroban_sdk::Vec<crate::types::Beneficiary>,
+}
+
+#[contractevent]
+pub struct MetadataUpdated {
+    pub project_id: u64,
+    pub version: u32,
+    pub metadata_uri: soroban_sdk::Bytes,
+    pub updated_by: Address,
+}
+
+#[contractevent]
+pub struct MerkleRootSet {
+    pub project_id: u64,
+    pub merkle_root: Option<soroban_sdk::BytesN<32>>,
+}
+
+/// Emitted when a `bump_project` pass has covered all of a project's entries.
+#[contractevent]
+pub struct ProjectBumped {
+    pub project_id: u64,
+    /// Ledger sequence until which every entry touched by the pass stays live.
+    pub live_until_ledger: u32,
+}
+
+#[contractevent]
 pub struct ProjectActive {
     pub project_id: u64,
 }
 
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[contractevent]
 pub struct ProjectVerified {
     pub project_id: u64,
     pub oracle: Address,
     pub proof_hash: BytesN<32>,
 }
 
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[contractevent]
+pub struct MilestoneReleased {
+    pub project_id: u64,
+    pub milestone_index: u32,
+    pub oracle: Address,
+    pub proof_hash: BytesN<32>,
+}
+
+#[contractevent]
+pub struct AttestationSubmitted {
+    pub project_id: u64,
+    pub oracle: Address,
+    pub proof_hash: BytesN<32>,
+    pub attestations: u32,
+}
+
+#[contractevent]
+pub struct OracleQuorumUpdated {
+    pub old_quorum: u32,
+    pub new_quorum: u32,
+}
+
+#[contractevent]
 pub struct ProjectExpired {
     pub project_id: u64,
     pub deadline: u64,
@@ -148,6 +381,14 @@ pub struct ProjectCancelled {
     pub cancelled_by: Address,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectFailed {
+    pub project_id: u64,
+    pub oracle: Address,
+    pub reason_hash: BytesN<32>,
+}
+
 #[contractevent]
 pub struct FundsReleased {
     pub project_id: u64,
@@ -155,17 +396,14 @@ pub struct FundsReleased {
     pub amount: i128,
 }
 
-#[contractevent]
 /// Structured refund event data (previously emitted as a bare tuple).
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[contractevent]
 pub struct Refunded {
     pub project_id: u64,
     pub donator: Address,
     pub amount: i128,
 }
 
-#[contractevent]
 /// Event data emitted when a creator reclaims unclaimed donor funds
 /// after the refund window has expired.
 #[contracttype]
@@ -178,15 +416,12 @@ pub struct ExpiredFundsReclaimed {
 }
 
 /// Event data for protocol pause / unpause.
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[contractevent]
 pub struct ProtocolPaused {
     pub admin: Address,
 }
 
 #[contractevent]
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProtocolUnpaused {
     pub admin: Address,
 }
@@ -218,153 +453,503 @@ pub fn emit_project_funded(env: &Env, project_id: u64, donator: Address, amount:
     .publish(env);
 }
 
-pub fn emit_project_active(env: &Env, project_id: u64) {
-    ProjectActive { project_id }.publish(env);
-}
-
-pub fn emit_project_verified(env: &Env, project_id: u64, oracle: Address, proof_hash: BytesN<32>) {
-    ProjectVerified {
+pub fn emit_committed_deposit(
+    env: &Env,
+    project_id: u64,
+    commitment: BytesN<32>,
+    token: Address,
+    amount: i128,
+) {
+    DonationCommitted {
         project_id,
-        oracle,
-        proof_hash,
+        commitment,
+        token,
+        amount,
     }
     .publish(env);
 }
 
-pub fn emit_project_expired(env: &Env, project_id: u64, deadline: u64) {
-    ProjectExpired {
+pub fn emit_commitment_revealed(
+    env: &Env,
+    project_id: u64,
+    commitment: BytesN<32>,
+    donator: Address,
+    token: Address,
+    amount: i128,
+) {
+    CommitmentRevealed {
         project_id,
-        deadline,
+        commitment,
+        donator,
+        token,
+        amount,
     }
     .publish(env);
 }
 
-pub fn emit_project_cancelled(env: &Env, project_id: u64, cancelled_by: Address) {
-    let topics = (symbol_short!("cancelled"), project_id);
-    let data = ProjectCancelled {
+pub fn emit_committed_refunded(env: &Env, project_id: u64, commitment: BytesN<32>, amount: i128) {
+    CommittedRefunded {
         project_id,
-        cancelled_by,
-    };
-    env.events().publish(topics, data);
+        commitment,
+        amount,
+    }
+    .publish(env);
 }
 
-pub fn emit_funds_released(env: &Env, project_id: u64, token: Address, amount: i128) {
-    FundsReleased {
-        project_id,
+pub fn emit_token_rate_updated(env: &Env, token: Address, old_rate: Option<i128>, new_rate: i128) {
+    TokenRateUpdated {
         token,
-        amount,
+        old_rate,
+        new_rate,
     }
     .publish(env);
 }
 
-pub fn emit_refunded(env: &Env, project_id: u64, donator: Address, amount: i128) {
-    Refunded {
-        project_id,
-        donator,
-        amount,
+pub fn emit_token_supported(env: &Env, info: &crate::types::SupportedToken) {
+    TokenSupported {
+        token: info.token.clone(),
+        min_donation: info.min_donation,
+        decimals: info.decimals,
     }
     .publish(env);
 }
 
-pub fn emit_protocol_paused(env: &Env, admin: Address) {
-    ProtocolPaused { admin }.publish(env);
+pub fn emit_token_unsupported(env: &Env, token: Address) {
+    TokenUnsupported { token }.publish(env);
 }
 
-pub fn emit_protocol_unpaused(env: &Env, admin: Address) {
-    ProtocolUnpaused { admin }.publish(env);
-    let topics = (symbol_short!("refunded"), project_id);
-    let data = Refunded {
-        project_id,
-        donator,
-        amount,
-    };
-    env.events().publish(topics, data);
+pub fn emit_upgrade_proposed(env: &Env, wasm_hash: BytesN<32>, eta: u64, by: Address) {
+    UpgradeProposed { wasm_hash, eta, by }.publish(env);
 }
 
-pub fn emit_expired_funds_reclaimed(
+pub fn emit_upgrade_cancelled(env: &Env, wasm_hash: BytesN<32>, by: Address) {
+    UpgradeCancelled { wasm_hash, by }.publish(env);
+}
+
+pub fn emit_upgrade_executed(env: &Env, wasm_hash: BytesN<32>, by: Address) {
+    UpgradeExecuted { wasm_hash, by }.publish(env);
+}
+
+pub fn emit_schema_migrated(env: &Env, from_version: u32, to_version: u32) {
+    SchemaMigrated {
+        from_version,
+        to_version,
+    }
+    .publish(env);
+}
+
+pub fn emit_proposal_created(
+    env: &Env,
+    proposal_id: u64,
+    action: crate::types::GovAction,
+    proposer: Address,
+    eta: u64,
+) {
+    ProposalCreated {
+        proposal_id,
+        action,
+        proposer,
+        eta,
+    }
+    .publish(env);
+}
+
+pub fn emit_proposal_cancelled(env: &Env, proposal_id: u64, by: Address) {
+    ProposalCancelled { proposal_id, by }.publish(env);
+}
+
+pub fn emit_proposal_executed(env: &Env, proposal_id: u64, action: crate::types::GovAction) {
+    ProposalExecuted {
+        proposal_id,
+        action,
+    }
+    .publish(env);
+}
+
+pub fn emit_council_executed(env: &Env, proposal_id: u64, action: crate::rbac::CouncilAction) {
+    CouncilExecuted {
+        proposal_id,
+        action,
+    }
+    .publish(env);
+}
+
+pub fn emit_pledge_withdrawn(
     env: &Env,
     project_id: u64,
-    creator: Address,
+    donator: Address,
     token: Address,
     amount: i128,
 ) {
-    let topics = (symbol_short!("reclaim"), project_id, token.clone());
-    let data = ExpiredFundsReclaimed {
+    PledgeWithdrawn {
         project_id,
-        creator,
+        donator,
         token,
         amount,
-    };
-    env.events().publish(topics, data);
+    }
+    .publish(env);
 }
 
-pub fn emit_protocol_paused(env: &Env, admin: Address) {
-    let topics = (symbol_short!("paused"), admin.clone());
-    let data = ProtocolPaused { admin };
-    env.events().publish(topics, data);
+pub fn emit_round_created(
+    env: &Env,
+    round_id: u64,
+    token: Address,
+    start: u64,
+    end: u64,
+    creator: Address,
+) {
+    RoundCreated {
+        round_id,
+        token,
+        start,
+        end,
+        creator,
+    }
+    .publish(env);
 }
 
-pub fn emit_protocol_unpaused(env: &Env, admin: Address) {
-    let topics = (symbol_short!("unpaused"), admin.clone());
-    let data = ProtocolUnpaused { admin };
-    env.events().publish(topics, data);
+pub fn emit_round_funded(env: &Env, round_id: u64, sponsor: Address, amount: i128) {
+    RoundFunded {
+        round_id,
+        sponsor,
+        amount,
+    }
+    .publish(env);
 }
 
-pub fn emit_deadline_extended(
+pub fn emit_match_credited(env: &Env, round_id: u64, project_id: u64, amount: i128) {
+    MatchCredited {
+        round_id,
+        project_id,
+        amount,
+    }
+    .publish(env);
+}
+
+pub fn emit_match_returned(env: &Env, round_id: u64, project_id: u64, amount: i128) {
+    MatchReturned {
+        round_id,
+        project_id,
+        amount,
+    }
+    .publish(env);
+}
+
+pub fn emit_round_finalized(env: &Env, round_id: u64, matched: i128, returned: i128) {
+    RoundFinalized {
+        round_id,
+        matched,
+        returned,
+    }
+    .publish(env);
+}
+
+pub fn emit_pledge_created(env: &Env, pledge: &crate::types::RecurringPledge) {
+    PledgeCreated {
+        pledge_id: pledge.id,
+        project_id: pledge.project_id,
+        donator: pledge.donator.clone(),
+        token: pledge.token.clone(),
+        amount: pledge.amount,
+        period: pledge.period,
+        max_periods: pledge.max_periods,
+    }
+    .publish(env);
+}
+
+pub fn emit_pledge_cancelled(env: &Env, pledge_id: u64, project_id: u64) {
+    PledgeCancelled {
+        pledge_id,
+        project_id,
+    }
+    .publish(env);
+}
+
+pub fn emit_pledge_collected(
     env: &Env,
+    pledge_id: u64,
     project_id: u64,
-    old_deadline: u64,
-    new_deadline: u64,
+    amount: i128,
+    periods: u32,
 ) {
-    let topics = (symbol_short!("ext_dead"), project_id);
-    let data = DeadlineExtended {
+    PledgeCollected {
+        pledge_id,
         project_id,
-        old_deadline,
-        new_deadline,
-    };
-    env.events().publish(topics, data);
+        amount,
+        periods,
+    }
+    .publish(env);
 }
 
-pub fn emit_protocol_config_updated(
+pub fn emit_pledge_lapsed(env: &Env, pledge_id: u64, project_id: u64) {
+    PledgeLapsed {
+        pledge_id,
+        project_id,
+    }
+    .publish(env);
+}
+
+pub fn emit_donation_limits_set(
     env: &Env,
-    old_config: Option<ProtocolConfig>,
-    new_config: ProtocolConfig,
+    project_id: u64,
+    limits: crate::types::DonationLimits,
 ) {
-    let topics = (symbol_short!("cfg_upd"),);
-    let data = ProtocolConfigUpdated {
-        old_fee_recipient: old_config.as_ref().map(|c| c.fee_recipient.clone()),
-        old_fee_bps: old_config.map(|c| c.fee_bps).unwrap_or(0),
-        new_fee_recipient: new_config.fee_recipient,
-        new_fee_bps: new_config.fee_bps,
-    };
-    env.events().publish(topics, data);
+    DonationLimitsSet { project_id, limits }.publish(env);
 }
 
-pub fn emit_fee_deducted(env: &Env, project_id: u64, token: Address, amount: i128, recipient: Address) {
-    let topics = (symbol_short!("fee_ded"), project_id, token.clone());
-    let data = FeeDeducted {
+pub fn emit_fee_splits_updated(env: &Env, splits: soroban_sdk::Vec<crate::types::FeeSplit>) {
+    FeeSplitsUpdated { splits }.publish(env);
+}
+
+pub fn emit_project_fee_set(env: &Env, project_id: u64, fee_bps: Option<u32>) {
+    ProjectFeeSet {
         project_id,
-        token,
-        amount,
-        recipient,
+        fee_bps,
+    }
+    .publish(env);
+}
+
+pub fn emit_release_queued(
+    env: &Env,
+    project_id: u64,
+    milestone: Option<u32>,
+    release_after: u64,
+) {
+    ReleaseQueued {
+        project_id,
+        milestone,
+        release_after,
+    }
+    .publish(env);
+}
+
+pub fn emit_release_disputed(
+    env: &Env,
+    project_id: u64,
+    auditor: Address,
+    reason_hash: BytesN<32>,
+    cancelled: bool,
+) {
+    ReleaseDisputed {
+        project_id,
+        auditor,
+        reason_hash,
+        cancelled,
+    }
+    .publish(env);
+}
+
+pub fn emit_dispute_window_updated(env: &Env, window: u64) {
+    DisputeWindowUpdated { window }.publish(env);
+}
+
+pub fn emit_pause_flags_updated(env: &Env, flags: crate::types::PauseFlags) {
+    PauseFlagsUpdated { flags }.publish(env);
+}
+
+pub fn emit_project_frozen(env: &Env, project_id: u64, by: Address) {
+    ProjectFrozen { project_id, by }.publish(env);
+}
+
+pub fn emit_project_unfrozen(env: &Env, project_id: u64, by: Address) {
+    ProjectUnfrozen { project_id, by }.publish(env);
+}
+
+pub fn emit_ownership_transfer_proposed(
+    env: &Env,
+    project_id: u64,
+    owner: Address,
+    pending_owner: Address,
+) {
+    OwnershipTransferProposed {
+        project_id,
+        owner,
+        pending_owner,
+    }
+    .publish(env);
+}
+
+pub fn emit_ownership_transferred(
+    env: &Env,
+    project_id: u64,
+    previous_owner: Address,
+    new_owner: Address,
+) {
+    OwnershipTransferred {
+        project_id,
+        previous_owner,
+        new_owner,
+    }
+    .publish(env);
+}
+
+pub fn emit_beneficiaries_set(
+    env: &Env,
+    project_id: u64,
+    beneficiaries: soroban_sdk::Vec<crate::types::Beneficiary>,
+) {
+    BeneficiariesSet {
+        project_id,
+        beneficiaries,
+    }
+    .publish(env);
+}
+
+pub fn emit_metadata_updated(
+    env: &Env,
+    project_id: u64,
+    version: u32,
+    metadata_uri: soroban_sdk::Bytes,
+    updated_by: Address,
+) {
+    MetadataUpdated {
+        project_id,
+        version,
+        metadata_uri,
+        updated_by,
+    }
+    .publish(env);
+}
+
+pub fn emit_merkle_root_set(
+    env: &Env,
+    project_id: u64,
+    merkle_root: Option<soroban_sdk::BytesN<32>>,
+) {
+    MerkleRootSet {
+        project_id,
+        merkle_root,
+    }
+    .publish(env);
+}
+
+pub fn emit_project_bumped(env: &Env, project_id: u64, live_until_ledger: u32) {
+    ProjectBumped {
+        project_id,
+        live_until_ledger,
+    }
+    .publish(env);
+}
+
+pub fn emit_project_active(env: &Env, project_id: u64) {
+    ProjectActive { project_id }.publish(env);
+}
+
+pub fn emit_project_verified(env: &Env, project_id: u64, oracle: Address, proof_hash: BytesN<32>) {
+    ProjectVerified {
+        project_id,
+        oracle,
+        proof_hash,
+    }
+    .publish(env);
+}
+
+pub fn emit_milestone_released(
+    env: &Env,
+    project_id: u64,
+    milestone_index: u32,
+    oracle: Address,
+    proof_hash: BytesN<32>,
+) {
+    MilestoneReleased {
+        project_id,
+        milestone_index,
+        oracle,
+        proof_hash,
+    }
+    .publish(env);
+}
+
+pub fn emit_attestation_submitted(
+    env: &Env,
+    project_id: u64,
+    oracle: Address,
+    proof_hash: BytesN<32>,
+    attestations: u32,
+) {
+    AttestationSubmitted {
+        project_id,
+        oracle,
+        proof_hash,
+        attestations,
+    }
+    .publish(env);
+}
+
+pub fn emit_oracle_quorum_updated(env: &Env, old_quorum: u32, new_quorum: u32) {
+    OracleQuorumUpdated {
+        old_quorum,
+        new_quorum,
+    }
+    .publish(env);
+}
+
+pub fn emit_project_expired(env: &Env, project_id: u64, deadline: u64) {
+    ProjectExpired {
+        project_id,
+        deadline,
+    }
+    .publish(env);
+}
+
+pub fn emit_project_cancelled(env: &Env, project_id: u64, cancelled_by: Address) {
+    let topics = (symbol_short!("cancelled"), project_id);
+    let data = ProjectCancelled {
+        project_id,
+        cancelled_by,
     };
     env.events().publish(topics, data);
 }
 
-pub fn emit_whitelist_added(env: &Env, project_id: u64, address: Address) {
-    let topics = (symbol_short!("wl_add"), project_id);
-    let data = WhitelistAdded {
+pub fn emit_project_failed(env: &Env, project_id: u64, oracle: Address, reason_hash: BytesN<32>) {
+    let topics = (symbol_short!("failed"), project_id);
+    let data = ProjectFailed {
         project_id,
-        address,
+        oracle,
+        reason_hash,
     };
     env.events().publish(topics, data);
 }
 
-pub fn emit_whitelist_removed(env: &Env, project_id: u64, address: Address) {
-    let topics = (symbol_short!("wl_rem"), project_id);
-    let data = WhitelistRemoved {
+pub fn emit_funds_released(env: &Env, project_id: u64, token: Address, amount: i128) {
+    FundsReleased {
         project_id,
-        address,
+        token,
+        amount,
+    }
+    .publish(env);
+}
+
+pub fn emit_refunded(env: &Env, project_id: u64, donator: Address, amount: i128) {
+    Refunded {
+        project_id,
+        donator,
+        amount,
+    }
+    .publish(env);
+}
+
+pub fn emit_protocol_paused(env: &Env, admin: Address) {
+    ProtocolPaused { admin }.publish(env);
+}
+
+pub fn emit_protocol_unpaused(env: &Env, admin: Address) {
+    ProtocolUnpaused { admin }.publish(env);
+}
+
+pub fn emit_expired_funds_reclaimed(
+    env: &Env,
+    project_id: u64,
+    creator: Address,
+    token: Address,
+    amount: i128,
+) {
+    let topics = (symbol_short!("reclaim"), project_id, token.clone());
+    let data = ExpiredFundsReclaimed {
+        project_id,
+        creator,
+        token,
+        amount,
     };
     env.events().publish(topics, data);
 }
@@ -410,17 +995,20 @@ pub fn emit_fee_deducted(env: &Env, project_id: u64, token: Address, amount: i12
     env.events().publish(topics, data);
 }
 
-pub fn emit_deadline_extended(
-    env: &Env,
-    project_id: u64,
-    old_deadline: u64,
-    new_deadline: u64,
-) {
-    let topics = (symbol_short!("ext_dead"), project_id);
-    let data = DeadlineExtended {
+pub fn emit_whitelist_added(env: &Env, project_id: u64, address: Address) {
+    let topics = (symbol_short!("wl_add"), project_id);
+    let data = WhitelistAdded {
         project_id,
-        old_deadline,
-        new_deadline,
+        address,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_whitelist_removed(env: &Env, project_id: u64, address: Address) {
+    let topics = (symbol_short!("wl_rem"), project_id);
+    let data = WhitelistRemoved {
+        project_id,
+        address,
     };
     env.events().publish(topics, data);
 }
@@ -21,8 +21,13 @@ fn setup_env() -> (Env, PifpProtocolClient<'static>, Address) {
     (env, client, admin)
 }
 
-fn create_token<'a>(env: &Env, admin: &Address) -> token::Client<'a> {
+fn create_token<'a>(
+    env: &Env,
+    client: &PifpProtocolClient,
+    admin: &Address,
+) -> token::Client<'a> {
     let addr = env.register_stellar_asset_contract_v2(admin.clone());
+    client.add_supported_token(admin, &addr.address(), &0, &7);
     token::Client::new(env, &addr.address())
 }
 
@@ -41,8 +46,7 @@ proptest! {
         let creator = Address::generate(&env);
         client.grant_role(&admin, &creator, &Role::ProjectManager);
 
-        let token_admin = Address::generate(&env);
-        let token = create_token(&env, &token_admin);
+        let token = create_token(&env, &client, &admin);
         let proof_hash = BytesN::from_array(&env, &[7u8; 32]);
         let deadline = env.ledger().timestamp() + 86_400;
 
@@ -56,6 +60,8 @@ proptest! {
             &proof_hash,
             &dummy_metadata_uri(&env),
             &deadline,
+            &false,
+            &SorobanVec::new(&env),
         );
 
         check_all_project_invariants(&env, &project);
@@ -69,8 +75,7 @@ proptest! {
         let creator = Address::generate(&env);
         client.grant_role(&admin, &creator, &Role::ProjectManager);
 
-        let token_admin = Address::generate(&env);
-        let token = create_token(&env, &token_admin);
+        let token = create_token(&env, &client, &admin);
         let proof_hash = BytesN::from_array(&env, &[8u8; 32]);
         let deadline = env.ledger().timestamp() + offset;
 
@@ -84,6 +89,8 @@ proptest! {
             &proof_hash,
             &dummy_metadata_uri(&env),
             &deadline,
+            &false,
+            &SorobanVec::new(&env),
         );
 
         check_all_project_invariants(&env, &project);
@@ -96,8 +103,7 @@ proptest! {
         let creator = Address::generate(&env);
         client.grant_role(&admin, &creator, &Role::ProjectManager);
 
-        let token_admin = Address::generate(&env);
-        let token = create_token(&env, &token_admin);
+        let token = create_token(&env, &client, &admin);
         let proof_hash = BytesN::from_array(&env, &hash_bytes);
         let deadline = env.ledger().timestamp() + 86_400;
 
@@ -111,6 +117,8 @@ proptest! {
             &proof_hash,
             &dummy_metadata_uri(&env),
             &deadline,
+            &false,
+            &SorobanVec::new(&env),
         );
 
         check_all_project_invariants(&env, &project);
@@ -129,8 +137,7 @@ proptest! {
         let creator = Address::generate(&env);
         client.grant_role(&admin, &creator, &Role::ProjectManager);
 
-        let token_admin = Address::generate(&env);
-        let token_client = create_token(&env, &token_admin);
+        let token_client = create_token(&env, &client, &admin);
         let proof_hash = BytesN::from_array(&env, &[1u8; 32]);
         let deadline = env.ledger().timestamp() + 86_400;
 
@@ -144,6 +151,8 @@ proptest! {
             &proof_hash,
             &dummy_metadata_uri(&env),
             &deadline,
+            &false,
+            &SorobanVec::new(&env),
         );
 
         let donator = Address::generate(&env);
@@ -167,8 +176,7 @@ proptest! {
         let creator = Address::generate(&env);
         client.grant_role(&admin, &creator, &Role::ProjectManager);
 
-        let token_admin = Address::generate(&env);
-        let token_client = create_token(&env, &token_admin);
+        let token_client = create_token(&env, &client, &admin);
         let proof_hash = BytesN::from_array(&env, &[2u8; 32]);
         let deadline = env.ledger().timestamp() + 86_400;
 
@@ -182,6 +190,8 @@ proptest! {
             &proof_hash,
             &dummy_metadata_uri(&env),
             &deadline,
+            &false,
+            &SorobanVec::new(&env),
         );
 
         let sac = token::StellarAssetClient::new(&env, &token_client.address);
@@ -223,9 +233,10 @@ proptest! {
         let (env, client, admin) = setup_env();
         let creator = Address::generate(&env);
         client.grant_role(&admin, &creator, &Role::ProjectManager);
+        let oracle = Address::generate(&env);
+        client.set_oracle(&admin, &oracle);
 
-        let token_admin = Address::generate(&env);
-        let token = create_token(&env, &token_admin);
+        let token = create_token(&env, &client, &admin);
         let proof_hash = BytesN::from_array(&env, &stored_bytes);
         let deadline = env.ledger().timestamp() + 86_400;
 
@@ -239,10 +250,10 @@ proptest! {
             &proof_hash,
             &dummy_metadata_uri(&env),
             &deadline,
+            &false,
+            &SorobanVec::new(&env),
         );
 
-        let oracle = Address::generate(&env);
-        client.set_oracle(&admin, &oracle);
 
         let wrong_hash = BytesN::from_array(&env, &submitted_bytes);
         let result = client.try_verify_and_release(&oracle, &project.id, &wrong_hash);
@@ -256,9 +267,10 @@ proptest! {
         let (env, client, admin) = setup_env();
         let creator = Address::generate(&env);
         client.grant_role(&admin, &creator, &Role::ProjectManager);
+        let oracle = Address::generate(&env);
+        client.set_oracle(&admin, &oracle);
 
-        let token_admin = Address::generate(&env);
-        let token = create_token(&env, &token_admin);
+        let token = create_token(&env, &client, &admin);
         let proof_hash = BytesN::from_array(&env, &hash_bytes);
         let deadline = env.ledger().timestamp() + 86_400;
 
@@ -272,10 +284,10 @@ proptest! {
             &proof_hash,
             &dummy_metadata_uri(&env),
             &deadline,
+            &false,
+            &SorobanVec::new(&env),
         );
 
-        let oracle = Address::generate(&env);
-        client.set_oracle(&admin, &oracle);
 
         client.verify_and_release(&oracle, &project.id, &proof_hash);
 
@@ -293,26 +305,29 @@ proptest! {
     #[test]
     fn fuzz_sequential_ids(n in 2u32..=10u32) {
         let (env, client, admin) = setup_env();
-        let token_admin = Address::generate(&env);
-        let token = create_token(&env, &token_admin);
+        let token = create_token(&env, &client, &admin);
         let proof_hash = BytesN::from_array(&env, &[1u8; 32]);
         let deadline = env.ledger().timestamp() + 86_400;
 
         let mut tokens = SorobanVec::new(&env);
         tokens.push_back(token.address.clone());
 
-        let mut projects = Vec::new();
-        for _ in 0..n {
-            let creator = Address::generate(&env);
-            client.grant_role(&admin, &creator, &Role::ProjectManager);
+        let creators: Vec<Address> = (0..n).map(|_| Address::generate(&env)).collect();
+        for creator in &creators {
+            client.grant_role(&admin, creator, &Role::ProjectManager);
+        }
 
+        let mut projects = Vec::new();
+        for creator in &creators {
             let p = client.register_project(
-                &creator,
+                creator,
                 &tokens,
                 &1000,
                 &proof_hash,
             &dummy_metadata_uri(&env),
                 &deadline,
+                &false,
+                &SorobanVec::new(&env),
             );
             projects.push(p);
         }
@@ -336,8 +351,7 @@ proptest! {
         let creator = Address::generate(&env);
         client.grant_role(&admin, &creator, &Role::ProjectManager);
 
-        let token_admin = Address::generate(&env);
-        let token_client = create_token(&env, &token_admin);
+        let token_client = create_token(&env, &client, &admin);
         let proof_hash = BytesN::from_array(&env, &[5u8; 32]);
         let deadline = env.ledger().timestamp() + 86_400;
 
@@ -351,6 +365,8 @@ proptest! {
             &proof_hash,
             &dummy_metadata_uri(&env),
             &deadline,
+            &false,
+            &SorobanVec::new(&env),
         );
 
         let donator = Address::generate(&env);
@@ -369,9 +385,10 @@ proptest! {
         let (env, client, admin) = setup_env();
         let creator = Address::generate(&env);
         client.grant_role(&admin, &creator, &Role::ProjectManager);
+        let oracle = Address::generate(&env);
+        client.set_oracle(&admin, &oracle);
 
-        let token_admin = Address::generate(&env);
-        let token = create_token(&env, &token_admin);
+        let token = create_token(&env, &client, &admin);
         let proof_hash = BytesN::from_array(&env, &hash_bytes);
         let deadline = env.ledger().timestamp() + 86_400;
 
@@ -385,10 +402,10 @@ proptest! {
             &proof_hash,
             &dummy_metadata_uri(&env),
             &deadline,
+            &false,
+            &SorobanVec::new(&env),
         );
 
-        let oracle = Address::generate(&env);
-        client.set_oracle(&admin, &oracle);
         client.verify_and_release(&oracle, &original.id, &proof_hash);
 
         let after = client.get_project(&original.id);
@@ -411,9 +428,10 @@ proptest! {
         let (env, client, admin) = setup_env();
         let creator = Address::generate(&env);
         client.grant_role(&admin, &creator, &Role::ProjectManager);
+        let oracle = Address::generate(&env);
+        client.set_oracle(&admin, &oracle);
 
-        let token_admin = Address::generate(&env);
-        let token_client = create_token(&env, &token_admin);
+        let token_client = create_token(&env, &client, &admin);
         let proof_hash = BytesN::from_array(&env, &hash_bytes);
         let deadline = env.ledger().timestamp() + deadline_offset;
 
@@ -428,6 +446,8 @@ proptest! {
             &proof_hash,
             &dummy_metadata_uri(&env),
             &deadline,
+            &false,
+            &SorobanVec::new(&env),
         );
         check_all_project_invariants(&env, &project);
         assert_eq!(project.status, ProjectStatus::Funding);
@@ -457,8 +477,6 @@ proptest! {
         assert_eq!(final_balance, total_deposited);
 
         // Phase 3: Oracle verification.
-        let oracle = Address::generate(&env);
-        client.set_oracle(&admin, &oracle);
         client.verify_and_release(&oracle, &project.id, &proof_hash);
 
         let final_project = client.get_project(&project.id);
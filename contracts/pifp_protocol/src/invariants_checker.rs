@@ -4,7 +4,7 @@
 //! as defined in ARCHITECTURE.md. These checkers are used both in fuzz tests
 //! and can be triggered as post-execution assertions in debug builds.
 
-use crate::rbac::get_super_admin;
+use crate::rbac::{get_grants, Role};
 use crate::types::{Project, ProjectStatus};
 use soroban_sdk::{Address, Env, Vec};
 
@@ -80,6 +80,12 @@ pub fn check_inv7_status_transition(from: &ProjectStatus, to: &ProjectStatus) {
             | (ProjectStatus::Active, ProjectStatus::Completed)
             | (ProjectStatus::Active, ProjectStatus::Expired)
             | (ProjectStatus::Active, ProjectStatus::Cancelled)
+            | (ProjectStatus::Funding, ProjectStatus::PendingRelease)
+            | (ProjectStatus::Active, ProjectStatus::PendingRelease)
+            | (ProjectStatus::Funding, ProjectStatus::Failed)
+            | (ProjectStatus::Active, ProjectStatus::Failed)
+            // Finalized, disputed back for re-verification, or disputed and cancelled.
+            | (ProjectStatus::PendingRelease, _)
     );
 
     assert!(
@@ -89,10 +95,23 @@ pub fn check_inv7_status_transition(from: &ProjectStatus, to: &ProjectStatus) {
     );
 }
 
-/// INV-8: An address holds at most one RBAC role at a time.
-/// (Enforced by storage layout in rbac.rs).
-pub fn check_inv8_single_role(_env: &Env, _address: &Address) {
-    // This is guaranteed by RbacKey::Role(Address) mapping to a single Role enum.
+/// INV-8: An address holds each RBAC role at most once, and its SuperAdmin
+/// grant never expires.
+pub fn check_inv8_role_grants(env: &Env, address: &Address) {
+    let grants = get_grants(env, address);
+    for i in 0..grants.len() {
+        let grant = grants.get(i).unwrap();
+        assert_eq!(
+            grants.iter().filter(|g| g.role == grant.role).count(),
+            1,
+            "INV-8 violated: {:?} granted more than once",
+            grant.role
+        );
+        assert!(
+            grant.role != Role::SuperAdmin || grant.expires_at.is_none(),
+            "INV-8 violated: SuperAdmin grant expires"
+        );
+    }
 }
 
 /// INV-9: The SuperAdmin address is always set after init.
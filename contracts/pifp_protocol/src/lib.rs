@@ -7,12 +7,23 @@
 //! | Phase        | Entry Point(s)                              |
 //! |--------------|---------------------------------------------|
 //! | Bootstrap    | [`PifpProtocol::init`]                      |
-//! | Role admin   | `grant_role`, `revoke_role`, `transfer_super_admin`, `set_oracle` |
-//! | Registration | [`PifpProtocol::register_project`]          |
-//! | Funding      | [`PifpProtocol::deposit`]                   |
-//! | Donor safety | [`PifpProtocol::refund`]                    |
-//! | Verification | [`PifpProtocol::verify_and_release`]        |
-//! | Queries      | `get_project`, `get_project_balances`, `role_of`, `has_role` |
+//! | Role admin   | `grant_role`, `grant_role_until`, `renew_role`, `revoke_role`, `revoke_single_role`, `transfer_super_admin`, `set_oracle` |
+//! | Upgrades     | `propose_upgrade`, `cancel_upgrade`, `execute_upgrade`, `migrate` |
+//! | Governance   | `propose_action`, `cancel_proposal`, `execute_proposal` |
+//! | Admin council | `set_council`, `council_propose`, `council_approve`, `council_execute`, `council_cancel` |
+//! | Token registry | `add_supported_token`, `remove_supported_token`, `list_supported_tokens` |
+//! | Registration | [`PifpProtocol::register_project`], [`PifpProtocol::update_metadata`] |
+//! | Ownership    | `transfer_project_ownership`, `accept_project_ownership`, `set_beneficiaries` |
+//! | Funding      | [`PifpProtocol::deposit`], [`PifpProtocol::deposit_with_proof`], [`PifpProtocol::deposit_committed`] |
+//! | Matching     | `create_round`, `fund_round`, `finalize_round` |
+//! | Recurring    | `create_recurring_pledge`, `collect_pledges`, `cancel_recurring_pledge` |
+//! | Donor safety | [`PifpProtocol::withdraw_pledge`], [`PifpProtocol::refund`], [`PifpProtocol::refund_all`], [`PifpProtocol::process_refunds`], [`PifpProtocol::refund_committed`], [`PifpProtocol::reveal`] |
+//! | Emergency    | `pause`, `unpause`, `set_pause_flags`, `freeze_project`, `unfreeze_project` |
+//! | Maintenance  | `bump_project` |
+//! | Receipts     | `get_receipts`, `balance`, `decimals`, `name`, `symbol` |
+//! | Verification | [`PifpProtocol::verify_and_release`], [`PifpProtocol::verify_milestone`], [`PifpProtocol::submit_attestation`], [`PifpProtocol::submit_signed_attestation`], [`PifpProtocol::reject_verification`] |
+//! | Disputes     | `set_dispute_window`, `dispute`, `finalize_release` |
+//! | Queries      | `get_project`, `get_project_balances`, `list_projects`, `list_projects_by_creator`, `count_by_status`, `role_of`, `roles_of`, `has_role` |
 //!
 //! ## Architecture
 //!
@@ -24,17 +35,83 @@
 //! architecture and threat model.
 
 #![no_std]
+#![allow(clippy::too_many_arguments)]
 
-use soroban_sdk::{contract, contractimpl, panic_with_error, token, Address, Bytes, BytesN, Env, Vec};
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, token, xdr::ToXdr, Address, Bytes, BytesN, Env,
+    String, Vec, I256,
+};
 
 /// Refund window: 6 months (in seconds) after a project enters a terminal
-/// refundable state (Expired or Cancelled).  Donors must claim refunds within
+/// refundable state (Expired, Cancelled or Failed).  Donors must claim refunds within
 /// this window; after it passes, the creator may reclaim unclaimed funds.
 const REFUND_WINDOW: u64 = 6 * 30 * 24 * 60 * 60; // 15_552_000 seconds
 
 /// Maximum allowed length for a project metadata URI / CID.
 const MAX_METADATA_URI_LEN: u32 = 64;
 
+/// Number of metadata versions kept in a project's history.
+const MAX_METADATA_VERSIONS: u32 = 10;
+
+/// Maximum number of milestones a project may be registered with.
+const MAX_MILESTONES: u32 = 10;
+
+/// Milestone shares are expressed in basis points and must sum to this total.
+const TOTAL_MILESTONE_BPS: u32 = 10_000;
+
+/// Maximum number of items a single listing query or batched call visits.
+/// Each item touches at least two ledger entries, so this keeps a call within
+/// the 100-entry transaction footprint limit.
+const MAX_PAGE_SIZE: u32 = 30;
+
+/// Maximum number of addresses in one whitelist batch.
+const MAX_WHITELIST_BATCH: u32 = 50;
+
+/// Maximum number of tokens in the protocol's token registry, which lives in
+/// instance storage and is loaded on every call.
+const MAX_SUPPORTED_TOKENS: u32 = 50;
+
+/// SEP-41 name and symbol wallets display for donation receipts.
+const RECEIPT_NAME: &str = "PIFP Donation Receipt";
+const RECEIPT_SYMBOL: &str = "PIFPR";
+
+/// Delay between `propose_upgrade` and the earliest `execute_upgrade`: 7 days,
+/// long enough for donors to notice a pending upgrade and withdraw or refund.
+const UPGRADE_DELAY: u64 = 7 * 24 * 60 * 60;
+
+/// Once a `Funding` project has raised this share of its goal (in basis
+/// points), donors can no longer withdraw pledges, so a creator close to the
+/// goal is not pushed back by last-minute withdrawals.
+const WITHDRAW_LOCK_IN_BPS: i128 = 9_000;
+
+/// Maximum number of recipients the platform fee can be split between.
+const MAX_FEE_SPLITS: u32 = 10;
+
+/// Fee split shares must sum to exactly this many basis points (100 %).
+const TOTAL_FEE_SPLIT_BPS: u32 = 10_000;
+
+/// Maximum number of pledge slots `collect_pledges` visits per call.
+const MAX_PLEDGES_PER_COLLECT: u32 = 25;
+
+/// Maximum number of projects eligible in one matching round.
+const MAX_ROUND_PROJECTS: u32 = 20;
+
+/// Longest dispute window the `SuperAdmin` may configure: 30 days.
+const MAX_DISPUTE_WINDOW: u64 = 30 * 24 * 60 * 60;
+
+/// Maximum number of beneficiaries a project's payouts can be split between.
+const MAX_BENEFICIARIES: u32 = 10;
+
+/// Beneficiary shares must sum to exactly this many basis points (100 %).
+const TOTAL_BENEFICIARY_BPS: u32 = 10_000;
+
+/// Delay between `propose_action` and the earliest `execute_proposal`: 2 days.
+const GOVERNANCE_DELAY: u64 = 2 * 24 * 60 * 60;
+
+/// Storage layout version written by this code. See `storage.rs` for the
+/// layout changes of each version.
+const CURRENT_SCHEMA_VERSION: u32 = 2;
+
 pub mod errors;
 pub mod events;
 pub mod invariants_checker;
@@ -64,36 +141,74 @@ mod test_reclaim;
 #[cfg(test)]
 mod test_deadline;
 #[cfg(test)]
-mod test_deadline;
+mod test_protocol_config;
 #[cfg(test)]
-mod test_deadline;
+mod test_whitelist;
 #[cfg(test)]
-mod test_errors;
+mod test_milestones;
 #[cfg(test)]
-mod test_protocol_config;
+mod test_quorum;
+#[cfg(test)]
+mod test_signed_attestation;
+#[cfg(test)]
+mod test_committed;
+#[cfg(test)]
+mod test_multi_token_goal;
+#[cfg(test)]
+mod test_listing;
+#[cfg(test)]
+mod test_upgrade;
+#[cfg(test)]
+mod test_governance;
+#[cfg(test)]
+mod test_council;
+#[cfg(test)]
+mod test_withdraw;
+#[cfg(test)]
+mod test_matching;
+#[cfg(test)]
+mod test_recurring;
+#[cfg(test)]
+mod test_limits;
+#[cfg(test)]
+mod test_push_refunds;
+#[cfg(test)]
+mod test_fee_splits;
+#[cfg(test)]
+mod test_disputes;
+#[cfg(test)]
+mod test_rejection;
+#[cfg(test)]
+mod test_freeze;
+#[cfg(test)]
+mod test_ownership;
+#[cfg(test)]
+mod test_metadata_history;
+#[cfg(test)]
+mod test_receipts;
+#[cfg(test)]
+mod test_merkle_allowlist;
+#[cfg(test)]
+mod test_bump;
+#[cfg(test)]
+mod test_token_registry;
 #[cfg(test)]
-mod test_whitelist;
 mod test_utils;
 
 pub use errors::Error;
 pub use events::emit_funds_released;
-pub use rbac::Role;
+pub use rbac::{Council, CouncilAction, CouncilProposal, Role, RoleGrant};
 use storage::{
-    drain_token_balance, get_all_balances, get_and_increment_project_id, load_project,
-    load_project_pair, maybe_load_project, save_project, save_project_config, save_project_state,
     drain_token_balance, get_all_balances, get_and_increment_project_id, get_protocol_config,
-    load_project, load_project_pair, maybe_load_project, save_project, save_project_config,
-    save_project_state, set_protocol_config,
+    is_whitelisted, load_project, load_project_pair, maybe_load_project, save_project,
+    save_project_config, save_project_state, set_protocol_config,
 };
-pub use types::{Project, ProjectBalances, ProjectConfig, ProjectState, ProtocolConfig};
-
-
-    add_to_whitelist, drain_token_balance, get_all_balances, get_and_increment_project_id,
-    get_protocol_config, is_whitelisted, load_project, load_project_pair, maybe_load_project,
-    remove_from_whitelist, save_project, save_project_config, save_project_state,
-    set_protocol_config,
+pub use types::{
+    Beneficiary, BumpProgress, CommittedDeposit, DonationLimits, DonationReceipt, FeeSplit,
+    MetadataVersion, Milestone, OverfundPolicy, PauseFlags, Project, ProjectBalances, ProjectConfig,
+    ProjectState, GovAction, MatchingRound, PendingUpgrade, ProjectStatus, Proposal, ProtocolConfig,
+    QueuedRelease, RecurringPledge, RoundTally, StatusCounts, SupportedToken,
 };
-pub use types::{Project, ProjectBalances, ProjectConfig, ProjectState, ProtocolConfig};
 
 #[contract]
 pub struct PifpProtocol;
@@ -113,42 +228,107 @@ impl PifpProtocol {
     pub fn init(env: Env, super_admin: Address) {
         super_admin.require_auth();
         rbac::init_super_admin(&env, &super_admin);
+        storage::set_schema_version(&env, CURRENT_SCHEMA_VERSION);
     }
 
     // ─────────────────────────────────────────────────────────
     // Role management
     // ─────────────────────────────────────────────────────────
 
-    /// Grant `role` to `target`.
+    /// Grant `role` to `target` without an expiry.
+    ///
+    /// Only possible before the first project is registered; afterwards
+    /// grants are queued with `propose_action(GovAction::GrantRole)`.
     ///
     /// - `caller` must hold `SuperAdmin` or `Admin`.
-    /// - Only `SuperAdmin` can grant `SuperAdmin`.
+    /// - `SuperAdmin` cannot be granted; use `transfer_super_admin`.
+    /// - Roles `target` already holds are kept.
     pub fn grant_role(env: Env, caller: Address, target: Address, role: Role) {
-        rbac::grant_role(&env, &caller, &target, role);
+        caller.require_auth();
+        Self::apply_direct(&env, &caller, GovAction::GrantRole(target, role));
+    }
+
+    /// Grant `role` to `target` until ledger sequence `expires_at`.
+    ///
+    /// Only possible before the first project is registered; afterwards
+    /// grants are queued with `propose_action(GovAction::GrantRoleUntil)`.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - `expires_at` must be in the future; `SuperAdmin` cannot be granted
+    ///   with an expiry.
+    pub fn grant_role_until(
+        env: Env,
+        caller: Address,
+        target: Address,
+        role: Role,
+        expires_at: u32,
+    ) {
+        caller.require_auth();
+        Self::apply_direct(
+            &env,
+            &caller,
+            GovAction::GrantRoleUntil(target, role, expires_at),
+        );
+    }
+
+    /// Move the expiry of a role `target` holds to `expires_at`.
+    ///
+    /// Once a project is registered this can only bring the expiry forward;
+    /// extending a grant means granting it again through `propose_action`.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - `None` makes the grant permanent.
+    /// - Fails with `RoleNotFound` once the grant has lapsed.
+    pub fn renew_role(
+        env: Env,
+        caller: Address,
+        target: Address,
+        role: Role,
+        expires_at: Option<u32>,
+    ) {
+        caller.require_auth();
+        let may_extend = Self::before_first_project(&env);
+        rbac::renew_role(&env, &caller, &target, role, expires_at, may_extend);
     }
 
-    /// Revoke any role from `target`.
+    /// Revoke every role from `target`.
     ///
     /// - `caller` must hold `SuperAdmin` or `Admin`.
     /// - Cannot be used to remove the SuperAdmin; use `transfer_super_admin`.
     pub fn revoke_role(env: Env, caller: Address, target: Address) {
+        caller.require_auth();
         rbac::revoke_role(&env, &caller, &target);
     }
 
+    /// Revoke `role` from `target`, keeping its other roles.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - Cannot be used to remove `SuperAdmin`; use `transfer_super_admin`.
+    pub fn revoke_single_role(env: Env, caller: Address, target: Address, role: Role) {
+        caller.require_auth();
+        rbac::revoke_single_role(&env, &caller, &target, role);
+    }
+
     /// Transfer SuperAdmin to `new_super_admin`.
     ///
     /// - `current_super_admin` must authorize and hold the `SuperAdmin` role.
     /// - The previous SuperAdmin loses the role immediately.
     pub fn transfer_super_admin(env: Env, current_super_admin: Address, new_super_admin: Address) {
+        current_super_admin.require_auth();
         rbac::transfer_super_admin(&env, &current_super_admin, &new_super_admin);
     }
 
-    /// Return the role held by `address`, or `None`.
+    /// Return the most privileged unexpired role held by `address`, or `None`.
     pub fn role_of(env: Env, address: Address) -> Option<Role> {
         rbac::role_of(&env, address)
     }
 
-    /// Return `true` if `address` holds `role`.
+    /// Return the unexpired roles held by `address` with their expiry ledgers.
+    pub fn roles_of(env: Env, address: Address) -> Vec<RoleGrant> {
+        rbac::roles_of(&env, address)
+    }
+
+    /// Return `true` if `address` holds an unexpired grant of `role`.
     pub fn has_role(env: Env, address: Address, role: Role) -> bool {
         rbac::has_role(&env, address, role)
     }
@@ -159,22 +339,25 @@ impl PifpProtocol {
 
     /// Pause the protocol, halting all registrations, deposits, and releases.
     ///
+    /// Only possible before the first project is registered; afterwards use
+    /// `propose_action(GovAction::SetPaused(true))`, or `freeze_project` to
+    /// halt a single project at once.
+    ///
     /// - `caller` must hold `SuperAdmin` or `Admin`.
     pub fn pause(env: Env, caller: Address) {
         caller.require_auth();
-        rbac::require_admin_or_above(&env, &caller);
-        storage::set_paused(&env, true);
-        events::emit_protocol_paused(&env, caller);
+        Self::apply_direct(&env, &caller, GovAction::SetPaused(true));
     }
 
     /// Unpause the protocol.
     ///
+    /// Only possible before the first project is registered; afterwards use
+    /// `propose_action(GovAction::SetPaused(false))`.
+    ///
     /// - `caller` must hold `SuperAdmin` or `Admin`.
     pub fn unpause(env: Env, caller: Address) {
         caller.require_auth();
-        rbac::require_admin_or_above(&env, &caller);
-        storage::set_paused(&env, false);
-        events::emit_protocol_unpaused(&env, caller);
+        Self::apply_direct(&env, &caller, GovAction::SetPaused(false));
     }
 
     /// Return true if the protocol is paused.
@@ -182,6 +365,259 @@ impl PifpProtocol {
         storage::is_paused(&env)
     }
 
+    /// Pause or resume individual classes of operations (deposits,
+    /// registrations, releases, refunds) without halting the whole protocol.
+    ///
+    /// Only possible before the first project is registered; afterwards use
+    /// `propose_action(GovAction::SetPauseFlags)`.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    pub fn set_pause_flags(env: Env, caller: Address, flags: PauseFlags) {
+        caller.require_auth();
+        Self::apply_direct(&env, &caller, GovAction::SetPauseFlags(flags));
+    }
+
+    /// Return the per-operation pause flags.
+    pub fn get_pause_flags(env: Env) -> PauseFlags {
+        storage::get_pause_flags(&env)
+    }
+
+    /// Freeze a single project, halting its deposits and verification while
+    /// the rest of the protocol keeps running. Donors can still withdraw and
+    /// refund, and Auditors can still dispute a queued release.
+    ///
+    /// - `caller` must hold `SuperAdmin`, `Admin` or `Auditor`.
+    pub fn freeze_project(env: Env, caller: Address, project_id: u64) {
+        caller.require_auth();
+        rbac::require_can_freeze(&env, &caller);
+        storage::load_project_config(&env, project_id);
+
+        storage::set_frozen(&env, project_id, true);
+        events::emit_project_frozen(&env, project_id, caller);
+    }
+
+    /// Lift a freeze set by [`freeze_project`].
+    ///
+    /// - `caller` must hold `SuperAdmin`, `Admin` or `Auditor`.
+    pub fn unfreeze_project(env: Env, caller: Address, project_id: u64) {
+        caller.require_auth();
+        rbac::require_can_freeze(&env, &caller);
+        storage::load_project_config(&env, project_id);
+
+        storage::set_frozen(&env, project_id, false);
+        events::emit_project_unfrozen(&env, project_id, caller);
+    }
+
+    /// Return true if `project_id` is frozen.
+    pub fn is_project_frozen(env: Env, project_id: u64) -> bool {
+        storage::is_frozen(&env, project_id)
+    }
+
+    // ─────────────────────────────────────────────────────────
+    // Upgrades
+    // ─────────────────────────────────────────────────────────
+
+    /// Propose upgrading the contract to the already-uploaded `wasm_hash`.
+    ///
+    /// The upgrade can be executed once `UPGRADE_DELAY` (7 days) has passed.
+    /// Only one upgrade may be pending at a time.
+    ///
+    /// - `caller` must be the `SuperAdmin`.
+    pub fn propose_upgrade(env: Env, caller: Address, wasm_hash: BytesN<32>) -> u64 {
+        caller.require_auth();
+        Self::do_propose_upgrade(&env, &caller, wasm_hash)
+    }
+
+    /// Cancel the pending upgrade.
+    ///
+    /// - `caller` must be the `SuperAdmin`.
+    pub fn cancel_upgrade(env: Env, caller: Address) {
+        caller.require_auth();
+        Self::do_cancel_upgrade(&env, &caller);
+    }
+
+    /// Replace the contract code with the pending upgrade once its ETA has passed.
+    ///
+    /// Storage is left untouched; if the new code raises the schema version,
+    /// call `migrate` right after.
+    ///
+    /// - `caller` must be the `SuperAdmin`.
+    pub fn execute_upgrade(env: Env, caller: Address) {
+        caller.require_auth();
+        Self::do_execute_upgrade(&env, &caller);
+    }
+
+    /// Return the upgrade waiting out its timelock, if any.
+    pub fn get_pending_upgrade(env: Env) -> Option<PendingUpgrade> {
+        storage::get_pending_upgrade(&env)
+    }
+
+    /// Return the layout version of the data currently in storage.
+    pub fn schema_version(env: Env) -> u32 {
+        storage::get_schema_version(&env)
+    }
+
+    /// Migrate stored data to the layout of the running code.
+    ///
+    /// Projects are rewritten in batches of at most `limit` (capped at 30)
+    /// starting from a stored cursor, so large deployments can be migrated
+    /// over several transactions. Returns `true` once every project has been
+    /// migrated and the schema version was bumped; calling it when storage
+    /// is already current is a no-op returning `true`.
+    ///
+    /// New projects cannot be registered until the migration completes.
+    ///
+    /// - `caller` must be the `SuperAdmin`.
+    pub fn migrate(env: Env, caller: Address, limit: u32) -> bool {
+        caller.require_auth();
+        Self::do_migrate(&env, &caller, limit)
+    }
+
+    // ─────────────────────────────────────────────────────────
+    // Governance
+    // ─────────────────────────────────────────────────────────
+
+    /// Queue an administrative action for execution after `GOVERNANCE_DELAY`.
+    ///
+    /// `caller` must hold the authority the action needs (SuperAdmin for
+    /// protocol config and SuperAdmin grants, Admin or above otherwise); it is
+    /// checked again when the proposal is executed. Returns the proposal ID.
+    pub fn propose_action(env: Env, caller: Address, action: GovAction) -> u64 {
+        caller.require_auth();
+        Self::do_propose_action(&env, &caller, action)
+    }
+
+    /// Cancel a queued proposal during its delay.
+    ///
+    /// - `caller` must be the `SuperAdmin`.
+    pub fn cancel_proposal(env: Env, caller: Address, proposal_id: u64) {
+        caller.require_auth();
+        Self::do_cancel_proposal(&env, &caller, proposal_id);
+    }
+
+    /// Execute a queued proposal once its ETA has passed.
+    ///
+    /// Permissionless: anyone may trigger execution. The action is applied
+    /// with the proposer's authority, which must still be valid.
+    pub fn execute_proposal(env: Env, proposal_id: u64) {
+        let proposal = match storage::get_proposal(&env, proposal_id) {
+            Some(p) => p,
+            None => panic_with_error!(&env, Error::ProposalNotFound),
+        };
+        if env.ledger().timestamp() < proposal.eta {
+            panic_with_error!(&env, Error::TimelockNotElapsed);
+        }
+
+        storage::remove_proposal(&env, proposal_id);
+        Self::apply_action(&env, &proposal.proposer, proposal.action.clone());
+        events::emit_proposal_executed(&env, proposal_id, proposal.action);
+    }
+
+    /// Return a queued proposal, if it exists.
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Option<Proposal> {
+        storage::get_proposal(&env, proposal_id)
+    }
+
+    // ─────────────────────────────────────────────────────────
+    // Admin council
+    // ─────────────────────────────────────────────────────────
+
+    /// Put the protocol under a multisig admin council.
+    ///
+    /// The SuperAdmin role moves from `caller` to the contract's own address;
+    /// from then on SuperAdmin actions are taken through `council_propose`,
+    /// `council_approve` and `council_execute`, and no other address holds
+    /// SuperAdmin. Later changes to the council go through
+    /// `CouncilAction::SetCouncil`.
+    ///
+    /// - `caller` must be the `SuperAdmin`.
+    /// - `signers`: 1–20 unique addresses; `threshold`: 1 ≤ threshold ≤ signers.
+    pub fn set_council(env: Env, caller: Address, signers: Vec<Address>, threshold: u32) {
+        caller.require_auth();
+        rbac::require_super_admin(&env, &caller);
+
+        rbac::store_council(&env, &signers, threshold);
+        rbac::transfer_super_admin(&env, &caller, &env.current_contract_address());
+    }
+
+    /// Return the admin council, if one is configured.
+    pub fn get_council(env: Env) -> Option<Council> {
+        rbac::get_council(&env)
+    }
+
+    /// Propose a SuperAdmin action on behalf of the council.
+    ///
+    /// The proposer's approval is recorded immediately. The proposal must be
+    /// executed within 14 days, after which it expires. Returns the council
+    /// proposal ID.
+    ///
+    /// - `signer` must be a council member.
+    pub fn council_propose(env: Env, signer: Address, action: CouncilAction) -> u64 {
+        signer.require_auth();
+        rbac::create_council_proposal(&env, &signer, action)
+    }
+
+    /// Approve a council proposal. Returns the number of approvals so far.
+    ///
+    /// - `signer` must be a council member and may approve each proposal once.
+    pub fn council_approve(env: Env, signer: Address, proposal_id: u64) -> u32 {
+        signer.require_auth();
+        rbac::approve_council_proposal(&env, &signer, proposal_id)
+    }
+
+    /// Execute a council proposal that reached the approval threshold.
+    ///
+    /// Permissionless. The action runs with the contract address, which holds
+    /// the SuperAdmin role, as caller.
+    pub fn council_execute(env: Env, proposal_id: u64) {
+        let action = rbac::take_approved_council_action(&env, proposal_id);
+        let council = env.current_contract_address();
+
+        match action.clone() {
+            CouncilAction::Apply(gov_action) => Self::apply_direct(&env, &council, gov_action),
+            CouncilAction::Propose(gov_action) => {
+                Self::do_propose_action(&env, &council, gov_action);
+            }
+            CouncilAction::CancelProposal(id) => Self::do_cancel_proposal(&env, &council, id),
+            CouncilAction::ProposeUpgrade(wasm_hash) => {
+                Self::do_propose_upgrade(&env, &council, wasm_hash);
+            }
+            CouncilAction::CancelUpgrade => Self::do_cancel_upgrade(&env, &council),
+            CouncilAction::Migrate(limit) => {
+                Self::do_migrate(&env, &council, limit);
+            }
+            CouncilAction::SetCouncil(signers, threshold) => {
+                rbac::store_council(&env, &signers, threshold);
+            }
+            CouncilAction::TransferSuperAdmin(new_super_admin) => {
+                rbac::clear_council(&env);
+                rbac::transfer_super_admin(&env, &council, &new_super_admin);
+            }
+            // Replaces the running code, so it must come last.
+            CouncilAction::ExecuteUpgrade => {
+                events::emit_council_executed(&env, proposal_id, action);
+                Self::do_execute_upgrade(&env, &council);
+                return;
+            }
+        }
+
+        events::emit_council_executed(&env, proposal_id, action);
+    }
+
+    /// Withdraw a council proposal without executing it.
+    ///
+    /// - `signer` must be a council member: the proposer, or any signer once
+    ///   the proposal has expired.
+    pub fn council_cancel(env: Env, signer: Address, proposal_id: u64) {
+        signer.require_auth();
+        rbac::cancel_council_proposal(&env, &signer, proposal_id);
+    }
+
+    /// Return a council proposal awaiting approvals, if it exists.
+    pub fn get_council_proposal(env: Env, proposal_id: u64) -> Option<CouncilProposal> {
+        rbac::get_council_proposal(&env, proposal_id)
+    }
+
     // ─────────────────────────────────────────────────────────
     // Project lifecycle
     // ─────────────────────────────────────────────────────────
@@ -189,6 +625,13 @@ impl PifpProtocol {
     /// Register a new funding project.
     ///
     /// `creator` must hold the `ProjectManager`, `Admin`, or `SuperAdmin` role.
+    /// Every token in `accepted_tokens` must be in the protocol's token
+    /// registry (see `add_supported_token`).
+    ///
+    /// `milestones` is an optional ordered list of proof-gated tranches. When
+    /// empty, the project is released in one shot by `verify_and_release`
+    /// against `proof_hash`; otherwise each tranche is released separately via
+    /// `verify_milestone` and the shares must sum to 10 000 bps.
     pub fn register_project(
         env: Env,
         creator: Address,
@@ -198,12 +641,19 @@ impl PifpProtocol {
         metadata_uri: Bytes,
         deadline: u64,
         is_private: bool,
+        milestones: Vec<Milestone>,
     ) -> Project {
-        Self::require_not_paused(&env);
+        Self::require_not_paused_for(&env, |f| f.registrations);
         creator.require_auth();
         // RBAC gate: only authorised roles may create projects.
         rbac::require_can_register(&env, &creator);
 
+        // New projects are indexed in the current layout; a half-finished
+        // migration would count them twice.
+        if storage::get_schema_version(&env) < CURRENT_SCHEMA_VERSION {
+            panic_with_error!(&env, Error::MigrationPending);
+        }
+
         if accepted_tokens.is_empty() {
             panic_with_error!(&env, Error::EmptyAcceptedTokens);
         }
@@ -236,6 +686,29 @@ impl PifpProtocol {
             panic_with_error!(&env, Error::InvalidDeadline);
         }
 
+        // Milestone shares must be non-zero and cover the whole raised balance.
+        if milestones.len() > MAX_MILESTONES {
+            panic_with_error!(&env, Error::InvalidMilestones);
+        }
+        if !milestones.is_empty() {
+            let mut total_bps: u32 = 0;
+            for milestone in milestones.iter() {
+                if milestone.share_bps == 0 {
+                    panic_with_error!(&env, Error::InvalidMilestones);
+                }
+                total_bps = total_bps.saturating_add(milestone.share_bps);
+            }
+            if total_bps != TOTAL_MILESTONE_BPS {
+                panic_with_error!(&env, Error::InvalidMilestones);
+            }
+        }
+
+        for token in accepted_tokens.iter() {
+            if storage::get_supported_token(&env, &token).is_none() {
+                panic_with_error!(&env, Error::TokenNotSupported);
+            }
+        }
+
         let id = get_and_increment_project_id(&env);
         let project = Project {
             id,
@@ -249,6 +722,10 @@ impl PifpProtocol {
             donation_count: 0,
             is_private,
             refund_expiry: 0,
+            milestones,
+            released_milestones: 0,
+            limits: DonationLimits::default(),
+            merkle_root: None,
         };
 
         save_project(&env, &project);
@@ -306,6 +783,41 @@ impl PifpProtocol {
         events::emit_deadline_extended(&env, project_id, old_deadline, new_deadline);
     }
 
+    /// Set a project's contribution limits.
+    ///
+    /// - `caller` must be the project creator or an Admin.
+    /// - Only possible while the project is `Funding` and has no donations,
+    ///   so every donor is held to the same limits.
+    pub fn set_donation_limits(
+        env: Env,
+        caller: Address,
+        project_id: u64,
+        limits: DonationLimits,
+    ) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
+        }
+        if state.status != ProjectStatus::Funding || state.donation_count > 0 {
+            panic_with_error!(&env, Error::DonationLimitsLocked);
+        }
+
+        if limits.min_donation < 0
+            || limits.max_per_donor < 0
+            || (limits.hard_cap != 0 && limits.hard_cap < config.goal)
+            || (limits.max_per_donor != 0 && limits.max_per_donor < limits.min_donation)
+        {
+            panic_with_error!(&env, Error::InvalidDonationLimits);
+        }
+
+        config.limits = limits.clone();
+        save_project_config(&env, project_id, &config);
+        events::emit_donation_limits_set(&env, project_id, limits);
+    }
+
     /// Add an address to a project's whitelist.
     ///
     /// - `caller` must be the project creator or an Admin.
@@ -338,438 +850,3063 @@ impl PifpProtocol {
         events::emit_whitelist_removed(&env, project_id, address);
     }
 
-    pub fn get_project(env: Env, id: u64) -> Project {
-        load_project(&env, id)
-    }
+    /// Add up to 50 addresses to a project's whitelist in one call.
+    ///
+    /// - `caller` must be the project creator or an Admin.
+    pub fn add_to_whitelist_batch(
+        env: Env,
+        caller: Address,
+        project_id: u64,
+        addresses: Vec<Address>,
+    ) {
+        caller.require_auth();
+        Self::require_whitelist_manager(&env, &caller, project_id, &addresses);
 
-    /// Return the immutable metadata URI attached to a project.
-    pub fn get_project_metadata(env: Env, project_id: u64) -> Bytes {
-        let config = storage::load_project_config(&env, project_id);
-        config.metadata_uri
+        for address in addresses.iter() {
+            storage::add_to_whitelist(&env, project_id, &address);
+            events::emit_whitelist_added(&env, project_id, address);
+        }
     }
 
-    /// Return the balance of `token` for `project_id`.
-    pub fn get_balance(env: Env, project_id: u64, token: Address) -> i128 {
-        storage::get_token_balance(&env, project_id, &token)
+    /// Remove up to 50 addresses from a project's whitelist in one call.
+    ///
+    /// - `caller` must be the project creator or an Admin.
+    pub fn remove_from_whitelist_batch(
+        env: Env,
+        caller: Address,
+        project_id: u64,
+        addresses: Vec<Address>,
+    ) {
+        caller.require_auth();
+        Self::require_whitelist_manager(&env, &caller, project_id, &addresses);
+
+        for address in addresses.iter() {
+            storage::remove_from_whitelist(&env, project_id, &address);
+            events::emit_whitelist_removed(&env, project_id, address);
+        }
     }
 
-    /// Return the current per-token balances for a project.
+    /// Set or clear the Merkle root of a project's donor allowlist.
     ///
-    /// Reconstructs the balance snapshot from persistent storage for every
-    /// token that was accepted at registration time.
+    /// Donors proven against the root with `deposit_with_proof` may donate to
+    /// a private project without a whitelist entry. Leaves are
+    /// `sha256(donor.to_xdr())` and each parent is the sha256 of its two
+    /// children in ascending byte order.
     ///
-    /// # Errors
-    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
-    pub fn get_project_balances(env: Env, project_id: u64) -> ProjectBalances {
-        let project = match maybe_load_project(&env, project_id) {
-            Some(p) => p,
-            None => panic_with_error!(&env, Error::ProjectNotFound),
-        };
-        get_all_balances(&env, &project)
+    /// - `caller` must be the project creator or an Admin.
+    pub fn set_merkle_root(
+        env: Env,
+        caller: Address,
+        project_id: u64,
+        merkle_root: Option<BytesN<32>>,
+    ) {
+        caller.require_auth();
+        let mut config = storage::load_project_config(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
+        }
+
+        config.merkle_root = merkle_root.clone();
+        save_project_config(&env, project_id, &config);
+        events::emit_merkle_root_set(&env, project_id, merkle_root);
+    }
+
+    /// Propose `new_owner` as the owner of a project.
+    ///
+    /// The transfer completes once `new_owner` calls
+    /// [`accept_project_ownership`]. Proposing again replaces the pending owner.
+    ///
+    /// - `caller` must be the project creator. Admins cannot propose, as the
+    ///   new owner could then redirect payouts with `set_beneficiaries`.
+    pub fn transfer_project_ownership(
+        env: Env,
+        caller: Address,
+        project_id: u64,
+        new_owner: Address,
+    ) {
+        caller.require_auth();
+        let config = storage::load_project_config(&env, project_id);
+        if caller != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+
+        storage::set_pending_owner(&env, project_id, &new_owner);
+        events::emit_ownership_transfer_proposed(&env, project_id, config.creator, new_owner);
+    }
+
+    /// Accept a pending ownership transfer and become the project's creator.
+    ///
+    /// The project moves from the previous owner's creator listing to
+    /// `new_owner`'s. Beneficiaries set by the previous owner are cleared,
+    /// so payouts go to `new_owner` until they set their own.
+    pub fn accept_project_ownership(env: Env, new_owner: Address, project_id: u64) {
+        new_owner.require_auth();
+        if storage::get_pending_owner(&env, project_id) != Some(new_owner.clone()) {
+            panic_with_error!(&env, Error::NoPendingTransfer);
+        }
+
+        let mut config = storage::load_project_config(&env, project_id);
+        let previous_owner = config.creator;
+        config.creator = new_owner.clone();
+        save_project_config(&env, project_id, &config);
+        storage::remove_pending_owner(&env, project_id);
+        storage::remove_creator_project(&env, &previous_owner, project_id);
+        storage::append_creator_project(&env, &new_owner, project_id);
+        storage::set_beneficiaries(&env, project_id, &Vec::new(&env));
+
+        events::emit_ownership_transferred(&env, project_id, previous_owner, new_owner);
+    }
+
+    /// Return the proposed new owner of a project, if a transfer is pending.
+    pub fn get_pending_owner(env: Env, project_id: u64) -> Option<Address> {
+        storage::get_pending_owner(&env, project_id)
+    }
+
+    /// Send a project's released and reclaimed funds to weighted beneficiaries,
+    /// e.g. an organisation's treasury, instead of the creator.
+    ///
+    /// Each `Beneficiary.share_bps` is a share of the payout after fees; the
+    /// shares must sum to 10 000. An empty list pays the creator again.
+    ///
+    /// - `caller` must be the project creator.
+    pub fn set_beneficiaries(
+        env: Env,
+        caller: Address,
+        project_id: u64,
+        beneficiaries: Vec<Beneficiary>,
+    ) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        let config = storage::load_project_config(&env, project_id);
+        if caller != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        Self::validate_beneficiaries(&env, &beneficiaries);
+
+        storage::set_beneficiaries(&env, project_id, &beneficiaries);
+        events::emit_beneficiaries_set(&env, project_id, beneficiaries);
+    }
+
+    /// Return a project's beneficiaries; empty when funds go to the creator.
+    pub fn get_beneficiaries(env: Env, project_id: u64) -> Vec<Beneficiary> {
+        storage::get_beneficiaries(&env, project_id)
+    }
+
+    pub fn get_project(env: Env, id: u64) -> Project {
+        load_project(&env, id)
+    }
+
+    /// Return the current metadata URI attached to a project.
+    pub fn get_project_metadata(env: Env, project_id: u64) -> Bytes {
+        let config = storage::load_project_config(&env, project_id);
+        config.metadata_uri
+    }
+
+    /// Replace a project's metadata URI, keeping the previous ones in its
+    /// metadata history.
+    ///
+    /// The history holds the 10 most recent versions; older ones are dropped.
+    ///
+    /// - `caller` must be the project creator or an Admin.
+    /// - Only possible while the project is `Funding` or `Active`.
+    pub fn update_metadata(env: Env, caller: Address, project_id: u64, metadata_uri: Bytes) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
+        }
+        if env.ledger().timestamp() >= config.deadline {
+            panic_with_error!(&env, Error::ProjectExpired);
+        }
+        if !matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active) {
+            panic_with_error!(&env, Error::ProjectNotActive);
+        }
+        if metadata_uri.is_empty() || metadata_uri.len() > MAX_METADATA_URI_LEN {
+            panic_with_error!(&env, Error::MetadataCidInvalid);
+        }
+
+        let mut history = Self::metadata_history(&env, &config);
+        let version = match history.last() {
+            Some(latest) => latest.version + 1,
+            None => 0,
+        };
+        if history.len() >= MAX_METADATA_VERSIONS {
+            history.pop_front();
+        }
+        history.push_back(MetadataVersion {
+            version,
+            metadata_uri: metadata_uri.clone(),
+            updated_at: env.ledger().timestamp(),
+        });
+        storage::set_metadata_history(&env, project_id, &history);
+
+        config.metadata_uri = metadata_uri.clone();
+        save_project_config(&env, project_id, &config);
+
+        events::emit_metadata_updated(&env, project_id, version, metadata_uri, caller);
+    }
+
+    /// Return the most recent metadata versions of a project, oldest first.
+    ///
+    /// A project whose metadata was never updated has a single version 0.
+    pub fn get_metadata_history(env: Env, project_id: u64) -> Vec<MetadataVersion> {
+        let config = storage::load_project_config(&env, project_id);
+        Self::metadata_history(&env, &config)
+    }
+
+    /// Return the balance of `token` for `project_id`.
+    pub fn get_balance(env: Env, project_id: u64, token: Address) -> i128 {
+        storage::get_token_balance(&env, project_id, &token)
+    }
+
+    /// Return the current per-token balances for a project.
+    ///
+    /// Reconstructs the balance snapshot from persistent storage for every
+    /// token that was accepted at registration time.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_project_balances(env: Env, project_id: u64) -> ProjectBalances {
+        let project = match maybe_load_project(&env, project_id) {
+            Some(p) => p,
+            None => panic_with_error!(&env, Error::ProjectNotFound),
+        };
+        get_all_balances(&env, &project)
+    }
+
+    /// List up to `limit` projects by ascending ID, starting at `start_id`.
+    ///
+    /// `limit` is capped at 30. Pass the last returned ID + 1 as the next
+    /// `start_id`; an empty result means there are no more projects.
+    pub fn list_projects(env: Env, start_id: u64, limit: u32) -> Vec<Project> {
+        let end_id = storage::get_project_count(&env)
+            .min(start_id.saturating_add(limit.min(MAX_PAGE_SIZE) as u64));
+
+        let mut projects = Vec::new(&env);
+        let mut id = start_id;
+        while id < end_id {
+            if let Some(project) = maybe_load_project(&env, id) {
+                projects.push_back(project);
+            }
+            id += 1;
+        }
+        projects
+    }
+
+    /// List up to `limit` projects owned by `creator`, oldest first.
+    ///
+    /// `cursor` is the position in the creator's list to start from (0 for
+    /// the first page, then the previous cursor plus `limit`). `limit` is
+    /// capped at 30. Transferring a project away moves the creator's newest
+    /// project into its slot, so the order is only kept until a transfer.
+    pub fn list_projects_by_creator(
+        env: Env,
+        creator: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<Project> {
+        let end = storage::get_creator_project_count(&env, &creator)
+            .min(cursor.saturating_add(limit.min(MAX_PAGE_SIZE)));
+
+        let mut projects = Vec::new(&env);
+        let mut index = cursor;
+        while index < end {
+            if let Some(project) = storage::get_creator_project(&env, &creator, index)
+                .and_then(|id| maybe_load_project(&env, id))
+            {
+                projects.push_back(project);
+            }
+            index += 1;
+        }
+        projects
+    }
+
+    /// Return the number of projects `creator` currently owns, whether
+    /// registered or received through a transfer.
+    pub fn get_creator_project_count(env: Env, creator: Address) -> u32 {
+        storage::get_creator_project_count(&env, &creator)
+    }
+
+    /// Return the number of projects in each lifecycle state.
+    ///
+    /// Projects past their deadline are counted under their stored status
+    /// until an entry point (e.g. `expire_project`) lazily expires them.
+    pub fn count_by_status(env: Env) -> StatusCounts {
+        storage::get_status_counts(&env)
+    }
+
+    // ─────────────────────────────────────────────────────────
+    // Donation receipts
+    // ─────────────────────────────────────────────────────────
+
+    /// List up to `limit` of `donor`'s donation receipts, oldest first.
+    ///
+    /// Each receipt covers one project and token and is updated by every
+    /// later donation there, and by every refund or withdrawal in its
+    /// `refunded` total. `cursor` is the position to start from (0 for
+    /// the first page, then the previous cursor plus `limit`). `limit` is
+    /// capped at 30.
+    pub fn get_receipts(
+        env: Env,
+        donor: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<DonationReceipt> {
+        let end = storage::get_receipt_count(&env, &donor)
+            .min(cursor.saturating_add(limit.min(MAX_PAGE_SIZE)));
+
+        let mut receipts = Vec::new(&env);
+        let mut index = cursor;
+        while index < end {
+            if let Some(receipt) = storage::get_receipt(&env, &donor, index) {
+                receipts.push_back(receipt);
+            }
+            index += 1;
+        }
+        receipts
+    }
+
+    /// SEP-41 `balance`: the number of donation receipts `id` holds, not an
+    /// amount of any donated token.
+    ///
+    /// Each project and token the address donated to counts as one unit,
+    /// whether or not the donation was later refunded; read the amounts with
+    /// [`get_receipts`]. Receipts are soulbound; the contract exposes no
+    /// transfer or approve functions for them.
+    pub fn balance(env: Env, id: Address) -> i128 {
+        storage::get_receipt_count(&env, &id) as i128
+    }
+
+    /// SEP-41 `decimals`: receipts are whole units.
+    pub fn decimals(_env: Env) -> u32 {
+        0
+    }
+
+    /// SEP-41 `name` of the donation receipt.
+    pub fn name(env: Env) -> String {
+        String::from_str(&env, RECEIPT_NAME)
+    }
+
+    /// SEP-41 `symbol` of the donation receipt.
+    pub fn symbol(env: Env) -> String {
+        String::from_str(&env, RECEIPT_SYMBOL)
+    }
+
+    /// Deposit funds into a project.
+    ///
+    /// The `token` must be one of the project's accepted tokens.
+    pub fn deposit(env: Env, project_id: u64, donator: Address, token: Address, amount: i128) {
+        Self::do_deposit(&env, project_id, donator, token, amount, None);
+    }
+
+    /// Deposit funds into a private project, proving the donor is on its
+    /// Merkle allowlist.
+    ///
+    /// `proof` holds the sibling hashes from the donor's leaf up to the root
+    /// set with `set_merkle_root`. Donors with a whitelist entry may use
+    /// `deposit` instead. Otherwise behaves exactly like `deposit`.
+    pub fn deposit_with_proof(
+        env: Env,
+        project_id: u64,
+        donator: Address,
+        token: Address,
+        amount: i128,
+        proof: Vec<BytesN<32>>,
+    ) {
+        Self::do_deposit(&env, project_id, donator, token, amount, Some(proof));
+    }
+
+    /// Deposit funds into a project behind a hash commitment.
+    ///
+    /// Only the commitment is recorded — no `DonatorBalance` entry is written
+    /// and the emitted event carries no donor address. The commitment must be
+    /// `sha256(owner.to_xdr() ‖ salt)` where `owner` is the address that will
+    /// later `reveal` the donation or receive its refund. Using a fresh
+    /// one-time `funder` account keeps the token transfer unlinkable too.
+    ///
+    /// Not available for private projects, whose whitelist needs the donor's
+    /// identity.
+    pub fn deposit_committed(
+        env: Env,
+        project_id: u64,
+        funder: Address,
+        token: Address,
+        amount: i128,
+        commitment: BytesN<32>,
+    ) {
+        Self::require_not_paused_for(&env, |f| f.deposits);
+        Self::require_not_frozen(&env, project_id);
+        funder.require_auth();
+
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        let (config, mut state) = Self::load_depositable_project(&env, project_id, &token);
+
+        if config.is_private {
+            panic_with_error!(&env, Error::NotWhitelisted);
+        }
+        if storage::get_commitment(&env, project_id, &commitment).is_some() {
+            panic_with_error!(&env, Error::CommitmentExists);
+        }
+        let amount = match Self::accepted_amount(&env, &config, &token, None, amount) {
+            Ok(a) => a,
+            Err(e) => panic_with_error!(&env, e),
+        };
+
+        state.donation_count += 1;
+        save_project_state(&env, project_id, &state);
+
+        let token_client = token::Client::new(&env, &token);
+        token_client.transfer(&funder, env.current_contract_address(), &amount);
+
+        Self::credit_project(&env, &config, &mut state, &token, amount);
+
+        storage::set_commitment(
+            &env,
+            project_id,
+            &commitment,
+            &CommittedDeposit {
+                token: token.clone(),
+                amount,
+            },
+        );
+        storage::append_project_commitment(&env, project_id, &commitment);
+
+        events::emit_committed_deposit(&env, project_id, commitment, token, amount);
+    }
+
+    /// Reveal a committed donation, converting it into a regular donor balance.
+    ///
+    /// `donator` must be the owner bound into the commitment and proves it
+    /// with `salt`. Afterwards the donation behaves exactly like a `deposit`
+    /// (including `refund`), so revealing is only possible while the project
+    /// still accepts deposits and the donor stays within `max_per_donor`.
+    /// Commitments on expired, cancelled or failed projects are refunded
+    /// with `refund_committed` instead.
+    pub fn reveal(
+        env: Env,
+        project_id: u64,
+        commitment: BytesN<32>,
+        donator: Address,
+        salt: BytesN<32>,
+    ) {
+        Self::require_not_paused_for(&env, |f| f.deposits);
+        donator.require_auth();
+        Self::require_commitment_owner(&env, &commitment, &donator, &salt);
+
+        let (config, mut state) = load_project_pair(&env, project_id);
+        if env.ledger().timestamp() >= config.deadline {
+            panic_with_error!(&env, Error::ProjectExpired);
+        }
+        match state.status {
+            ProjectStatus::Funding | ProjectStatus::Active => {}
+            ProjectStatus::Expired => panic_with_error!(&env, Error::ProjectExpired),
+            _ => panic_with_error!(&env, Error::ProjectNotActive),
+        }
+
+        let committed = match storage::get_commitment(&env, project_id, &commitment) {
+            Some(c) => c,
+            None => panic_with_error!(&env, Error::CommitmentNotFound),
+        };
+        let current_donor_balance =
+            storage::get_donator_balance(&env, project_id, &committed.token, &donator);
+        if let Err(e) = Self::check_donor_cap(&config, current_donor_balance, committed.amount) {
+            panic_with_error!(&env, e);
+        }
+        storage::remove_commitment(&env, project_id, &commitment);
+
+        // The commitment was counted as its own donation; don't count the
+        // donor twice if they already hold a balance for this token.
+        if current_donor_balance > 0 {
+            state.donation_count = state.donation_count.saturating_sub(1);
+            save_project_state(&env, project_id, &state);
+        }
+        storage::add_to_donator_balance(
+            &env,
+            project_id,
+            &committed.token,
+            &donator,
+            committed.amount,
+        );
+        storage::index_project_donor(&env, project_id, &donator);
+        storage::record_receipt(&env, &donator, project_id, &committed.token, committed.amount);
+
+        events::emit_commitment_revealed(
+            &env,
+            project_id,
+            commitment,
+            donator,
+            committed.token,
+            committed.amount,
+        );
+    }
+
+    /// Refund a committed donation from an expired or cancelled project.
+    ///
+    /// Anyone may submit the call: the refund can only go to the `recipient`
+    /// bound into the commitment, proven with `salt`. The same refund window
+    /// as `refund` applies.
+    pub fn refund_committed(
+        env: Env,
+        project_id: u64,
+        commitment: BytesN<32>,
+        recipient: Address,
+        salt: BytesN<32>,
+    ) {
+        Self::require_commitment_owner(&env, &commitment, &recipient, &salt);
+
+        let (config, state) = Self::load_refundable_project(&env, project_id);
+
+        let committed = match storage::get_commitment(&env, project_id, &commitment) {
+            Some(c) => c,
+            None => panic_with_error!(&env, Error::CommitmentNotFound),
+        };
+
+        let refund_amount = Self::refundable_amount(&env, &config, &state, committed.amount)
+            .min(storage::get_token_balance(&env, project_id, &committed.token));
+
+        // Remove first to prevent double-refund/reentrancy patterns.
+        storage::remove_commitment(&env, project_id, &commitment);
+        storage::add_to_token_balance(&env, project_id, &committed.token, -refund_amount);
+
+        if refund_amount > 0 {
+            let token_client = token::Client::new(&env, &committed.token);
+            token_client.transfer(&env.current_contract_address(), &recipient, &refund_amount);
+        }
+        storage::record_receipt_refund(&env, &recipient, project_id, &committed.token, refund_amount);
+
+        events::emit_committed_refunded(&env, project_id, commitment, refund_amount);
+    }
+
+    /// Mark an active project as cancelled.
+    ///
+    /// - `caller` must be `SuperAdmin` or `ProjectManager`.
+    /// - If `caller` is `ProjectManager`, it must be the project's creator.
+    /// - Only projects in `Active` status may be cancelled.
+    pub fn cancel_project(env: Env, caller: Address, project_id: u64) {
+        caller.require_auth();
+        rbac::require_can_cancel_project(&env, &caller);
+
+        let (config, mut state) = load_project_pair(&env, project_id);
+
+        if env.ledger().timestamp() >= config.deadline
+            && matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active)
+        {
+            state.status = ProjectStatus::Expired;
+            state.refund_expiry = env.ledger().timestamp() + REFUND_WINDOW;
+            save_project_state(&env, project_id, &state);
+            panic_with_error!(&env, Error::ProjectExpired);
+        }
+
+        if !rbac::has_role(&env, caller.clone(), Role::SuperAdmin)
+            && caller != config.creator
+        {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+
+        if state.status != ProjectStatus::Active {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+
+        state.status = ProjectStatus::Cancelled;
+        state.refund_expiry = env.ledger().timestamp() + REFUND_WINDOW;
+        save_project_state(&env, project_id, &state);
+        events::emit_project_cancelled(&env, project_id, caller);
+    }
+
+    /// Refund a donator from a cancelled or expired project that was not verified.
+    ///
+    /// Donors must claim their refund within the 6-month refund window.
+    /// After the window expires, only the creator may reclaim unclaimed funds
+    /// via [`reclaim_expired_funds`].
+    pub fn refund(env: Env, donator: Address, project_id: u64, token: Address) {
+        donator.require_auth();
+
+        let (config, state) = Self::load_refundable_project(&env, project_id);
+
+        if storage::get_donator_balance(&env, project_id, &token, &donator) <= 0 {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+        Self::refund_token(&env, &config, &state, &donator, &token, false);
+    }
+
+    /// Refund a donator's balances in every accepted token of a cancelled or
+    /// expired project in one call.
+    ///
+    /// # Errors
+    /// `InsufficientBalance` if the donator holds no balance in any token.
+    pub fn refund_all(env: Env, donator: Address, project_id: u64) {
+        donator.require_auth();
+
+        let (config, state) = Self::load_refundable_project(&env, project_id);
+
+        let mut refunded_any = false;
+        for token in config.accepted_tokens.iter() {
+            if storage::get_donator_balance(&env, project_id, &token, &donator) > 0 {
+                Self::refund_token(&env, &config, &state, &donator, &token, false);
+                refunded_any = true;
+            }
+        }
+        if !refunded_any {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+    }
+
+    /// Push refunds to the next batch of a cancelled or expired project's
+    /// donors, without them having to claim.
+    ///
+    /// Permissionless. Visits at most `limit` donors (capped at 30) from a
+    /// stored cursor and refunds each one in every accepted token. A transfer
+    /// that fails (e.g. the donor has no trustline) is skipped and the
+    /// balance stays claimable through `refund`. Returns `true` once every
+    /// recorded donor has been visited.
+    ///
+    /// Donations recorded before donor enumeration existed, and committed
+    /// deposits that were never revealed, are not in the donor list and must
+    /// still be claimed individually.
+    pub fn process_refunds(env: Env, project_id: u64, limit: u32) -> bool {
+        let (config, state) = Self::load_refundable_project(&env, project_id);
+
+        let donor_count = storage::get_project_donor_count(&env, project_id);
+        let mut cursor = storage::get_refund_cursor(&env, project_id);
+        let end = donor_count.min(cursor.saturating_add(limit.min(MAX_PAGE_SIZE)));
+        while cursor < end {
+            if let Some(donator) = storage::get_project_donor(&env, project_id, cursor) {
+                for token in config.accepted_tokens.iter() {
+                    Self::refund_token(&env, &config, &state, &donator, &token, true);
+                }
+            }
+            cursor += 1;
+        }
+        storage::set_refund_cursor(&env, project_id, cursor);
+
+        cursor >= donor_count
+    }
+
+    /// Extend the TTL of a project's storage entries so they are not archived.
+    ///
+    /// Permissionless, so creators, donors or a keeper bot can keep a
+    /// long-running project alive. Every call tops the project's config,
+    /// state, token balances, per-project settings and current round tally
+    /// up to the full 30-day TTL, then does the same for at most `limit`
+    /// list entries (capped at 30) from a stored cursor, walking donors
+    /// (donor-list entry and balance in every accepted token), then
+    /// recurring pledges, then committed donations. Returns `true` once all
+    /// three lists have been covered and emits `ProjectBumped` with the
+    /// ledger until which the whole pass is guaranteed live; the next call
+    /// starts a new pass.
+    ///
+    /// Whitelist entries, round contributions and donation receipts are not
+    /// walked; they are extended whenever they are read. Entries that were
+    /// already archived cannot be extended and must first be restored with a
+    /// `RestoreFootprint` operation.
+    ///
+    /// # Errors
+    /// * `ProjectNotFound` — no project with `project_id`.
+    pub fn bump_project(env: Env, project_id: u64, limit: u32) -> bool {
+        let config = storage::maybe_load_project_config(&env, project_id)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ProjectNotFound));
+        storage::extend_project_entries(&env, &config);
+
+        let started_ledger = env.ledger().sequence();
+        let mut progress = storage::get_bump_progress(&env, project_id).unwrap_or(BumpProgress {
+            next_donor: 0,
+            next_pledge: 0,
+            next_commitment: 0,
+            started_ledger,
+        });
+        let mut budget = limit.min(MAX_PAGE_SIZE);
+
+        let donor_count = storage::get_project_donor_count(&env, project_id);
+        while progress.next_donor < donor_count && budget > 0 {
+            storage::extend_donor_entries(
+                &env,
+                project_id,
+                progress.next_donor,
+                &config.accepted_tokens,
+            );
+            progress.next_donor += 1;
+            budget -= 1;
+        }
+        let pledge_count = storage::get_project_pledge_count(&env, project_id);
+        while progress.next_pledge < pledge_count && budget > 0 {
+            storage::extend_pledge_entries(&env, project_id, progress.next_pledge);
+            progress.next_pledge += 1;
+            budget -= 1;
+        }
+        let commitment_count = storage::get_project_commitment_count(&env, project_id);
+        while progress.next_commitment < commitment_count && budget > 0 {
+            storage::extend_commitment_entries(&env, project_id, progress.next_commitment);
+            progress.next_commitment += 1;
+            budget -= 1;
+        }
+
+        if progress.next_donor < donor_count
+            || progress.next_pledge < pledge_count
+            || progress.next_commitment < commitment_count
+        {
+            storage::set_bump_progress(&env, project_id, Some(&progress));
+            return false;
+        }
+        storage::set_bump_progress(&env, project_id, None);
+        let live_until = progress.started_ledger.saturating_add(storage::PERSISTENT_BUMP_AMOUNT);
+        events::emit_project_bumped(&env, project_id, live_until);
+        true
+    }
+
+    /// Withdraw some or all of a donation while the project is still `Funding`.
+    ///
+    /// Lets donors change their minds before the goal is reached instead of
+    /// waiting for the deadline. Withdrawals are locked once the project has
+    /// raised 90 % of its goal, or once the project is `Active`. Withdrawing
+    /// the full balance removes the donor from `donation_count`.
+    ///
+    /// # Errors
+    /// - `InvalidAmount` if `amount <= 0`.
+    /// - `ProjectExpired` / `ProjectNotActive` / `TokenNotAccepted` /
+    ///   `FundingClosed` as for `deposit`.
+    /// - `WithdrawalLocked` if the project is past the lock-in point.
+    /// - `InsufficientBalance` if `amount` exceeds the donor's balance.
+    pub fn withdraw_pledge(
+        env: Env,
+        donator: Address,
+        project_id: u64,
+        token: Address,
+        amount: i128,
+    ) {
+        Self::require_not_paused(&env);
+        donator.require_auth();
+
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        let (config, mut state) = Self::load_depositable_project(&env, project_id, &token);
+
+        if state.status != ProjectStatus::Funding {
+            panic_with_error!(&env, Error::WithdrawalLocked);
+        }
+        let lock_in = config.goal.saturating_mul(WITHDRAW_LOCK_IN_BPS);
+        if Self::funded_value(&env, &config).saturating_mul(10_000) >= lock_in {
+            panic_with_error!(&env, Error::WithdrawalLocked);
+        }
+
+        let donor_balance = storage::get_donator_balance(&env, project_id, &token, &donator);
+        if amount > donor_balance {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+
+        let remaining = donor_balance - amount;
+        storage::set_donator_balance(&env, project_id, &token, &donator, remaining);
+        storage::add_to_token_balance(&env, project_id, &token, -amount);
+        Self::track_round_contribution(&env, project_id, &token, &donator, -amount);
+        if remaining == 0 {
+            state.donation_count = state.donation_count.saturating_sub(1);
+            save_project_state(&env, project_id, &state);
+        }
+
+        let token_client = token::Client::new(&env, &token);
+        token_client.transfer(&env.current_contract_address(), &donator, &amount);
+        storage::record_receipt_refund(&env, &donator, project_id, &token, amount);
+
+        events::emit_pledge_withdrawn(&env, project_id, donator, token, amount);
+    }
+
+    /// Grant the Oracle role to `oracle`.
+    ///
+    /// Replaces the original `set_oracle(admin, oracle)`.
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    pub fn set_oracle(env: Env, caller: Address, oracle: Address) {
+        caller.require_auth();
+        Self::apply_direct(&env, &caller, GovAction::GrantRole(oracle, Role::Oracle));
+    }
+
+    /// Set the initial global protocol configuration.
+    ///
+    /// Only the first configuration may be set directly. Later changes must
+    /// be queued with `propose_action(GovAction::UpdateProtocolConfig)` so that
+    /// donors get `GOVERNANCE_DELAY` notice before fees change.
+    ///
+    /// - `caller` must be the `SuperAdmin`.
+    /// - `fee_bps` must be less than or equal to 1000 (10%).
+    pub fn update_protocol_config(env: Env, caller: Address, fee_recipient: Address, fee_bps: u32) {
+        caller.require_auth();
+        Self::apply_direct(
+            &env,
+            &caller,
+            GovAction::UpdateProtocolConfig(ProtocolConfig {
+                fee_recipient,
+                fee_bps,
+            }),
+        );
+    }
+
+    /// Return the global protocol configuration, if it has been set.
+    pub fn get_protocol_config(env: Env) -> Option<ProtocolConfig> {
+        get_protocol_config(&env)
+    }
+
+    /// Divide the platform fee between several recipients.
+    ///
+    /// Splits only change who receives the fee, not how much donors pay, so
+    /// they apply immediately. Each `FeeSplit.share_bps` is a share of the fee;
+    /// the shares must sum to 10 000. An empty list sends the whole fee to
+    /// `fee_recipient` again.
+    ///
+    /// - `caller` must be the `SuperAdmin`.
+    pub fn set_fee_splits(env: Env, caller: Address, splits: Vec<FeeSplit>) {
+        caller.require_auth();
+        Self::apply_direct(&env, &caller, GovAction::SetFeeSplits(splits));
+    }
+
+    /// Return the platform fee splits; empty when fees go to `fee_recipient`.
+    pub fn get_fee_splits(env: Env) -> Vec<FeeSplit> {
+        storage::get_fee_splits(&env)
+    }
+
+    /// Override the platform fee for one project, e.g. `Some(0)` for a
+    /// humanitarian partner. `None` removes the override.
+    ///
+    /// The effective fee is the smaller of the override and the global
+    /// `fee_bps`. Setting or lowering an override only cuts the fee and
+    /// applies at once; raising or removing one raises the fee on a project
+    /// donors already funded, so it must be queued with
+    /// `propose_action(GovAction::SetProjectFee)`.
+    ///
+    /// - `caller` must be the `SuperAdmin`.
+    pub fn set_project_fee(env: Env, caller: Address, project_id: u64, fee_bps: Option<u32>) {
+        caller.require_auth();
+        Self::apply_direct(&env, &caller, GovAction::SetProjectFee(project_id, fee_bps));
+    }
+
+    /// Return the fee, in basis points, that a release of `project_id`
+    /// would pay now.
+    pub fn get_effective_fee_bps(env: Env, project_id: u64) -> u32 {
+        match get_protocol_config(&env) {
+            Some(protocol) => Self::effective_fee_bps(&env, project_id, &protocol),
+            None => 0,
+        }
+    }
+
+    /// Set the number of distinct oracle attestations required before a
+    /// proof hash is accepted and funds are released.
+    ///
+    /// Only possible before the first project is registered; afterwards use
+    /// `propose_action(GovAction::SetOracleQuorum)`.
+    ///
+    /// - `caller` must be the `SuperAdmin`.
+    /// - `quorum` must be at least 1. The default (never set) is 1.
+    pub fn set_oracle_quorum(env: Env, caller: Address, quorum: u32) {
+        caller.require_auth();
+        Self::apply_direct(&env, &caller, GovAction::SetOracleQuorum(quorum));
+    }
+
+    /// Return the number of oracle attestations required to release funds.
+    pub fn get_oracle_quorum(env: Env) -> u32 {
+        storage::get_oracle_quorum(&env)
+    }
+
+    /// Set how long, in seconds, a verified release waits before it can be
+    /// finalized. During the window an `Auditor` may dispute the release.
+    ///
+    /// A window of 0 (the default) releases funds as soon as the oracle
+    /// quorum is reached. Releases already queued keep their deadline.
+    ///
+    /// Only possible before the first project is registered; afterwards use
+    /// `propose_action(GovAction::SetDisputeWindow)`.
+    ///
+    /// - `caller` must be the `SuperAdmin`.
+    /// - `window` must not exceed 30 days.
+    pub fn set_dispute_window(env: Env, caller: Address, window: u64) {
+        caller.require_auth();
+        Self::apply_direct(&env, &caller, GovAction::SetDisputeWindow(window));
+    }
+
+    /// Return the dispute window in seconds.
+    pub fn get_dispute_window(env: Env) -> u64 {
+        storage::get_dispute_window(&env)
+    }
+
+    /// Set the conversion rate used to count `token` towards funding goals.
+    ///
+    /// Rates are relative weights: a balance of `b` in `token` is worth
+    /// `b * rate / first_rate` units of a project's first accepted token.
+    /// Quote them per smallest unit so tokens with different decimals line up.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - `rate` must be strictly positive.
+    pub fn set_token_rate(env: Env, caller: Address, token: Address, rate: i128) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+
+        if rate <= 0 {
+            panic_with_error!(&env, Error::InvalidTokenRate);
+        }
+
+        let old_rate = storage::get_token_rate(&env, &token);
+        storage::set_token_rate(&env, &token, rate);
+        events::emit_token_rate_updated(&env, token, old_rate, rate);
+    }
+
+    /// Return the goal conversion rate of `token`, if one has been set.
+    pub fn get_token_rate(env: Env, token: Address) -> Option<i128> {
+        storage::get_token_rate(&env, &token)
+    }
+
+    /// Add `token` to the protocol's token registry, or update its settings.
+    ///
+    /// Projects may only list registered tokens, and donations are only
+    /// accepted in them, so creators cannot point donors at look-alike
+    /// contracts. `min_donation` applies to every donation of the token on
+    /// top of any per-project minimum; `decimals` is stored for front-ends
+    /// to display amounts.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - `min_donation` must not be negative.
+    /// - The registry holds at most 50 tokens.
+    pub fn add_supported_token(
+        env: Env,
+        caller: Address,
+        token: Address,
+        min_donation: i128,
+        decimals: u32,
+    ) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+
+        if min_donation < 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if storage::get_supported_token(&env, &token).is_none()
+            && storage::get_supported_tokens(&env).len() >= MAX_SUPPORTED_TOKENS
+        {
+            panic_with_error!(&env, Error::TooManyTokens);
+        }
+
+        let info = SupportedToken {
+            token,
+            min_donation,
+            decimals,
+        };
+        storage::set_supported_token(&env, &info);
+        events::emit_token_supported(&env, &info);
+    }
+
+    /// Remove `token` from the protocol's token registry.
+    ///
+    /// New projects can no longer list the token and no further donations
+    /// are accepted in it, including by projects that already list it.
+    /// Balances already held stay withdrawable, refundable and releasable.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    ///
+    /// # Errors
+    /// * `TokenNotSupported` — `token` is not in the registry.
+    pub fn remove_supported_token(env: Env, caller: Address, token: Address) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+
+        if storage::get_supported_token(&env, &token).is_none() {
+            panic_with_error!(&env, Error::TokenNotSupported);
+        }
+        storage::remove_supported_token(&env, &token);
+        events::emit_token_unsupported(&env, token);
+    }
+
+    /// Return the registry settings of `token`, or `None` if it is not supported.
+    pub fn get_supported_token(env: Env, token: Address) -> Option<SupportedToken> {
+        storage::get_supported_token(&env, &token)
+    }
+
+    /// Return every token in the registry, in the order they were added.
+    pub fn list_supported_tokens(env: Env) -> Vec<SupportedToken> {
+        let mut tokens = Vec::new(&env);
+        for token in storage::get_supported_tokens(&env).iter() {
+            if let Some(info) = storage::get_supported_token(&env, &token) {
+                tokens.push_back(info);
+            }
+        }
+        tokens
+    }
+
+    /// Return the combined value of a project's balances in units of its
+    /// first accepted token — the figure compared against `goal`.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_funded_value(env: Env, project_id: u64) -> i128 {
+        let config = storage::load_project_config(&env, project_id);
+        Self::funded_value(&env, &config)
+    }
+
+    /// Return how many distinct oracles have attested `proof_hash` for a project.
+    pub fn get_attestation_count(env: Env, project_id: u64, proof_hash: BytesN<32>) -> u32 {
+        storage::get_attestation_count(&env, project_id, &proof_hash)
+    }
+
+    /// Record an oracle's attestation of `proof_hash` for a project.
+    ///
+    /// `proof_hash` must be the project's `proof_hash` or the proof hash of
+    /// an unreleased milestone; anything else fails with `VerificationFailed`
+    /// and is not recorded. Once the tally for the hash reaches the oracle
+    /// quorum, the corresponding funds are released in the same call.
+    ///
+    /// - `oracle` must hold the `Oracle` role and may attest each hash once.
+    pub fn submit_attestation(
+        env: Env,
+        oracle: Address,
+        project_id: u64,
+        proof_hash: BytesN<32>,
+    ) {
+        Self::require_not_paused_for(&env, |f| f.releases);
+        oracle.require_auth();
+        rbac::require_oracle(&env, &oracle);
+
+        Self::process_attestation(&env, oracle, project_id, proof_hash);
+    }
+
+    /// Register or rotate the ed25519 public key an oracle signs attestations with.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - `oracle` must hold the `Oracle` role. Revoking the role drops the key.
+    pub fn set_oracle_key(env: Env, caller: Address, oracle: Address, public_key: BytesN<32>) {
+        caller.require_auth();
+        rbac::set_oracle_key(&env, &caller, &oracle, &public_key);
+    }
+
+    /// Return the ed25519 public key registered for `oracle`, if any.
+    pub fn get_oracle_key(env: Env, oracle: Address) -> Option<BytesN<32>> {
+        rbac::get_oracle_key(&env, &oracle)
+    }
+
+    /// Return the exact bytes an oracle must sign for `submit_signed_attestation`.
+    ///
+    /// Layout: `contract_id (XDR) ‖ project_id (u64 BE) ‖ proof_hash (32 bytes)
+    /// ‖ nonce (u64 BE) ‖ expiry (u64 BE)`.
+    pub fn get_attestation_payload(
+        env: Env,
+        project_id: u64,
+        proof_hash: BytesN<32>,
+        nonce: u64,
+        expiry: u64,
+    ) -> Bytes {
+        Self::attestation_payload(&env, project_id, &proof_hash, nonce, expiry)
+    }
+
+    /// Relay an attestation signed off-chain by a registered oracle key.
+    ///
+    /// Anyone may submit; the oracle does not need to authorise the
+    /// transaction or pay its fees. The signature must cover the payload
+    /// returned by `get_attestation_payload`, `expiry` must not have passed,
+    /// and each `nonce` can be used once per oracle. The attestation then
+    /// counts towards the quorum exactly like `submit_attestation`.
+    pub fn submit_signed_attestation(
+        env: Env,
+        oracle: Address,
+        project_id: u64,
+        proof_hash: BytesN<32>,
+        nonce: u64,
+        expiry: u64,
+        signature: BytesN<64>,
+    ) {
+        Self::require_not_paused_for(&env, |f| f.releases);
+        rbac::require_oracle(&env, &oracle);
+
+        if env.ledger().timestamp() > expiry {
+            panic_with_error!(&env, Error::AttestationExpired);
+        }
+
+        let public_key = match rbac::get_oracle_key(&env, &oracle) {
+            Some(key) => key,
+            None => panic_with_error!(&env, Error::OracleKeyNotSet),
+        };
+
+        // Panics (aborting the transaction) if the signature does not verify.
+        let payload = Self::attestation_payload(&env, project_id, &proof_hash, nonce, expiry);
+        env.crypto().ed25519_verify(&public_key, &payload, &signature);
+
+        rbac::consume_oracle_nonce(&env, &oracle, nonce);
+
+        Self::process_attestation(&env, oracle, project_id, proof_hash);
+    }
+
+    /// Verify proof of impact and release funds to the creator.
+    ///
+    /// The registered oracle submits a proof hash. If it matches the project's
+    /// stored `proof_hash`, the call counts as an attestation; once the oracle
+    /// quorum is reached the project status transitions to `Completed`. With
+    /// the default quorum of 1 the release happens immediately.
+    ///
+    /// NOTE: This is a mocked verification (hash equality).
+    /// The structure is prepared for future ZK-STARK verification.
+    ///
+    /// Reads the immutable config (for proof_hash) and mutable state (for status),
+    /// then writes back only the small state entry.
+    pub fn verify_and_release(
+        env: Env,
+        oracle: Address,
+        project_id: u64,
+        submitted_proof_hash: BytesN<32>,
+    ) {
+        Self::require_not_paused_for(&env, |f| f.releases);
+        oracle.require_auth();
+        // RBAC gate: caller must hold the Oracle role.
+        rbac::require_oracle(&env, &oracle);
+
+        // Optimised dual-read helper, including the lazy expiry and status checks.
+        let (config, mut state) = Self::load_verifiable_project(&env, project_id);
+
+        // Milestone projects are released tranche by tranche.
+        if !config.milestones.is_empty() {
+            panic_with_error!(&env, Error::InvalidMilestones);
+        }
+
+        // Mocked ZK verification: compare submitted hash to stored hash.
+        if submitted_proof_hash != config.proof_hash {
+            panic_with_error!(&env, Error::VerificationFailed);
+        }
+
+        let attestations = Self::attest(&env, project_id, &oracle, &submitted_proof_hash);
+        if attestations >= storage::get_oracle_quorum(&env) {
+            Self::queue_or_release(&env, &config, &mut state, None, oracle, submitted_proof_hash);
+        }
+    }
+
+    /// Verify a single milestone and release its tranche to the creator.
+    ///
+    /// The oracle submits the proof hash for milestone `milestone_index`. On a
+    /// match the call counts as an attestation; once the oracle quorum is
+    /// reached, the milestone's share of every accepted token's remaining
+    /// balance is paid out (minus the platform fee) and the milestone is
+    /// marked as released. Once the last milestone is released the project
+    /// transitions to `Completed`.
+    ///
+    /// Each tranche is computed relative to the shares still locked, so the
+    /// final milestone always drains whatever balance is left.
+    pub fn verify_milestone(
+        env: Env,
+        oracle: Address,
+        project_id: u64,
+        milestone_index: u32,
+        submitted_proof_hash: BytesN<32>,
+    ) {
+        Self::require_not_paused_for(&env, |f| f.releases);
+        oracle.require_auth();
+        // RBAC gate: caller must hold the Oracle role.
+        rbac::require_oracle(&env, &oracle);
+
+        let (config, mut state) = Self::load_verifiable_project(&env, project_id);
+
+        let milestone = match config.milestones.get(milestone_index) {
+            Some(m) => m,
+            None => panic_with_error!(&env, Error::MilestoneNotFound),
+        };
+        if state.released_milestones & (1u32 << milestone_index) != 0 {
+            panic_with_error!(&env, Error::MilestoneAlreadyReleased);
+        }
+
+        if submitted_proof_hash != milestone.proof_hash {
+            panic_with_error!(&env, Error::VerificationFailed);
+        }
+
+        let attestations = Self::attest(&env, project_id, &oracle, &submitted_proof_hash);
+        if attestations >= storage::get_oracle_quorum(&env) {
+            Self::queue_or_release(
+                &env,
+                &config,
+                &mut state,
+                Some(milestone_index),
+                oracle,
+                submitted_proof_hash,
+            );
+        }
+    }
+
+    /// Report that the proof of impact for a project was rejected.
+    ///
+    /// Counts as the oracle's rejection; once the number of distinct oracles
+    /// rejecting the project that still hold the Oracle role reaches the
+    /// oracle quorum, the project moves to `Failed` and donors can refund
+    /// immediately instead of waiting for the deadline. With the default
+    /// quorum of 1 this happens in the same call.
+    ///
+    /// - `oracle` must hold the `Oracle` role and may reject a project once.
+    /// - `reason_hash` identifies the off-chain verification report.
+    pub fn reject_verification(
+        env: Env,
+        oracle: Address,
+        project_id: u64,
+        reason_hash: BytesN<32>,
+    ) {
+        Self::require_not_paused(&env);
+        oracle.require_auth();
+        // RBAC gate: caller must hold the Oracle role.
+        rbac::require_oracle(&env, &oracle);
+
+        let (_, mut state) = Self::load_verifiable_project(&env, project_id);
+
+        if storage::has_rejected(&env, project_id, &oracle) {
+            panic_with_error!(&env, Error::AlreadyAttested);
+        }
+        storage::record_rejection(&env, project_id, &oracle);
+        if Self::valid_rejections(&env, project_id) < storage::get_oracle_quorum(&env) {
+            return;
+        }
+
+        state.status = ProjectStatus::Failed;
+        state.refund_expiry = env.ledger().timestamp() + REFUND_WINDOW;
+        save_project_state(&env, project_id, &state);
+
+        events::emit_project_failed(&env, project_id, oracle, reason_hash);
+    }
+
+    /// Return how many distinct oracles that still hold the Oracle role have
+    /// rejected the proof of a project.
+    pub fn get_rejection_count(env: Env, project_id: u64) -> u32 {
+        Self::valid_rejections(&env, project_id)
+    }
+
+    /// Dispute a verified release while it waits out the dispute window.
+    ///
+    /// The queued release is dropped and the attestations of its proof are
+    /// cleared, so a new release needs a fresh quorum of attestations. The
+    /// oracles that attested the disputed proof may attest it again.
+    ///
+    /// With `cancel` set the project is cancelled and donors can refund.
+    /// Otherwise it returns to the status it had before the release was
+    /// queued (or `Expired`, if its deadline has passed meanwhile).
+    ///
+    /// - `auditor` must hold the `Auditor` role.
+    /// - `reason_hash` identifies the off-chain dispute report.
+    pub fn dispute(
+        env: Env,
+        auditor: Address,
+        project_id: u64,
+        reason_hash: BytesN<32>,
+        cancel: bool,
+    ) {
+        auditor.require_auth();
+        rbac::require_auditor(&env, &auditor);
+
+        let (config, mut state) = load_project_pair(&env, project_id);
+        let queued = match storage::get_queued_release(&env, project_id) {
+            Some(q) if state.status == ProjectStatus::PendingRelease => q,
+            _ => panic_with_error!(&env, Error::NoPendingRelease),
+        };
+
+        let now = env.ledger().timestamp();
+        if now >= queued.release_after {
+            panic_with_error!(&env, Error::DisputeWindowClosed);
+        }
+
+        storage::remove_queued_release(&env, project_id);
+        storage::clear_attestations(&env, project_id, &queued.proof_hash);
+
+        if cancel {
+            state.status = ProjectStatus::Cancelled;
+            state.refund_expiry = now + REFUND_WINDOW;
+        } else if now >= config.deadline {
+            state.status = ProjectStatus::Expired;
+            state.refund_expiry = now + REFUND_WINDOW;
+        } else {
+            state.status = queued.resume_status;
+        }
+        save_project_state(&env, project_id, &state);
+
+        events::emit_release_disputed(&env, project_id, auditor, reason_hash, cancel);
+    }
+
+    /// Execute a queued release once its dispute window has passed.
+    ///
+    /// Permissionless: anyone can finalize an undisputed release.
+    pub fn finalize_release(env: Env, project_id: u64) {
+        Self::require_not_paused_for(&env, |f| f.releases);
+        Self::require_not_frozen(&env, project_id);
+
+        let (config, mut state) = load_project_pair(&env, project_id);
+        let queued = match storage::get_queued_release(&env, project_id) {
+            Some(q) if state.status == ProjectStatus::PendingRelease => q,
+            _ => panic_with_error!(&env, Error::NoPendingRelease),
+        };
+        if env.ledger().timestamp() < queued.release_after {
+            panic_with_error!(&env, Error::DisputeWindowOpen);
+        }
+
+        storage::remove_queued_release(&env, project_id);
+        state.status = queued.resume_status;
+        match queued.milestone {
+            Some(index) => Self::release_milestone(
+                &env,
+                &config,
+                &mut state,
+                index,
+                queued.oracle,
+                queued.proof_hash,
+            ),
+            None => Self::release_all(&env, &config, &mut state, queued.oracle, queued.proof_hash),
+        }
+    }
+
+    /// Return the release waiting out the dispute window for `project_id`, if any.
+    pub fn get_queued_release(env: Env, project_id: u64) -> Option<QueuedRelease> {
+        storage::get_queued_release(&env, project_id)
+    }
+
+    /// Mark a project as expired if its deadline has passed.
+    ///
+    /// Permissionless: anyone can trigger expiration once the deadline is met.
+    /// - Panics if project is not in Funding status.
+    /// - Panics if deadline has not passed.
+    pub fn expire_project(env: Env, project_id: u64) {
+        let (config, mut state) = load_project_pair(&env, project_id);
+
+        // State transition check: only Funding or Active projects can expire.
+        // Completed projects cannot be expired.
+        match state.status {
+            ProjectStatus::Funding | ProjectStatus::Active => {}
+            _ => panic_with_error!(&env, Error::InvalidTransition),
+        }
+
+        // Deadline check.
+        if env.ledger().timestamp() < config.deadline {
+            panic_with_error!(&env, Error::ProjectNotExpired);
+        }
+
+        // Update status and save.
+        state.status = ProjectStatus::Expired;
+        state.refund_expiry = env.ledger().timestamp() + REFUND_WINDOW;
+        save_project_state(&env, project_id, &state);
+
+        // Standardized event emission.
+        events::emit_project_expired(&env, project_id, config.deadline);
+    }
+
+    // ─────────────────────────────────────────────────────────
+    // Matching rounds
+    // ─────────────────────────────────────────────────────────
+
+    /// Create a quadratic-funding matching round.
+    ///
+    /// Donations in `token` to the listed projects between `start` and `end`
+    /// are tallied per donor. Sponsors add to the pool with `fund_round`, and
+    /// `finalize_round` splits it after `end`. A project can take part in one
+    /// open round at a time.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - `project_ids`: 1–20 unique projects that accept `token` and are
+    ///   still `Funding` or `Active`.
+    pub fn create_round(
+        env: Env,
+        caller: Address,
+        token: Address,
+        start: u64,
+        end: u64,
+        project_ids: Vec<u64>,
+    ) -> u64 {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+
+        if end <= start
+            || end <= env.ledger().timestamp()
+            || project_ids.is_empty()
+            || project_ids.len() > MAX_ROUND_PROJECTS
+        {
+            panic_with_error!(&env, Error::InvalidRound);
+        }
+
+        let round_id = storage::get_and_increment_round_id(&env);
+        for i in 0..project_ids.len() {
+            let project_id = project_ids.get(i).unwrap();
+            if project_ids.last_index_of(project_id) != Some(i) {
+                panic_with_error!(&env, Error::InvalidRound);
+            }
+            let project = match maybe_load_project(&env, project_id) {
+                Some(p) => p,
+                None => panic_with_error!(&env, Error::InvalidRound),
+            };
+            if !project.accepts_token(&token)
+                || !matches!(project.status, ProjectStatus::Funding | ProjectStatus::Active)
+                || storage::get_project_round(&env, project_id).is_some()
+            {
+                panic_with_error!(&env, Error::InvalidRound);
+            }
+            storage::set_project_round(&env, project_id, Some(round_id));
+        }
+
+        storage::set_round(
+            &env,
+            &MatchingRound {
+                id: round_id,
+                creator: caller.clone(),
+                token: token.clone(),
+                pool: 0,
+                start,
+                end,
+                project_ids,
+                finalized: false,
+            },
+        );
+        events::emit_round_created(&env, round_id, token, start, end, caller);
+        round_id
+    }
+
+    /// Add `amount` of the round token to a round's matching pool.
+    ///
+    /// Anyone may sponsor a round until it ends.
+    pub fn fund_round(env: Env, sponsor: Address, round_id: u64, amount: i128) {
+        Self::require_not_paused_for(&env, |f| f.deposits);
+        sponsor.require_auth();
+
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        let mut round = Self::load_round(&env, round_id);
+        if round.finalized || env.ledger().timestamp() >= round.end {
+            panic_with_error!(&env, Error::RoundClosed);
+        }
+
+        let token_client = token::Client::new(&env, &round.token);
+        token_client.transfer(&sponsor, env.current_contract_address(), &amount);
+
+        round.pool = match round.pool.checked_add(amount) {
+            Some(p) => p,
+            None => panic_with_error!(&env, Error::Overflow),
+        };
+        storage::set_round(&env, &round);
+        events::emit_round_funded(&env, round_id, sponsor, amount);
+    }
+
+    /// Split a round's pool between its projects once the round has ended.
+    ///
+    /// Each project still `Funding` or `Active` is weighted by
+    /// `(Σ √contribution)² − Σ contribution` over its donors in the round, and
+    /// its match is credited to its token balance like a donation (it can
+    /// complete the goal). Whatever is not allocated — rounding dust, or the
+    /// whole pool if no project has a weight — returns to the round creator.
+    /// A match is also returned to the creator if its project later fails;
+    /// see [`return_round_matches`].
+    ///
+    /// Permissionless.
+    pub fn finalize_round(env: Env, round_id: u64) {
+        let mut round = Self::load_round(&env, round_id);
+        if round.finalized {
+            panic_with_error!(&env, Error::RoundClosed);
+        }
+        if env.ledger().timestamp() < round.end {
+            panic_with_error!(&env, Error::RoundNotEnded);
+        }
+
+        let mut weights: Vec<i128> = Vec::new(&env);
+        let mut total_weight: i128 = 0;
+        for project_id in round.project_ids.iter() {
+            storage::set_project_round(&env, project_id, None);
+
+            let state = storage::load_project_state(&env, project_id);
+            let weight = if matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active)
+            {
+                let tally = storage::get_round_tally(&env, round_id, project_id);
+                (tally.sqrt_sum.saturating_mul(tally.sqrt_sum) - tally.total).max(0)
+            } else {
+                0
+            };
+            weights.push_back(weight);
+            total_weight = total_weight.saturating_add(weight);
+        }
+
+        let mut matched: i128 = 0;
+        if total_weight > 0 {
+            for i in 0..round.project_ids.len() {
+                let weight = weights.get(i).unwrap();
+                if weight == 0 {
+                    continue;
+                }
+                let project_id = round.project_ids.get(i).unwrap();
+                // `pool * weight` exceeds i128 for 18-decimal tokens, so
+                // the product is taken in 256 bits; `share <= pool` fits again.
+                let share = match I256::from_i128(&env, round.pool)
+                    .mul(&I256::from_i128(&env, weight))
+                    .div(&I256::from_i128(&env, total_weight))
+                    .to_i128()
+                {
+                    Some(v) => v,
+                    None => panic_with_error!(&env, Error::Overflow),
+                };
+                if share == 0 {
+                    continue;
+                }
+
+                let (config, mut state) = load_project_pair(&env, project_id);
+                Self::credit_project(&env, &config, &mut state, &round.token, share);
+                storage::record_round_match(&env, round_id, project_id, share);
+                events::emit_match_credited(&env, round_id, project_id, share);
+                matched += share;
+            }
+        }
+
+        let returned = round.pool - matched;
+        if returned > 0 {
+            let token_client = token::Client::new(&env, &round.token);
+            token_client.transfer(&env.current_contract_address(), &round.creator, &returned);
+        }
+
+        round.finalized = true;
+        storage::set_round(&env, &round);
+        events::emit_round_finalized(&env, round_id, matched, returned);
+    }
+
+    /// Return a matching round.
+    ///
+    /// # Errors
+    /// Panics with `Error::RoundNotFound` if `round_id` does not exist.
+    pub fn get_round(env: Env, round_id: u64) -> MatchingRound {
+        Self::load_round(&env, round_id)
+    }
+
+    /// Return the current quadratic tally of a project in a round.
+    pub fn get_round_tally(env: Env, round_id: u64, project_id: u64) -> RoundTally {
+        storage::get_round_tally(&env, round_id, project_id)
+    }
+
+    /// Return the matches a project received to the creators of their rounds
+    /// once it is `Expired`, `Cancelled` or `Failed`.
+    ///
+    /// Permissionless, like `process_refunds`. Each match is returned less the
+    /// share already paid out with released milestones, so donors' refunds
+    /// are unaffected. `reclaim_expired_funds` returns any match still held
+    /// before paying out the remaining balance.
+    pub fn return_round_matches(env: Env, project_id: u64) {
+        let (config, state) = Self::load_refundable_project(&env, project_id);
+        Self::return_matches(&env, &config, &state);
+    }
+
+    // ─────────────────────────────────────────────────────────
+    // Recurring pledges
+    // ─────────────────────────────────────────────────────────
+
+    /// Set up a recurring donation of `amount` every `period` seconds, at most
+    /// `max_periods` times.
+    ///
+    /// Nothing is transferred here: the donor must `approve` this contract as
+    /// spender of `amount * max_periods` on `token`, and `collect_pledges`
+    /// pulls instalments as they fall due. The first instalment is due
+    /// immediately.
+    ///
+    /// # Errors
+    /// - `InvalidAmount` if `amount <= 0`.
+    /// - `InvalidPledge` if `period` or `max_periods` is zero.
+    /// - `ProjectExpired` / `ProjectNotActive` / `TokenNotAccepted` /
+    ///   `NotWhitelisted` as for `deposit`.
+    pub fn create_recurring_pledge(
+        env: Env,
+        donator: Address,
+        project_id: u64,
+        token: Address,
+        amount: i128,
+        period: u64,
+        max_periods: u32,
+    ) -> u64 {
+        Self::do_create_pledge(
+            &env,
+            donator,
+            project_id,
+            token,
+            amount,
+            period,
+            max_periods,
+            None,
+        )
+    }
+
+    /// Set up a recurring donation to a private project, proving the donor
+    /// is on its Merkle allowlist.
+    ///
+    /// The proof is stored with the pledge and checked against the
+    /// project's current root on every collection, so replacing the root
+    /// can lapse the pledge. Otherwise behaves exactly like
+    /// `create_recurring_pledge`.
+    pub fn create_pledge_with_proof(
+        env: Env,
+        donator: Address,
+        project_id: u64,
+        token: Address,
+        amount: i128,
+        period: u64,
+        max_periods: u32,
+        proof: Vec<BytesN<32>>,
+    ) -> u64 {
+        Self::do_create_pledge(
+            &env,
+            donator,
+            project_id,
+            token,
+            amount,
+            period,
+            max_periods,
+            Some(proof),
+        )
+    }
+
+    /// Stop a recurring pledge. Instalments already collected stay with the
+    /// project (and remain refundable like any deposit).
+    pub fn cancel_recurring_pledge(env: Env, donator: Address, pledge_id: u64) {
+        donator.require_auth();
+
+        let pledge = match storage::get_pledge(&env, pledge_id) {
+            Some(p) => p,
+            None => panic_with_error!(&env, Error::PledgeNotFound),
+        };
+        if pledge.donator != donator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+
+        storage::remove_pledge(&env, pledge_id);
+        events::emit_pledge_cancelled(&env, pledge_id, pledge.project_id);
+    }
+
+    /// Return a live recurring pledge.
+    pub fn get_recurring_pledge(env: Env, pledge_id: u64) -> Option<RecurringPledge> {
+        storage::get_pledge(&env, pledge_id)
+    }
+
+    /// Pull every due instalment for a batch of a project's pledges.
+    ///
+    /// Permissionless. Visits up to 25 pledge slots, resuming where the
+    /// previous call stopped and wrapping around, so repeated calls cover
+    /// every pledge. Each collection is booked exactly like a `deposit` from
+    /// the donor, so it fails while deposits are paused or the project is
+    /// frozen. A pledge whose `transfer_from` fails (allowance or balance
+    /// too low), or whose donor is no longer whitelisted and whose stored
+    /// proof no longer matches the Merkle root, lapses and is removed.
+    /// Under a `RefundExcess` hard cap only the whole instalments that fit
+    /// are collected; a pledge that cannot fit a single one lapses.
+    /// Returns the number of pledges collected.
+    pub fn collect_pledges(env: Env, project_id: u64) -> u32 {
+        Self::require_not_paused_for(&env, |f| f.deposits);
+        Self::require_not_frozen(&env, project_id);
+
+        let (config, mut state) = load_project_pair(&env, project_id);
+        let now = env.ledger().timestamp();
+        if now >= config.deadline {
+            panic_with_error!(&env, Error::ProjectExpired);
+        }
+        if !matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active) {
+            panic_with_error!(&env, Error::ProjectNotActive);
+        }
+        Self::require_no_release(&env, &state);
+
+        let count = storage::get_project_pledge_count(&env, project_id);
+        if count == 0 {
+            return 0;
+        }
+        let start = storage::get_pledge_cursor(&env, project_id) % count;
+        let batch = count.min(MAX_PLEDGES_PER_COLLECT);
+        let contract_address = env.current_contract_address();
+
+        let mut collected: u32 = 0;
+        for offset in 0..batch {
+            let index = (start + offset) % count;
+            let mut pledge = match storage::get_project_pledge(&env, project_id, index)
+                .and_then(|id| storage::get_pledge(&env, id))
+            {
+                Some(p) => p,
+                None => continue,
+            };
+            if now < pledge.next_due {
+                continue;
+            }
+
+            let remaining = pledge.max_periods - pledge.collected_periods;
+            let elapsed = (now - pledge.next_due) / pledge.period + 1;
+            let periods = if elapsed < remaining as u64 {
+                elapsed as u32
+            } else {
+                remaining
+            };
+            let amount = match pledge.amount.checked_mul(periods as i128) {
+                Some(a) => a,
+                None => panic_with_error!(&env, Error::Overflow),
+            };
+            let accepted =
+                Self::accepted_amount(&env, &config, &pledge.token, Some(&pledge.donator), amount);
+
+            let allowed = Self::may_pledge(&env, &config, &pledge.donator, &pledge.allowlist_proof);
+            let token_client = token::Client::new(&env, &pledge.token);
+            let amount = match accepted {
+                Ok(a) if allowed => a,
+                _ => 0,
+            };
+            // A hard cap with `RefundExcess` may accept less than was due.
+            // Only whole instalments are collected so the schedule stays in
+            // step with what was paid; if not even one fits, the pledge lapses.
+            let periods = (amount / pledge.amount) as u32;
+            let amount = pledge.amount * periods as i128;
+            if periods == 0
+                || !matches!(
+                    token_client.try_transfer_from(
+                        &contract_address,
+                        &pledge.donator,
+                        &contract_address,
+                        &amount,
+                    ),
+                    Ok(Ok(()))
+                )
+            {
+                storage::remove_pledge(&env, pledge.id);
+                events::emit_pledge_lapsed(&env, pledge.id, project_id);
+                continue;
+            }
+
+            Self::credit_donator(&env, &config, &mut state, &pledge.token, &pledge.donator, amount);
+            events::emit_project_funded(&env, project_id, pledge.donator.clone(), amount);
+            events::emit_pledge_collected(&env, pledge.id, project_id, amount, periods);
+
+            pledge.collected_periods += periods;
+            pledge.next_due = pledge
+                .next_due
+                .saturating_add(pledge.period.saturating_mul(periods as u64));
+            if pledge.collected_periods >= pledge.max_periods {
+                storage::remove_pledge(&env, pledge.id);
+            } else {
+                storage::set_pledge(&env, &pledge);
+            }
+            collected += 1;
+        }
+
+        storage::set_pledge_cursor(&env, project_id, (start + batch) % count);
+        collected
     }
 
-    /// Deposit funds into a project.
+    // ─────────────────────────────────────────────────────────
+    // Donor Refund Expiry
+    // ─────────────────────────────────────────────────────────
+
+    /// Reclaim unclaimed donor funds after the 6-month refund window has expired.
     ///
-    /// The `token` must be one of the project's accepted tokens.
-    pub fn deposit(env: Env, project_id: u64, donator: Address, token: Address, amount: i128) {
+    /// Only the project creator may call this, and only for projects that are
+    /// `Expired`, `Cancelled` or `Failed` whose `refund_expiry` timestamp has passed.
+    /// Round matches the project still holds are first returned to their
+    /// rounds' creators. For each accepted token, any remaining balance is
+    /// then paid to the project's beneficiaries, or to the creator when none
+    /// are set.
+    pub fn reclaim_expired_funds(env: Env, creator: Address, project_id: u64) {
         Self::require_not_paused(&env);
-        donator.require_auth();
+        creator.require_auth();
 
-        if amount <= 0 {
-            panic_with_error!(&env, Error::InvalidAmount);
+        let (config, state) = load_project_pair(&env, project_id);
+
+        // Only the project creator may reclaim.
+        if creator != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
         }
 
-        // Read both config and state with a single helper that bumps TTLs
-        // atomically. This is the optimized retrieval pattern; it also returns
-        // the state needed for the subsequent checks.
-        let (config, mut state) = load_project_pair(&env, project_id);
+        // Project must be in a terminal refundable state.
+        if !matches!(
+            state.status,
+            ProjectStatus::Expired | ProjectStatus::Cancelled | ProjectStatus::Failed
+        ) {
+            panic_with_error!(&env, Error::InvalidTransition);
+        }
+
+        // The refund window must have expired.
+        if state.refund_expiry == 0 || env.ledger().timestamp() < state.refund_expiry {
+            panic_with_error!(&env, Error::RefundWindowActive);
+        }
+
+        // Sponsor matches go back to their rounds, not to the creator.
+        Self::return_matches(&env, &config, &state);
+
+        // Drain remaining balances for each accepted token.
+        for token in config.accepted_tokens.iter() {
+            let balance = drain_token_balance(&env, project_id, &token);
+            if balance > 0 {
+                let token_client = token::Client::new(&env, &token);
+                Self::pay_beneficiaries(&env, &config, &token_client, balance);
+
+                events::emit_expired_funds_reclaimed(
+                    &env,
+                    project_id,
+                    config.creator.clone(),
+                    token,
+                    balance,
+                );
+            }
+        }
+    }
+
+    // ─────────────────────────────────────────────────────────
+    // Internal Helpers
+    // ─────────────────────────────────────────────────────────
+
+    fn require_not_paused(env: &Env) {
+        if storage::is_paused(env) {
+            panic_with_error!(env, Error::ProtocolPaused);
+        }
+    }
+
+    /// Panic with `ProtocolPaused` if the protocol is paused, or if the
+    /// operation selected by `paused` is paused on its own.
+    fn require_not_paused_for(env: &Env, paused: fn(&PauseFlags) -> bool) {
+        Self::require_not_paused(env);
+        if paused(&storage::get_pause_flags(env)) {
+            panic_with_error!(env, Error::ProtocolPaused);
+        }
+    }
+
+    fn require_not_frozen(env: &Env, project_id: u64) {
+        if storage::is_frozen(env, project_id) {
+            panic_with_error!(env, Error::ProjectFrozen);
+        }
+    }
+
+    /// Queue a pending upgrade with the authority of `caller`.
+    fn do_propose_upgrade(env: &Env, caller: &Address, wasm_hash: BytesN<32>) -> u64 {
+        rbac::require_super_admin(env, caller);
+
+        if storage::get_pending_upgrade(env).is_some() {
+            panic_with_error!(env, Error::UpgradeAlreadyPending);
+        }
+
+        let eta = env.ledger().timestamp() + UPGRADE_DELAY;
+        storage::set_pending_upgrade(
+            env,
+            &PendingUpgrade {
+                wasm_hash: wasm_hash.clone(),
+                eta,
+            },
+        );
+        events::emit_upgrade_proposed(env, wasm_hash, eta, caller.clone());
+        eta
+    }
+
+    /// Drop the pending upgrade with the authority of `caller`.
+    fn do_cancel_upgrade(env: &Env, caller: &Address) {
+        rbac::require_super_admin(env, caller);
+
+        let pending = match storage::get_pending_upgrade(env) {
+            Some(p) => p,
+            None => panic_with_error!(env, Error::NoPendingUpgrade),
+        };
+        storage::remove_pending_upgrade(env);
+        events::emit_upgrade_cancelled(env, pending.wasm_hash, caller.clone());
+    }
+
+    /// Install the pending upgrade with the authority of `caller`.
+    fn do_execute_upgrade(env: &Env, caller: &Address) {
+        rbac::require_super_admin(env, caller);
+
+        let pending = match storage::get_pending_upgrade(env) {
+            Some(p) => p,
+            None => panic_with_error!(env, Error::NoPendingUpgrade),
+        };
+        if env.ledger().timestamp() < pending.eta {
+            panic_with_error!(env, Error::TimelockNotElapsed);
+        }
+
+        storage::remove_pending_upgrade(env);
+        events::emit_upgrade_executed(env, pending.wasm_hash.clone(), caller.clone());
+        env.deployer()
+            .update_current_contract_wasm(pending.wasm_hash);
+    }
+
+    /// Run one migration batch with the authority of `caller`.
+    fn do_migrate(env: &Env, caller: &Address, limit: u32) -> bool {
+        rbac::require_super_admin(env, caller);
+
+        let from_version = storage::get_schema_version(env);
+        if from_version >= CURRENT_SCHEMA_VERSION {
+            return true;
+        }
+
+        let mut cursor = storage::get_migration_cursor(env);
+        if cursor == 0 {
+            storage::begin_migration(env, from_version);
+        }
+        let project_count = storage::get_project_count(env);
+        let end = project_count.min(cursor.saturating_add(limit.min(MAX_PAGE_SIZE) as u64));
+        while cursor < end {
+            storage::migrate_project(env, cursor, from_version);
+            cursor += 1;
+        }
+
+        if cursor < project_count {
+            storage::set_migration_cursor(env, Some(cursor));
+            return false;
+        }
+
+        storage::set_migration_cursor(env, None);
+        storage::set_schema_version(env, CURRENT_SCHEMA_VERSION);
+        events::emit_schema_migrated(env, from_version, CURRENT_SCHEMA_VERSION);
+        true
+    }
+
+    /// Queue a governance proposal with the authority of `caller`.
+    fn do_propose_action(env: &Env, caller: &Address, action: GovAction) -> u64 {
+        Self::require_can_apply(env, caller, &action);
+
+        let id = storage::get_and_increment_proposal_id(env);
+        let eta = env.ledger().timestamp() + GOVERNANCE_DELAY;
+        storage::set_proposal(
+            env,
+            &Proposal {
+                id,
+                action: action.clone(),
+                proposer: caller.clone(),
+                eta,
+            },
+        );
+        events::emit_proposal_created(env, id, action, caller.clone(), eta);
+        id
+    }
+
+    /// Drop a queued governance proposal with the authority of `caller`.
+    fn do_cancel_proposal(env: &Env, caller: &Address, proposal_id: u64) {
+        rbac::require_super_admin(env, caller);
+
+        if storage::get_proposal(env, proposal_id).is_none() {
+            panic_with_error!(env, Error::ProposalNotFound);
+        }
+        storage::remove_proposal(env, proposal_id);
+        events::emit_proposal_cancelled(env, proposal_id, caller.clone());
+    }
+
+    /// Load a matching round or panic with `RoundNotFound`.
+    fn load_round(env: &Env, round_id: u64) -> MatchingRound {
+        match storage::get_round(env, round_id) {
+            Some(r) => r,
+            None => panic_with_error!(env, Error::RoundNotFound),
+        }
+    }
+
+    /// Adjust `donator`'s matched contribution to `project_id` by `delta`.
+    ///
+    /// Only applies while the project is in an unfinalized round for `token`.
+    /// Increases count only inside the round window; decreases (withdrawals)
+    /// are applied until the round is finalized so withdrawn funds are never
+    /// matched.
+    fn track_round_contribution(
+        env: &Env,
+        project_id: u64,
+        token: &Address,
+        donator: &Address,
+        delta: i128,
+    ) {
+        let round = match storage::get_project_round(env, project_id)
+            .and_then(|id| storage::get_round(env, id))
+        {
+            Some(r) => r,
+            None => return,
+        };
+        let now = env.ledger().timestamp();
+        if round.finalized || round.token != *token {
+            return;
+        }
+        if delta > 0 && (now < round.start || now >= round.end) {
+            return;
+        }
+
+        let old = storage::get_round_contribution(env, round.id, project_id, donator);
+        let new = old.saturating_add(delta).max(0);
+        if new == old {
+            return;
+        }
+
+        let mut tally = storage::get_round_tally(env, round.id, project_id);
+        tally.sqrt_sum += isqrt(new) - isqrt(old);
+        tally.total += new - old;
+        storage::set_round_tally(env, round.id, project_id, &tally);
+        storage::set_round_contribution(env, round.id, project_id, donator, new);
+    }
+
+    /// Validate and store a new protocol configuration.
+    fn apply_protocol_config(env: &Env, config: ProtocolConfig) {
+        if config.fee_bps > 1000 {
+            panic_with_error!(env, Error::InvalidFeeBasisPoints);
+        }
+        let old_config = get_protocol_config(env);
+        set_protocol_config(env, &config);
+        events::emit_protocol_config_updated(env, old_config, config);
+    }
+
+    /// Assert that `by` holds the authority `action` requires.
+    fn require_can_apply(env: &Env, by: &Address, action: &GovAction) {
+        match action {
+            GovAction::UpdateProtocolConfig(config) => {
+                rbac::require_super_admin(env, by);
+                if config.fee_bps > 1000 {
+                    panic_with_error!(env, Error::InvalidFeeBasisPoints);
+                }
+            }
+            GovAction::SetFeeSplits(splits) => {
+                rbac::require_super_admin(env, by);
+                Self::validate_fee_splits(env, splits);
+            }
+            GovAction::SetProjectFee(project_id, fee_bps) => {
+                rbac::require_super_admin(env, by);
+                storage::load_project_config(env, *project_id);
+                if fee_bps.unwrap_or(0) > 1000 {
+                    panic_with_error!(env, Error::InvalidFeeBasisPoints);
+                }
+            }
+            GovAction::SetDisputeWindow(window) => {
+                rbac::require_super_admin(env, by);
+                if *window > MAX_DISPUTE_WINDOW {
+                    panic_with_error!(env, Error::InvalidDisputeWindow);
+                }
+            }
+            GovAction::SetOracleQuorum(quorum) => {
+                rbac::require_super_admin(env, by);
+                if *quorum == 0 {
+                    panic_with_error!(env, Error::InvalidQuorum);
+                }
+            }
+            GovAction::GrantRole(_, Role::SuperAdmin)
+            | GovAction::GrantRoleUntil(_, Role::SuperAdmin, _) => {
+                rbac::require_super_admin(env, by)
+            }
+            GovAction::GrantRole(..)
+            | GovAction::GrantRoleUntil(..)
+            | GovAction::RevokeRole(_)
+            | GovAction::SetPaused(_)
+            | GovAction::SetPauseFlags(_) => rbac::require_admin_or_above(env, by),
+        }
+    }
+
+    /// Return `true` while no project has been registered, so no donor has
+    /// funds that an immediate admin change could work against.
+    fn before_first_project(env: &Env) -> bool {
+        storage::get_project_count(env) == 0
+    }
+
+    /// Return `true` if `action` may take effect without the governance
+    /// delay. Before the first project anything goes; afterwards only the
+    /// first protocol config and changes that cannot cost donors anything
+    /// (revocations, fee redistribution, lowering a fee override) skip it.
+    fn may_skip_timelock(env: &Env, action: &GovAction) -> bool {
+        match action {
+            GovAction::UpdateProtocolConfig(_) => get_protocol_config(env).is_none(),
+            GovAction::RevokeRole(_) | GovAction::SetFeeSplits(_) => true,
+            GovAction::SetProjectFee(project_id, Some(fee_bps)) => {
+                match storage::get_project_fee(env, *project_id) {
+                    Some(current) => *fee_bps <= current,
+                    None => true,
+                }
+            }
+            _ => Self::before_first_project(env),
+        }
+    }
+
+    /// Apply a governance action from a direct entry point, panicking with
+    /// `TimelockRequired` if it has to be queued with `propose_action`.
+    fn apply_direct(env: &Env, by: &Address, action: GovAction) {
+        Self::require_can_apply(env, by, &action);
+        if !Self::may_skip_timelock(env, &action) {
+            panic_with_error!(env, Error::TimelockRequired);
+        }
+        Self::run_action(env, by, action);
+    }
+
+    /// Apply a governance action with the authority of `by`.
+    fn apply_action(env: &Env, by: &Address, action: GovAction) {
+        Self::require_can_apply(env, by, &action);
+        Self::run_action(env, by, action);
+    }
+
+    /// Carry out a governance action whose authority was already checked.
+    fn run_action(env: &Env, by: &Address, action: GovAction) {
+        match action {
+            GovAction::UpdateProtocolConfig(config) => Self::apply_protocol_config(env, config),
+            GovAction::GrantRole(target, role) => rbac::grant_role(env, by, &target, role, None),
+            GovAction::GrantRoleUntil(target, role, expires_at) => {
+                rbac::grant_role(env, by, &target, role, Some(expires_at))
+            }
+            GovAction::RevokeRole(target) => rbac::revoke_role(env, by, &target),
+            GovAction::SetPaused(true) => {
+                storage::set_paused(env, true);
+                events::emit_protocol_paused(env, by.clone());
+            }
+            GovAction::SetPaused(false) => {
+                storage::set_paused(env, false);
+                events::emit_protocol_unpaused(env, by.clone());
+            }
+            GovAction::SetFeeSplits(splits) => {
+                storage::set_fee_splits(env, &splits);
+                events::emit_fee_splits_updated(env, splits);
+            }
+            GovAction::SetProjectFee(project_id, fee_bps) => {
+                storage::set_project_fee(env, project_id, fee_bps);
+                events::emit_project_fee_set(env, project_id, fee_bps);
+            }
+            GovAction::SetDisputeWindow(window) => {
+                storage::set_dispute_window(env, window);
+                events::emit_dispute_window_updated(env, window);
+            }
+            GovAction::SetPauseFlags(flags) => {
+                storage::set_pause_flags(env, &flags);
+                events::emit_pause_flags_updated(env, flags);
+            }
+            GovAction::SetOracleQuorum(quorum) => {
+                let old_quorum = storage::get_oracle_quorum(env);
+                storage::set_oracle_quorum(env, quorum);
+                events::emit_oracle_quorum_updated(env, old_quorum, quorum);
+            }
+        }
+    }
+
+    /// Panic with `InvalidFeeSplits` unless `splits` is empty or has at most
+    /// `MAX_FEE_SPLITS` non-zero shares summing to 10 000 bps.
+    fn validate_fee_splits(env: &Env, splits: &Vec<FeeSplit>) {
+        if splits.is_empty() {
+            return;
+        }
+        if splits.len() > MAX_FEE_SPLITS {
+            panic_with_error!(env, Error::InvalidFeeSplits);
+        }
+        let mut total: u32 = 0;
+        for split in splits.iter() {
+            if split.share_bps == 0 {
+                panic_with_error!(env, Error::InvalidFeeSplits);
+            }
+            total = total.saturating_add(split.share_bps);
+        }
+        if total != TOTAL_FEE_SPLIT_BPS {
+            panic_with_error!(env, Error::InvalidFeeSplits);
+        }
+    }
+
+    /// Stored metadata history of a project, or its registration entry if
+    /// the metadata was never updated.
+    fn metadata_history(env: &Env, config: &ProjectConfig) -> Vec<MetadataVersion> {
+        let history = storage::get_metadata_history(env, config.id);
+        if !history.is_empty() {
+            return history;
+        }
+        Vec::from_array(
+            env,
+            [MetadataVersion {
+                version: 0,
+                metadata_uri: config.metadata_uri.clone(),
+                updated_at: 0,
+            }],
+        )
+    }
+
+    /// Panic with `InvalidBeneficiaries` unless `beneficiaries` is empty or
+    /// has at most `MAX_BENEFICIARIES` non-zero shares summing to 10 000 bps.
+    fn validate_beneficiaries(env: &Env, beneficiaries: &Vec<Beneficiary>) {
+        if beneficiaries.is_empty() {
+            return;
+        }
+        if beneficiaries.len() > MAX_BENEFICIARIES {
+            panic_with_error!(env, Error::InvalidBeneficiaries);
+        }
+        let mut total: u32 = 0;
+        for beneficiary in beneficiaries.iter() {
+            if beneficiary.share_bps == 0 {
+                panic_with_error!(env, Error::InvalidBeneficiaries);
+            }
+            total = total.saturating_add(beneficiary.share_bps);
+        }
+        if total != TOTAL_BENEFICIARY_BPS {
+            panic_with_error!(env, Error::InvalidBeneficiaries);
+        }
+    }
+
+    /// Fee in basis points charged on releases of `project_id`: the global
+    /// fee, lowered to the project's override if one is set.
+    fn effective_fee_bps(env: &Env, project_id: u64, protocol: &ProtocolConfig) -> u32 {
+        match storage::get_project_fee(env, project_id) {
+            Some(fee_bps) => fee_bps.min(protocol.fee_bps),
+            None => protocol.fee_bps,
+        }
+    }
+
+    /// Load a project for a deposit, lazily expiring it if its deadline has
+    /// passed and rejecting non-fundable statuses and unaccepted tokens.
+    fn load_depositable_project(
+        env: &Env,
+        project_id: u64,
+        token: &Address,
+    ) -> (ProjectConfig, ProjectState) {
+        let (config, mut state) = load_project_pair(env, project_id);
 
         // Check expiration
         if env.ledger().timestamp() >= config.deadline {
             if matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active) {
                 state.status = ProjectStatus::Expired;
                 state.refund_expiry = env.ledger().timestamp() + REFUND_WINDOW;
-                save_project_state(&env, project_id, &state);
-            }
-            panic_with_error!(&env, Error::ProjectExpired);
-        }
-
-        // Whitelist check
-        if config.is_private {
-            if !is_whitelisted(&env, project_id, &donator) {
-                panic_with_error!(&env, Error::NotWhitelisted);
+                save_project_state(env, project_id, &state);
             }
+            panic_with_error!(env, Error::ProjectExpired);
         }
 
         // Basic status check: must be Funding or Active.
         match state.status {
             ProjectStatus::Funding | ProjectStatus::Active => {}
-            ProjectStatus::Expired => panic_with_error!(&env, Error::ProjectExpired),
-            _ => panic_with_error!(&env, Error::ProjectNotActive),
+            ProjectStatus::Expired => panic_with_error!(env, Error::ProjectExpired),
+            _ => panic_with_error!(env, Error::ProjectNotActive),
         }
+        Self::require_no_release(env, &state);
 
         // Verify token is accepted.
-        let mut found = false;
-        for t in config.accepted_tokens.iter() {
-            if t == token {
-                found = true;
-                break;
-            }
+        if !config.accepted_tokens.contains(token) {
+            panic_with_error!(env, Error::TokenNotAccepted);
         }
-        if !found {
-            panic_with_error!(&env, Error::TokenNotAccepted);
+
+        (config, state)
+    }
+
+    /// Reject donations once any milestone has been released.
+    ///
+    /// Refunds return the unreleased share of a donor's balance, so a
+    /// donation made after a release would be refunded short.
+    fn require_no_release(env: &Env, state: &ProjectState) {
+        if state.released_milestones != 0 {
+            panic_with_error!(env, Error::FundingClosed);
         }
+    }
 
+    /// Book a donation that has already been transferred to the contract.
+    ///
+    /// Counts new donors in `donation_count`, credits the project (including
+    /// the `Funding` → `Active` transition), the donor's refundable balance
+    /// and any open matching round.
+    fn credit_donator(
+        env: &Env,
+        config: &ProjectConfig,
+        state: &mut ProjectState,
+        token: &Address,
+        donator: &Address,
+        amount: i128,
+    ) {
         // Check if this is a new unique (donator, token) pair.
         // A donator balance of 0 implicitly proves they have not donated yet, saving a storage key entirely.
-        let current_donor_balance =
-            storage::get_donator_balance(&env, project_id, &token, &donator);
-        let is_new_donor = current_donor_balance == 0;
-
-        if is_new_donor {
-            // Increment donation count
+        let current_donor_balance = storage::get_donator_balance(env, config.id, token, donator);
+        if current_donor_balance == 0 {
             state.donation_count += 1;
-            // Save the updated state.
-            save_project_state(&env, project_id, &state);
+            save_project_state(env, config.id, state);
         }
 
+        // Update the per-token balance and the Funding → Active transition.
+        Self::credit_project(env, config, state, token, amount);
+
+        // Track per-donator refundable amount for this token.
+        let new_donor_balance = current_donor_balance
+            .checked_add(amount)
+            .expect("donator balance overflow");
+        storage::set_donator_balance(env, config.id, token, donator, new_donor_balance);
+        storage::index_project_donor(env, config.id, donator);
+        storage::record_receipt(env, donator, config.id, token, amount);
+        Self::track_round_contribution(env, config.id, token, donator, amount);
+    }
+
+    /// Shared body of `deposit` and `deposit_with_proof`.
+    ///
+    /// Private projects admit donors on the whitelist or, when `proof` is
+    /// given, donors it proves against the project's Merkle root.
+    fn do_deposit(
+        env: &Env,
+        project_id: u64,
+        donator: Address,
+        token: Address,
+        amount: i128,
+        proof: Option<Vec<BytesN<32>>>,
+    ) {
+        Self::require_not_paused_for(env, |f| f.deposits);
+        Self::require_not_frozen(env, project_id);
+        donator.require_auth();
+
+        if amount <= 0 {
+            panic_with_error!(env, Error::InvalidAmount);
+        }
+
+        // Read both config and state with a single helper that bumps TTLs
+        // atomically, then run the lazy-expiry, status and token checks.
+        let (config, mut state) = Self::load_depositable_project(env, project_id, &token);
+
+        // Whitelist check
+        if config.is_private {
+            let allowed = match &proof {
+                Some(proof) => Self::is_in_merkle_allowlist(env, &config, &donator, proof),
+                None => is_whitelisted(env, project_id, &donator),
+            };
+            if !allowed {
+                panic_with_error!(env, Error::NotWhitelisted);
+            }
+        }
+
+        let amount = match Self::accepted_amount(env, &config, &token, Some(&donator), amount) {
+            Ok(a) => a,
+            Err(e) => panic_with_error!(env, e),
+        };
+
         // Transfer tokens from donator to contract.
-        let token_client = token::Client::new(&env, &token);
+        let token_client = token::Client::new(env, &token);
         token_client.transfer(&donator, env.current_contract_address(), &amount);
 
-        // Update the per-token balance.
-        let new_balance = storage::add_to_token_balance(&env, project_id, &token, amount);
+        Self::credit_donator(env, &config, &mut state, &token, &donator, amount);
 
-        // If this is the primary token and goal is reached, transition from Funding to Active.
-        if state.status == ProjectStatus::Funding {
-            if let Some(first_token) = config.accepted_tokens.get(0) {
-                if token == first_token && new_balance >= config.goal {
-                    state.status = ProjectStatus::Active;
-                    save_project_state(&env, project_id, &state);
-                    events::emit_project_active(&env, project_id);
-                }
+        // Standardized event emission
+        events::emit_project_funded(env, project_id, donator, amount);
+    }
+
+    /// Shared body of `create_recurring_pledge` and
+    /// `create_pledge_with_proof`.
+    fn do_create_pledge(
+        env: &Env,
+        donator: Address,
+        project_id: u64,
+        token: Address,
+        amount: i128,
+        period: u64,
+        max_periods: u32,
+        proof: Option<Vec<BytesN<32>>>,
+    ) -> u64 {
+        Self::require_not_paused_for(env, |f| f.deposits);
+        Self::require_not_frozen(env, project_id);
+        donator.require_auth();
+
+        if amount <= 0 {
+            panic_with_error!(env, Error::InvalidAmount);
+        }
+        if period == 0 || max_periods == 0 {
+            panic_with_error!(env, Error::InvalidPledge);
+        }
+
+        let (config, _) = Self::load_depositable_project(env, project_id, &token);
+        if !Self::may_pledge(env, &config, &donator, &proof) {
+            panic_with_error!(env, Error::NotWhitelisted);
+        }
+        if let Err(e) = Self::check_supported_amount(env, &token, amount) {
+            panic_with_error!(env, e);
+        }
+
+        let pledge = RecurringPledge {
+            id: storage::get_and_increment_pledge_id(env),
+            project_id,
+            donator,
+            token,
+            amount,
+            period,
+            max_periods,
+            collected_periods: 0,
+            next_due: env.ledger().timestamp(),
+            allowlist_proof: proof,
+        };
+        storage::set_pledge(env, &pledge);
+        storage::append_project_pledge(env, project_id, pledge.id);
+        events::emit_pledge_created(env, &pledge);
+        pledge.id
+    }
+
+    /// Return `true` if `donator` may donate to `config`'s project through a
+    /// pledge: the project is public, the donor has a whitelist entry, or
+    /// `proof` links the donor to the project's Merkle root.
+    fn may_pledge(
+        env: &Env,
+        config: &ProjectConfig,
+        donator: &Address,
+        proof: &Option<Vec<BytesN<32>>>,
+    ) -> bool {
+        if !config.is_private || is_whitelisted(env, config.id, donator) {
+            return true;
+        }
+        match proof {
+            Some(proof) => Self::is_in_merkle_allowlist(env, config, donator, proof),
+            None => false,
+        }
+    }
+
+    /// Return `true` if `proof` links `donator`'s leaf to the project's
+    /// Merkle root. Always `false` when no root is set.
+    fn is_in_merkle_allowlist(
+        env: &Env,
+        config: &ProjectConfig,
+        donator: &Address,
+        proof: &Vec<BytesN<32>>,
+    ) -> bool {
+        let root = match &config.merkle_root {
+            Some(root) => root,
+            None => return false,
+        };
+
+        let mut node: BytesN<32> = env.crypto().sha256(&donator.to_xdr(env)).into();
+        for sibling in proof.iter() {
+            let mut preimage = Bytes::new(env);
+            if node <= sibling {
+                preimage.append(&Bytes::from(node));
+                preimage.append(&Bytes::from(sibling));
+            } else {
+                preimage.append(&Bytes::from(sibling));
+                preimage.append(&Bytes::from(node));
             }
+            node = env.crypto().sha256(&preimage).into();
         }
+        node == *root
+    }
 
-        // Track per-donator refundable amount for this token.
-        let new_donor_balance = current_donor_balance
-            .checked_add(amount)
-            .expect("donator balance overflow");
-        storage::set_donator_balance(&env, project_id, &token, &donator, new_donor_balance);
+    /// Assert that `caller` may edit the whitelist of `project_id` and that
+    /// `addresses` fits in one batch.
+    fn require_whitelist_manager(
+        env: &Env,
+        caller: &Address,
+        project_id: u64,
+        addresses: &Vec<Address>,
+    ) {
+        let config = storage::load_project_config(env, project_id);
+        if *caller != config.creator {
+            rbac::require_admin_or_above(env, caller);
+        }
+        if addresses.len() > MAX_WHITELIST_BATCH {
+            panic_with_error!(env, Error::WhitelistBatchTooLarge);
+        }
+    }
+
+    /// Apply the token registry and a project's donation limits to a
+    /// donation of `amount` in `token`, returning the amount to take from
+    /// the donor.
+    ///
+    /// `donator` is `None` for committed deposits, which cannot be checked
+    /// against a per-donor limit and are therefore refused when one is set.
+    /// Under `OverfundPolicy::RefundExcess` the amount is reduced to what
+    /// still fits under the hard cap.
+    fn accepted_amount(
+        env: &Env,
+        config: &ProjectConfig,
+        token: &Address,
+        donator: Option<&Address>,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        Self::check_supported_amount(env, token, amount)?;
+
+        let limits = &config.limits;
+
+        if amount < limits.min_donation {
+            return Err(Error::BelowMinDonation);
+        }
+
+        if limits.max_per_donor > 0 {
+            let donor_balance = match donator {
+                Some(d) => storage::get_donator_balance(env, config.id, token, d),
+                None => return Err(Error::DonorCapExceeded),
+            };
+            Self::check_donor_cap(config, donor_balance, amount)?;
+        }
+
+        if limits.hard_cap > 0 {
+            // Tokens that do not count towards the goal are not capped either.
+            if let Some((rate, base_rate)) = Self::goal_rate(env, config, token) {
+                let headroom = (limits.hard_cap - Self::funded_value(env, config)).max(0);
+                let value = match amount.checked_mul(rate) {
+                    Some(v) => v / base_rate,
+                    None => return Err(Error::Overflow),
+                };
+                if value > headroom {
+                    let fitting = match headroom.checked_mul(base_rate) {
+                        Some(v) => v / rate,
+                        None => return Err(Error::Overflow),
+                    };
+                    return match limits.overflow {
+                        OverfundPolicy::RefundExcess if fitting > 0 => Ok(fitting),
+                        _ => Err(Error::HardCapReached),
+                    };
+                }
+            }
+        }
 
-        // Standardized event emission
-        events::emit_project_funded(&env, project_id, donator, amount);
+        Ok(amount)
     }
 
-    /// Mark an active project as cancelled.
-    ///
-    /// - `caller` must be `SuperAdmin` or `ProjectManager`.
-    /// - If `caller` is `ProjectManager`, it must be the project's creator.
-    /// - Only projects in `Active` status may be cancelled.
-    pub fn cancel_project(env: Env, caller: Address, project_id: u64) {
-        caller.require_auth();
-        rbac::require_can_cancel_project(&env, &caller);
-
-        let (config, mut state) = load_project_pair(&env, project_id);
+    /// Reject `amount` if it would lift a donor's `donor_balance` above the
+    /// project's `max_per_donor` (0 = uncapped).
+    fn check_donor_cap(
+        config: &ProjectConfig,
+        donor_balance: i128,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let cap = config.limits.max_per_donor;
+        if cap > 0 && donor_balance.saturating_add(amount) > cap {
+            return Err(Error::DonorCapExceeded);
+        }
+        Ok(())
+    }
 
-        if env.ledger().timestamp() >= config.deadline
-            && matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active)
-        {
-            state.status = ProjectStatus::Expired;
-            state.refund_expiry = env.ledger().timestamp() + REFUND_WINDOW;
-            save_project_state(&env, project_id, &state);
-            panic_with_error!(&env, Error::ProjectExpired);
+    /// Check a donation of `amount` against the token registry: `token` must
+    /// be supported and `amount` must reach its minimum donation.
+    fn check_supported_amount(env: &Env, token: &Address, amount: i128) -> Result<(), Error> {
+        match storage::get_supported_token(env, token) {
+            Some(info) if amount < info.min_donation => Err(Error::BelowMinDonation),
+            Some(_) => Ok(()),
+            None => Err(Error::TokenNotSupported),
         }
+    }
 
-        if matches!(rbac::get_role(&env, &caller), Some(Role::ProjectManager))
-            && caller != config.creator
-        {
-            panic_with_error!(&env, Error::NotAuthorized);
+    /// Conversion of `token` into goal units as `(rate, base_rate)`, or
+    /// `None` if the token does not count towards the goal.
+    fn goal_rate(env: &Env, config: &ProjectConfig, token: &Address) -> Option<(i128, i128)> {
+        let first_token = config.accepted_tokens.get(0)?;
+        match storage::get_token_rate(env, &first_token) {
+            Some(base_rate) => storage::get_token_rate(env, token).map(|rate| (rate, base_rate)),
+            None if *token == first_token => Some((1, 1)),
+            None => None,
         }
+    }
 
-        if state.status != ProjectStatus::Active {
-            panic_with_error!(&env, Error::InvalidTransition);
+    /// Pay each round match a failed project still holds back to the round's
+    /// creator, less the share released with milestones, and forget it.
+    fn return_matches(env: &Env, config: &ProjectConfig, state: &ProjectState) {
+        for round_id in storage::get_matched_rounds(env, config.id).iter() {
+            let round = Self::load_round(env, round_id);
+            let matched = storage::get_round_match(env, round_id, config.id);
+            let amount = Self::refundable_amount(env, config, state, matched)
+                .min(storage::get_token_balance(env, config.id, &round.token));
+            if amount <= 0 {
+                continue;
+            }
+            storage::add_to_token_balance(env, config.id, &round.token, -amount);
+            token::Client::new(env, &round.token).transfer(
+                &env.current_contract_address(),
+                &round.creator,
+                &amount,
+            );
+            events::emit_match_returned(env, round_id, config.id, amount);
         }
+        storage::clear_round_matches(env, config.id);
+    }
 
-        state.status = ProjectStatus::Cancelled;
-        state.refund_expiry = env.ledger().timestamp() + REFUND_WINDOW;
-        save_project_state(&env, project_id, &state);
-        events::emit_project_cancelled(&env, project_id, caller);
+    /// Add `amount` to the project's `token` balance and move the project
+    /// from `Funding` to `Active` once the goal is reached.
+    fn credit_project(
+        env: &Env,
+        config: &ProjectConfig,
+        state: &mut ProjectState,
+        token: &Address,
+        amount: i128,
+    ) {
+        storage::add_to_token_balance(env, config.id, token, amount);
+
+        // Transition from Funding to Active once the weighted total of all
+        // accepted tokens reaches the goal.
+        if state.status == ProjectStatus::Funding && Self::funded_value(env, config) >= config.goal
+        {
+            state.status = ProjectStatus::Active;
+            save_project_state(env, config.id, state);
+            events::emit_project_active(env, config.id);
+        }
     }
 
-    /// Refund a donator from a cancelled or expired project that was not verified.
+    /// Value of all balances of a project, in units of its first accepted token.
     ///
-    /// Donors must claim their refund within the 6-month refund window.
-    /// After the window expires, only the creator may reclaim unclaimed funds
-    /// via [`reclaim_expired_funds`].
-    pub fn refund(env: Env, donator: Address, project_id: u64, token: Address) {
-        donator.require_auth();
+    /// Each token's balance is converted with `balance * rate / first_rate`.
+    /// Tokens without a rate contribute nothing; if the first token has no
+    /// rate, only its own balance counts.
+    fn funded_value(env: &Env, config: &ProjectConfig) -> i128 {
+        let mut total: i128 = 0;
+        for token in config.accepted_tokens.iter() {
+            let balance = storage::get_token_balance(env, config.id, &token);
+            if balance == 0 {
+                continue;
+            }
+            if let Some((rate, base_rate)) = Self::goal_rate(env, config, &token) {
+                let value = match balance.checked_mul(rate) {
+                    Some(v) => v / base_rate,
+                    None => panic_with_error!(env, Error::Overflow),
+                };
+                total = match total.checked_add(value) {
+                    Some(t) => t,
+                    None => panic_with_error!(env, Error::Overflow),
+                };
+            }
+        }
+        total
+    }
 
-        let (config, mut state) = load_project_pair(&env, project_id);
+    /// Load a project for a refund, lazily expiring it if its deadline has
+    /// passed. Panics unless it is `Expired`, `Cancelled` or `Failed` and
+    /// still inside the refund window.
+    fn load_refundable_project(env: &Env, project_id: u64) -> (ProjectConfig, ProjectState) {
+        // Refunds ignore the global pause so donors can always exit.
+        if storage::get_pause_flags(env).refunds {
+            panic_with_error!(env, Error::ProtocolPaused);
+        }
+        let (config, mut state) = load_project_pair(env, project_id);
 
         if env.ledger().timestamp() >= config.deadline
             && matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active)
         {
             state.status = ProjectStatus::Expired;
             state.refund_expiry = env.ledger().timestamp() + REFUND_WINDOW;
-            save_project_state(&env, project_id, &state);
+            save_project_state(env, project_id, &state);
         }
 
         if !matches!(
             state.status,
-            ProjectStatus::Expired | ProjectStatus::Cancelled
+            ProjectStatus::Expired | ProjectStatus::Cancelled | ProjectStatus::Failed
         ) {
-            panic_with_error!(&env, Error::ProjectNotExpired);
+            panic_with_error!(env, Error::ProjectNotExpired);
         }
 
         // Block refunds after the refund window has expired.
         if state.refund_expiry > 0 && env.ledger().timestamp() >= state.refund_expiry {
-            panic_with_error!(&env, Error::RefundWindowExpired);
+            panic_with_error!(env, Error::RefundWindowExpired);
         }
 
-        let refund_amount = storage::get_donator_balance(&env, project_id, &token, &donator);
-        if refund_amount <= 0 {
-            panic_with_error!(&env, Error::InsufficientBalance);
-        }
-
-        // Zero-out first to prevent double-refund/reentrancy patterns.
-        storage::set_donator_balance(&env, project_id, &token, &donator, 0);
-        storage::add_to_token_balance(&env, project_id, &token, -refund_amount);
-
-        let contract_address = env.current_contract_address();
-        let token_client = token::Client::new(&env, &token);
-        token_client.transfer(&contract_address, &donator, &refund_amount);
-
-        events::emit_refunded(&env, project_id, donator, refund_amount);
+        (config, state)
     }
 
-    /// Grant the Oracle role to `oracle`.
-    ///
-    /// Replaces the original `set_oracle(admin, oracle)`.
-    /// - `caller` must hold `SuperAdmin` or `Admin`.
-    pub fn set_oracle(env: Env, caller: Address, oracle: Address) {
-        caller.require_auth();
-        rbac::require_admin_or_above(&env, &caller);
-        rbac::grant_role(&env, &caller, &oracle, Role::Oracle);
-    }
-
-    /// Update the global protocol configuration.
+    /// Refund `donator`'s balance of `token` and return the amount paid out.
     ///
-    /// - `caller` must be the `SuperAdmin`.
-    /// - `fee_bps` must be less than or equal to 1000 (10%).
-    pub fn update_protocol_config(env: Env, caller: Address, fee_recipient: Address, fee_bps: u32) {
-        caller.require_auth();
-        rbac::require_super_admin(&env, &caller);
-
-        if fee_bps > 1000 {
-            panic_with_error!(&env, Error::InvalidFeeBasisPoints);
+    /// With `push`, a failing transfer is tolerated: the balance is restored
+    /// so the donor can still claim it, and 0 is returned.
+    fn refund_token(
+        env: &Env,
+        config: &ProjectConfig,
+        state: &ProjectState,
+        donator: &Address,
+        token: &Address,
+        push: bool,
+    ) -> i128 {
+        let donor_balance = storage::get_donator_balance(env, config.id, token, donator);
+        if donor_balance <= 0 {
+            return 0;
         }
 
-        let old_config = get_protocol_config(&env);
-        let new_config = ProtocolConfig {
-            fee_recipient,
-            fee_bps,
-        };
+        // Tranches already paid out are not refundable; never pay out more
+        // than the project still holds for this token.
+        let refund_amount = Self::refundable_amount(env, config, state, donor_balance)
+            .min(storage::get_token_balance(env, config.id, token));
 
-        set_protocol_config(&env, &new_config);
+        // Zero-out first to prevent double-refund/reentrancy patterns.
+        storage::set_donator_balance(env, config.id, token, donator, 0);
+        storage::add_to_token_balance(env, config.id, token, -refund_amount);
+
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(env, token);
+        if !push {
+            token_client.transfer(&contract_address, donator, &refund_amount);
+        } else if !matches!(
+            token_client.try_transfer(&contract_address, donator, &refund_amount),
+            Ok(Ok(()))
+        ) {
+            storage::set_donator_balance(env, config.id, token, donator, donor_balance);
+            storage::add_to_token_balance(env, config.id, token, refund_amount);
+            return 0;
+        }
+        storage::record_receipt_refund(env, donator, config.id, token, refund_amount);
 
-        events::emit_protocol_config_updated(&env, old_config, new_config);
+        events::emit_refunded(env, config.id, donator.clone(), refund_amount);
+        refund_amount
     }
 
-    /// Verify proof of impact and release funds to the creator.
-    ///
-    /// The registered oracle submits a proof hash. If it matches the project's
-    /// stored `proof_hash`, the project status transitions to `Completed`.
-    ///
-    /// NOTE: This is a mocked verification (hash equality).
-    /// The structure is prepared for future ZK-STARK verification.
-    ///
-    /// Reads the immutable config (for proof_hash) and mutable state (for status),
-    /// then writes back only the small state entry.
-    pub fn verify_and_release(
-        env: Env,
-        oracle: Address,
-        project_id: u64,
-        submitted_proof_hash: BytesN<32>,
+    /// Check that `commitment == sha256(owner.to_xdr() ‖ salt)`.
+    fn require_commitment_owner(
+        env: &Env,
+        commitment: &BytesN<32>,
+        owner: &Address,
+        salt: &BytesN<32>,
     ) {
-        Self::require_not_paused(&env);
-        oracle.require_auth();
-        // RBAC gate: caller must hold the Oracle role.
-        rbac::require_oracle(&env, &oracle);
+        let mut preimage = owner.to_xdr(env);
+        preimage.append(&Bytes::from(salt.clone()));
+        let digest: BytesN<32> = env.crypto().sha256(&preimage).into();
+        if digest != *commitment {
+            panic_with_error!(env, Error::InvalidCommitment);
+        }
+    }
 
-        // Optimised dual-read helper
-        let (config, mut state) = load_project_pair(&env, project_id);
+    /// Load a project for verification, lazily expiring it if its deadline
+    /// has passed and rejecting any status that cannot be verified.
+    fn load_verifiable_project(env: &Env, project_id: u64) -> (ProjectConfig, ProjectState) {
+        Self::require_not_frozen(env, project_id);
+        let (config, mut state) = load_project_pair(env, project_id);
 
         if env.ledger().timestamp() >= config.deadline
             && matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active)
         {
             state.status = ProjectStatus::Expired;
             state.refund_expiry = env.ledger().timestamp() + REFUND_WINDOW;
-            save_project_state(&env, project_id, &state);
-            panic_with_error!(&env, Error::ProjectExpired);
+            save_project_state(env, project_id, &state);
+            panic_with_error!(env, Error::ProjectExpired);
         }
 
         // Ensure the project is in a verifiable state.
         match state.status {
             ProjectStatus::Funding | ProjectStatus::Active => {}
-            ProjectStatus::Completed => panic_with_error!(&env, Error::MilestoneAlreadyReleased),
-            ProjectStatus::Expired => panic_with_error!(&env, Error::ProjectExpired),
-            ProjectStatus::Cancelled => panic_with_error!(&env, Error::InvalidTransition),
+            ProjectStatus::Completed => panic_with_error!(env, Error::MilestoneAlreadyReleased),
+            ProjectStatus::Expired => panic_with_error!(env, Error::ProjectExpired),
+            ProjectStatus::Cancelled | ProjectStatus::Failed => {
+                panic_with_error!(env, Error::InvalidTransition)
+            }
+            ProjectStatus::PendingRelease => panic_with_error!(env, Error::ReleasePending),
         }
 
-        // Mocked ZK verification: compare submitted hash to stored hash.
-        if submitted_proof_hash != config.proof_hash {
-            panic_with_error!(&env, Error::VerificationFailed);
+        (config, state)
+    }
+
+    /// Count an attestation and release whatever it unlocks once the quorum
+    /// is reached. Authorisation has already been checked by the caller.
+    fn process_attestation(env: &Env, oracle: Address, project_id: u64, proof_hash: BytesN<32>) {
+        let (config, mut state) = Self::load_verifiable_project(env, project_id);
+        let milestone = Self::attested_milestone(env, &config, &state, &proof_hash);
+
+        let attestations = Self::attest(env, project_id, &oracle, &proof_hash);
+        if attestations >= storage::get_oracle_quorum(env) {
+            Self::queue_or_release(env, &config, &mut state, milestone, oracle, proof_hash);
+        }
+    }
+
+    /// Find what an attested `proof_hash` would release: `None` for the
+    /// project's own proof hash, or the first unreleased milestone with that
+    /// hash. Panics with `VerificationFailed` for any other hash, and with
+    /// `MilestoneAlreadyReleased` if only released milestones match.
+    fn attested_milestone(
+        env: &Env,
+        config: &ProjectConfig,
+        state: &ProjectState,
+        proof_hash: &BytesN<32>,
+    ) -> Option<u32> {
+        if config.milestones.is_empty() {
+            if *proof_hash != config.proof_hash {
+                panic_with_error!(env, Error::VerificationFailed);
+            }
+            return None;
+        }
+
+        let mut released = false;
+        for (i, milestone) in config.milestones.iter().enumerate() {
+            let index = i as u32;
+            if milestone.proof_hash != *proof_hash {
+                continue;
+            }
+            if state.released_milestones & (1u32 << index) == 0 {
+                return Some(index);
+            }
+            released = true;
+        }
+        if released {
+            panic_with_error!(env, Error::MilestoneAlreadyReleased);
+        }
+        panic_with_error!(env, Error::VerificationFailed)
+    }
+
+    /// Release the verified funds now, or queue them behind the dispute
+    /// window when one is configured. `milestone` is `None` for a full release.
+    fn queue_or_release(
+        env: &Env,
+        config: &ProjectConfig,
+        state: &mut ProjectState,
+        milestone: Option<u32>,
+        oracle: Address,
+        proof_hash: BytesN<32>,
+    ) {
+        let window = storage::get_dispute_window(env);
+        if window == 0 {
+            match milestone {
+                Some(index) => {
+                    Self::release_milestone(env, config, state, index, oracle, proof_hash)
+                }
+                None => Self::release_all(env, config, state, oracle, proof_hash),
+            }
+            return;
+        }
+
+        let release_after = env.ledger().timestamp() + window;
+        let queued = QueuedRelease {
+            milestone,
+            oracle,
+            proof_hash,
+            release_after,
+            resume_status: state.status.clone(),
+        };
+        storage::set_queued_release(env, config.id, &queued);
+        state.status = ProjectStatus::PendingRelease;
+        save_project_state(env, config.id, state);
+
+        events::emit_release_queued(env, config.id, milestone, release_after);
+    }
+
+    /// Build the byte payload covered by a signed oracle attestation.
+    fn attestation_payload(
+        env: &Env,
+        project_id: u64,
+        proof_hash: &BytesN<32>,
+        nonce: u64,
+        expiry: u64,
+    ) -> Bytes {
+        let mut payload = env.current_contract_address().to_xdr(env);
+        payload.extend_from_array(&project_id.to_be_bytes());
+        payload.append(&Bytes::from(proof_hash.clone()));
+        payload.extend_from_array(&nonce.to_be_bytes());
+        payload.extend_from_array(&expiry.to_be_bytes());
+        payload
+    }
+
+    /// Record `oracle`'s attestation of `proof_hash` and return the tally of
+    /// attesters that still hold the Oracle role.
+    ///
+    /// Panics with `Error::AlreadyAttested` if this oracle already attested
+    /// the same hash for the project.
+    fn attest(env: &Env, project_id: u64, oracle: &Address, proof_hash: &BytesN<32>) -> u32 {
+        if storage::has_attested(env, project_id, proof_hash, oracle) {
+            panic_with_error!(env, Error::AlreadyAttested);
         }
+        let attestations = storage::record_attestation(env, project_id, proof_hash, oracle);
+        events::emit_attestation_submitted(
+            env,
+            project_id,
+            oracle.clone(),
+            proof_hash.clone(),
+            attestations,
+        );
+        Self::valid_attestations(env, project_id, proof_hash)
+    }
+
+    /// Count the attesters of `proof_hash` that still hold the Oracle role,
+    /// so attestations from revoked or expired oracles no longer count.
+    fn valid_attestations(env: &Env, project_id: u64, proof_hash: &BytesN<32>) -> u32 {
+        storage::get_attesters(env, project_id, proof_hash)
+            .iter()
+            .filter(|oracle| rbac::has_role(env, oracle.clone(), Role::Oracle))
+            .count() as u32
+    }
+
+    /// Count the rejecters of a project that still hold the Oracle role, so
+    /// rejections from revoked or expired oracles no longer count.
+    fn valid_rejections(env: &Env, project_id: u64) -> u32 {
+        storage::get_rejecters(env, project_id)
+            .iter()
+            .filter(|oracle| rbac::has_role(env, oracle.clone(), Role::Oracle))
+            .count() as u32
+    }
 
+    /// Release every accepted token's balance and mark the project `Completed`.
+    fn release_all(
+        env: &Env,
+        config: &ProjectConfig,
+        state: &mut ProjectState,
+        oracle: Address,
+        proof_hash: BytesN<32>,
+    ) {
         // Transition to Completed — only write the state entry.
         state.status = ProjectStatus::Completed;
 
         // Transfer all deposited tokens to the creator.
         // If any transfer fails, panic to revert the entire transaction.
-        let contract_address = env.current_contract_address();
-        let protocol_config = get_protocol_config(&env);
+        let protocol_config = get_protocol_config(env);
 
         for token in config.accepted_tokens.iter() {
             // Drain the token balance (gets balance and zeros it).
-            let mut balance = drain_token_balance(&env, project_id, &token);
-
-            // Only transfer if there's a non-zero balance.
-            if balance > 0 {
-                let token_client = token::Client::new(&env, &token);
-
-                // Deduct platform fee if configured.
-                if let Some(config) = &protocol_config {
-                    if config.fee_bps > 0 {
-                        // fee = balance * bps / 10000
-                        let fee_amount = balance
-                            .checked_mul(config.fee_bps as i128)
-                            .unwrap_or(0)
-                            .checked_div(10000)
-                            .unwrap_or(0);
-
-                        if fee_amount > 0 {
-                            token_client.transfer(
-                                &contract_address,
-                                &config.fee_recipient,
-                                &fee_amount,
-                            );
-                            balance = balance.checked_sub(fee_amount).unwrap_or(balance);
-                            events::emit_fee_deducted(
-                                &env,
-                                project_id,
-                                token.clone(),
-                                fee_amount,
-                                config.fee_recipient.clone(),
-                            );
-                        }
-                    }
-                }
-
-                // Transfer remaining to creator.
-                if balance > 0 {
-                    token_client.transfer(&contract_address, &config.creator, &balance);
-                    // Emit funds_released event for this token.
-                    events::emit_funds_released(&env, project_id, token, balance);
-                }
-            }
+            let balance = drain_token_balance(env, config.id, &token);
+            Self::pay_out(env, config, &token, balance, &protocol_config);
         }
 
         // Save the updated state (now marked as Completed).
-        save_project_state(&env, project_id, &state);
+        save_project_state(env, config.id, state);
 
         // Standardized event emission
-        events::emit_project_verified(&env, project_id, oracle.clone(), submitted_proof_hash);
+        events::emit_project_verified(env, config.id, oracle, proof_hash);
     }
 
-    /// Mark a project as expired if its deadline has passed.
-    ///
-    /// Permissionless: anyone can trigger expiration once the deadline is met.
-    /// - Panics if project is not in Funding status.
-    /// - Panics if deadline has not passed.
-    pub fn expire_project(env: Env, project_id: u64) {
-        let (config, mut state) = load_project_pair(&env, project_id);
+    /// Release the tranche for milestone `index`, completing the project once
+    /// every milestone has been released.
+    fn release_milestone(
+        env: &Env,
+        config: &ProjectConfig,
+        state: &mut ProjectState,
+        index: u32,
+        oracle: Address,
+        proof_hash: BytesN<32>,
+    ) {
+        let milestone = match config.milestones.get(index) {
+            Some(m) => m,
+            None => panic_with_error!(env, Error::MilestoneNotFound),
+        };
 
-        // State transition check: only Funding or Active projects can expire.
-        // Completed projects cannot be expired.
-        match state.status {
-            ProjectStatus::Funding | ProjectStatus::Active => {}
-            _ => panic_with_error!(&env, Error::InvalidTransition),
-        }
+        // Share of what is still locked, not of the original total.
+        let remaining_bps = Self::unreleased_bps(config, state.released_milestones);
+        let protocol_config = get_protocol_config(env);
 
-        // Deadline check.
-        if env.ledger().timestamp() < config.deadline {
-            panic_with_error!(&env, Error::ProjectNotExpired);
+        for token in config.accepted_tokens.iter() {
+            let balance = storage::get_token_balance(env, config.id, &token);
+            let tranche = if milestone.share_bps == remaining_bps {
+                balance
+            } else {
+                match balance.checked_mul(milestone.share_bps as i128) {
+                    Some(v) => v / remaining_bps as i128,
+                    None => panic_with_error!(env, Error::Overflow),
+                }
+            };
+
+            if tranche > 0 {
+                storage::set_token_balance(env, config.id, &token, balance - tranche);
+                Self::pay_out(env, config, &token, tranche, &protocol_config);
+            }
         }
 
-        // Update status and save.
-        state.status = ProjectStatus::Expired;
-        state.refund_expiry = env.ledger().timestamp() + REFUND_WINDOW;
-        save_project_state(&env, project_id, &state);
+        state.released_milestones |= 1u32 << index;
+        let all_released = (1u32 << config.milestones.len()) - 1;
+        let completed = state.released_milestones == all_released;
+        if completed {
+            state.status = ProjectStatus::Completed;
+        }
+        save_project_state(env, config.id, state);
 
-        // Standardized event emission.
-        events::emit_project_expired(&env, project_id, config.deadline);
+        events::emit_milestone_released(env, config.id, index, oracle.clone(), proof_hash.clone());
+        if completed {
+            events::emit_project_verified(env, config.id, oracle, proof_hash);
+        }
     }
 
-    // ─────────────────────────────────────────────────────────
-    // Donor Refund Expiry
-    // ─────────────────────────────────────────────────────────
-
-    /// Reclaim unclaimed donor funds after the 6-month refund window has expired.
+    /// Transfer `amount` of `token` from the contract to the project's
+    /// beneficiaries (or creator), deducting the platform fee first when one
+    /// is configured.
     ///
-    /// Only the project creator may call this, and only for projects that are
-    /// `Expired` or `Cancelled` whose `refund_expiry` timestamp has passed.
-    /// For each accepted token, any remaining balance is transferred to the creator.
-    pub fn reclaim_expired_funds(env: Env, creator: Address, project_id: u64) {
-        Self::require_not_paused(&env);
-        creator.require_auth();
-
-        let (config, state) = load_project_pair(&env, project_id);
-
-        // Only the project creator may reclaim.
-        if creator != config.creator {
-            panic_with_error!(&env, Error::NotAuthorized);
+    /// The caller is responsible for debiting the project's token balance.
+    fn pay_out(
+        env: &Env,
+        config: &ProjectConfig,
+        token: &Address,
+        amount: i128,
+        protocol_config: &Option<ProtocolConfig>,
+    ) {
+        if amount <= 0 {
+            return;
         }
 
-        // Project must be in a terminal refundable state.
-        if !matches!(
-            state.status,
-            ProjectStatus::Expired | ProjectStatus::Cancelled
-        ) {
-            panic_with_error!(&env, Error::InvalidTransition);
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(env, token);
+        let mut remaining = amount;
+
+        // Deduct platform fee if configured.
+        if let Some(protocol) = protocol_config {
+            let fee_bps = Self::effective_fee_bps(env, config.id, protocol);
+            if fee_bps > 0 {
+                // fee = amount * bps / 10000
+                let fee_amount = amount
+                    .checked_mul(fee_bps as i128)
+                    .unwrap_or(0)
+                    .checked_div(10000)
+                    .unwrap_or(0);
+
+                if fee_amount > 0 {
+                    remaining = remaining.checked_sub(fee_amount).unwrap_or(remaining);
+
+                    // Without splits the whole fee goes to `fee_recipient`;
+                    // otherwise each split gets its share and the last one
+                    // also takes the rounding remainder.
+                    let mut splits = storage::get_fee_splits(env);
+                    if splits.is_empty() {
+                        splits.push_back(FeeSplit {
+                            recipient: protocol.fee_recipient.clone(),
+                            share_bps: TOTAL_FEE_SPLIT_BPS,
+                        });
+                    }
+                    let mut unpaid = fee_amount;
+                    for (i, split) in splits.iter().enumerate() {
+                        let share = if i as u32 + 1 == splits.len() {
+                            unpaid
+                        } else {
+                            fee_amount * split.share_bps as i128 / TOTAL_FEE_SPLIT_BPS as i128
+                        };
+                        if share <= 0 {
+                            continue;
+                        }
+                        unpaid -= share;
+                        token_client.transfer(&contract_address, &split.recipient, &share);
+                        events::emit_fee_deducted(
+                            env,
+                            config.id,
+                            token.clone(),
+                            share,
+                            split.recipient,
+                        );
+                    }
+                }
+            }
         }
 
-        // The refund window must have expired.
-        if state.refund_expiry == 0 || env.ledger().timestamp() < state.refund_expiry {
-            panic_with_error!(&env, Error::RefundWindowActive);
+        // Transfer remaining to the beneficiaries.
+        if remaining > 0 {
+            Self::pay_beneficiaries(env, config, &token_client, remaining);
+            events::emit_funds_released(env, config.id, token.clone(), remaining);
         }
+    }
 
-        // Drain remaining balances for each accepted token.
+    /// Transfer `amount` from the contract to the project's beneficiaries, or
+    /// to its creator when none are set. The last beneficiary also takes the
+    /// rounding remainder.
+    fn pay_beneficiaries(
+        env: &Env,
+        config: &ProjectConfig,
+        token_client: &token::Client,
+        amount: i128,
+    ) {
         let contract_address = env.current_contract_address();
-        for token in config.accepted_tokens.iter() {
-            let balance = drain_token_balance(&env, project_id, &token);
-            if balance > 0 {
-                let token_client = token::Client::new(&env, &token);
-                token_client.transfer(&contract_address, &config.creator, &balance);
+        let beneficiaries = storage::get_beneficiaries(env, config.id);
+        if beneficiaries.is_empty() {
+            token_client.transfer(&contract_address, &config.creator, &amount);
+            return;
+        }
 
-                events::emit_expired_funds_reclaimed(
-                    &env,
-                    project_id,
-                    config.creator.clone(),
-                    token,
-                    balance,
-                );
+        let mut unpaid = amount;
+        for (i, beneficiary) in beneficiaries.iter().enumerate() {
+            let share = if i as u32 + 1 == beneficiaries.len() {
+                unpaid
+            } else {
+                amount * beneficiary.share_bps as i128 / TOTAL_BENEFICIARY_BPS as i128
+            };
+            if share > 0 {
+                unpaid -= share;
+                token_client.transfer(&contract_address, &beneficiary.recipient, &share);
             }
         }
     }
 
-    // ─────────────────────────────────────────────────────────
-    // Internal Helpers
-    // ─────────────────────────────────────────────────────────
+    /// Sum of the shares (in bps) of all milestones not yet released.
+    fn unreleased_bps(config: &ProjectConfig, released_milestones: u32) -> u32 {
+        let mut remaining: u32 = 0;
+        for (i, milestone) in config.milestones.iter().enumerate() {
+            if released_milestones & (1u32 << i) == 0 {
+                remaining += milestone.share_bps;
+            }
+        }
+        remaining
+    }
 
-    fn require_not_paused(env: &Env) {
-        if storage::is_paused(env) {
-            panic_with_error!(env, Error::ProtocolPaused);
+    /// Portion of a donor's recorded balance that is still refundable after
+    /// any milestone tranches have been released.
+    fn refundable_amount(
+        env: &Env,
+        config: &ProjectConfig,
+        state: &ProjectState,
+        donor_balance: i128,
+    ) -> i128 {
+        if state.released_milestones == 0 {
+            return donor_balance;
+        }
+        let remaining_bps = Self::unreleased_bps(config, state.released_milestones);
+        match donor_balance.checked_mul(remaining_bps as i128) {
+            Some(v) => v / TOTAL_MILESTONE_BPS as i128,
+            None => panic_with_error!(env, Error::Overflow),
         }
     }
 }
+
+/// Integer square root: the largest `r` with `r * r <= n` (0 for `n <= 0`).
+fn isqrt(n: i128) -> i128 {
+    if n < 2 {
+        return n.max(0);
+    }
+    let mut x = n;
+    // `(x + 1) / 2` without overflowing at `i128::MAX`.
+    let mut y = x / 2 + (x & 1);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
@@ -13,7 +13,22 @@
 //! ## Storage layout
 //!
 //! - `RbacKey::SuperAdmin` → `Address`  — the one and only super-admin.
-//! - `RbacKey::Role(addr)` → `Role`     — the role held by `addr`, if any.
+//! - `RbacKey::Roles(addr)` → `Vec<RoleGrant>` — roles held by `addr`, with optional expiries.
+//! - `RbacKey::Role(addr)` → `Role`     — legacy single-role entry, read as a permanent grant.
+//! - `RbacKey::OracleKey(addr)` → `BytesN<32>` — ed25519 public key an oracle signs attestations with.
+//! - `RbacKey::OracleNonce(addr, nonce)` → `()` — marks a signed-attestation nonce as used.
+//! - `RbacKey::Council` → `Council` — admin council signers and approval threshold.
+//! - `RbacKey::CouncilProposalCount` → `u64` — council proposal ID counter.
+//! - `RbacKey::CouncilProposal(id)` → `CouncilProposal` — council action awaiting approvals.
+//!
+//! ## Admin council
+//!
+//! Once `set_council` is called, the SuperAdmin role moves to the contract's
+//! own address and SuperAdmin actions can only be taken by a council
+//! proposal that reached `threshold` signer approvals. No single key can act
+//! as SuperAdmin from then on. A proposal that is not executed within
+//! `COUNCIL_PROPOSAL_LIFETIME` expires; its proposer may cancel it earlier,
+//! and any signer may clear it once expired.
 //!
 //! ## Event emissions
 //!
@@ -22,43 +37,96 @@
 //!
 //! | Event topic prefix | Trigger |
 //! |--------------------|---------|
-//! | `role_set`         | Role granted or replaced |
-//! | `role_del`         | Role revoked |
+//! | `role_set`         | Role granted or renewed, with its expiry ledger |
+//! | `role_del`         | Role revoked, with the expiry it had |
+//! | `oracle_key_set`   | Oracle signing key registered or rotated |
+//! | `council_set`      | Council signers or threshold changed |
+//! | `council_proposed` | Council action proposed |
+//! | `council_approved` | Signer approved a council action |
+//! | `council_cancelled` | Council proposal cancelled |
 //!
 //! ## Threat model notes
 //!
-//! - `Admin` cannot escalate to `SuperAdmin` — only `SuperAdmin` may grant that role.
+//! - Nobody can escalate to `SuperAdmin` through `grant_role`. Only the address
+//!   stored under `RbacKey::SuperAdmin` holds it, so `set_council` and
+//!   `transfer_super_admin` leave no other SuperAdmin behind.
 //! - `SuperAdmin` cannot be removed via `revoke_role`; use `transfer_super_admin`.
-//! - An address holds **at most one role** at a time; granting a new role replaces the old one.
+//! - An address may hold several roles; each role appears at most once in its set.
+//! - A grant with an expiry ledger stops counting once `env.ledger().sequence()`
+//!   reaches it. Expired grants are pruned on the address's next role change.
+//! - SuperAdmin is never granted with an expiry, so the protocol cannot be left without one.
 
 #![allow(unused)]
 #![allow(deprecated)]
 
-use soroban_sdk::{contractevent, contracttype, Address, Env, Vec};
+use soroban_sdk::{contractevent, contracttype, Address, BytesN, Env, Vec};
 
 use crate::errors::Error;
+use crate::types::GovAction;
+
+/// Maximum number of council signers.
+pub const MAX_COUNCIL_SIGNERS: u32 = 20;
+
+/// Seconds a council proposal stays open for approval and execution.
+pub const COUNCIL_PROPOSAL_LIFETIME: u64 = 14 * 24 * 60 * 60;
 
 #[contractevent]
 pub struct RoleSet {
     pub target: Address,
     pub role: Role,
+    pub expires_at: Option<u32>,
     pub by: Option<Address>,
 }
 
 #[contractevent]
 pub struct RoleDel {
     pub target: Address,
+    pub role: Role,
+    pub expires_at: Option<u32>,
     pub by: Option<Address>,
 }
 
+#[contractevent]
+pub struct OracleKeySet {
+    pub oracle: Address,
+    pub public_key: BytesN<32>,
+    pub by: Address,
+}
+
+#[contractevent]
+pub struct CouncilSet {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+}
+
+#[contractevent]
+pub struct CouncilProposed {
+    pub proposal_id: u64,
+    pub action: CouncilAction,
+    pub proposer: Address,
+}
+
+#[contractevent]
+pub struct CouncilApproved {
+    pub proposal_id: u64,
+    pub signer: Address,
+    pub approvals: u32,
+}
+
+#[contractevent]
+pub struct CouncilCancelled {
+    pub proposal_id: u64,
+    pub by: Address,
+}
+
 // ─────────────────────────────────────────────────────────
 // Role enum — stored per address
 // ─────────────────────────────────────────────────────────
 
 /// The set of roles that can be assigned to an address.
 ///
-/// A single address may hold at most one role at a time.
-/// Upgrading or revoking replaces / removes the stored value.
+/// An address may hold any combination of roles; each is granted and
+/// revoked independently.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Role {
@@ -68,12 +136,84 @@ pub enum Role {
     Admin,
     /// Can call `verify_and_release`; replaces the single oracle address.
     Oracle,
-    /// Read-only observer; confirmed by off-chain checks rather than on-chain gates.
+    /// Can `dispute` a queued release during its dispute window and
+    /// `freeze_project` / `unfreeze_project` a single project.
     Auditor,
     /// Can call `register_project`; restricted to managing their own projects.
     ProjectManager,
 }
 
+/// Roles from most to least privileged; `role_of` reports the first one held.
+const ROLE_PRECEDENCE: [Role; 5] = [
+    Role::SuperAdmin,
+    Role::Admin,
+    Role::Oracle,
+    Role::Auditor,
+    Role::ProjectManager,
+];
+
+/// A role held by an address.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleGrant {
+    pub role: Role,
+    /// Ledger sequence from which the grant no longer applies; `None` never expires.
+    pub expires_at: Option<u32>,
+}
+
+// ─────────────────────────────────────────────────────────
+// Admin council
+// ─────────────────────────────────────────────────────────
+
+/// Signers that jointly hold the SuperAdmin role.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Council {
+    pub signers: Vec<Address>,
+    /// Approvals required to execute a council proposal.
+    pub threshold: u32,
+}
+
+/// A SuperAdmin action the council can take.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CouncilAction {
+    /// Apply a governance action immediately. Subject to the same rules as
+    /// the direct entry points: actions they refuse once projects exist fail
+    /// with `TimelockRequired` and must be proposed instead.
+    Apply(GovAction),
+    /// Queue a governance action behind the governance timelock.
+    Propose(GovAction),
+    /// Cancel a queued governance proposal.
+    CancelProposal(u64),
+    /// Propose a contract upgrade to the given WASM hash.
+    ProposeUpgrade(BytesN<32>),
+    /// Cancel the pending contract upgrade.
+    CancelUpgrade,
+    /// Execute the pending contract upgrade after its delay.
+    ExecuteUpgrade,
+    /// Run one `migrate` batch of the given size.
+    Migrate(u32),
+    /// Replace the council signers and threshold.
+    SetCouncil(Vec<Address>, u32),
+    /// Dissolve the council and hand SuperAdmin to a single address.
+    TransferSuperAdmin(Address),
+}
+
+/// A council action awaiting signer approvals.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilProposal {
+    pub id: u64,
+    pub action: CouncilAction,
+    pub proposer: Address,
+    /// Signers that approved; the proposer approves implicitly.
+    pub approvals: Vec<Address>,
+    /// Ledger timestamp from which the proposal can no longer be approved
+    /// or executed.
+    pub expires_at: u64,
+}
+
 // ─────────────────────────────────────────────────────────
 // Storage keys
 // ─────────────────────────────────────────────────────────
@@ -81,35 +221,119 @@ pub enum Role {
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum RbacKey {
-    /// Maps an address → its current Role (if any).
+    /// Legacy single-role entry written before role sets; replaced by `Roles`
+    /// on the address's next role change.
     Role(Address),
+    /// Maps an address → the roles it holds.
+    Roles(Address),
     /// The one and only SuperAdmin address.
     SuperAdmin,
+    /// Maps an oracle address → the ed25519 public key it signs attestations with.
+    OracleKey(Address),
+    /// Marks a signed-attestation nonce as consumed for an oracle.
+    OracleNonce(Address, u64),
+    /// The admin council, once configured.
+    Council,
+    /// Council proposal ID counter.
+    CouncilProposalCount,
+    /// Council action awaiting approvals, keyed by proposal ID.
+    CouncilProposal(u64),
 }
 
 // ─────────────────────────────────────────────────────────
 // Storage helpers (private)
 // ─────────────────────────────────────────────────────────
 
-/// Persist a role assignment. Overwrites any existing role.
-fn store_role(env: &Env, address: &Address, role: &Role) {
-    env.storage()
-        .persistent()
-        .set(&RbacKey::Role(address.clone()), role);
+/// Returns `true` while `grant` has not reached its expiry ledger.
+fn is_live(env: &Env, grant: &RoleGrant) -> bool {
+    !matches!(grant.expires_at, Some(at) if env.ledger().sequence() >= at)
 }
 
-/// Remove any role stored for `address`.
-fn clear_role(env: &Env, address: &Address) {
-    env.storage()
-        .persistent()
-        .remove(&RbacKey::Role(address.clone()));
+/// Read every grant stored for `address`, expired ones included.
+///
+/// Addresses assigned before role sets existed still have a single
+/// `RbacKey::Role` entry; it is read as a permanent grant.
+fn load_grants(env: &Env, address: &Address) -> Vec<RoleGrant> {
+    let storage = env.storage().persistent();
+    if let Some(grants) = storage.get(&RbacKey::Roles(address.clone())) {
+        return grants;
+    }
+    let mut grants = Vec::new(env);
+    if let Some(role) = storage.get::<_, Role>(&RbacKey::Role(address.clone())) {
+        grants.push_back(RoleGrant {
+            role,
+            expires_at: None,
+        });
+    }
+    grants
+}
+
+/// Persist the grants for `address`, dropping expired ones.
+fn store_grants(env: &Env, address: &Address, grants: &Vec<RoleGrant>) {
+    let mut live = Vec::new(env);
+    for grant in grants.iter() {
+        if is_live(env, &grant) {
+            live.push_back(grant);
+        }
+    }
+
+    let storage = env.storage().persistent();
+    storage.remove(&RbacKey::Role(address.clone()));
+    if live.is_empty() {
+        storage.remove(&RbacKey::Roles(address.clone()));
+    } else {
+        storage.set(&RbacKey::Roles(address.clone()), &live);
+    }
 }
 
-/// Read the role for `address`, returning `None` if unassigned.
+/// Add `grant` to the set held by `address`, replacing any grant of the same role.
+fn store_grant(env: &Env, address: &Address, grant: &RoleGrant) {
+    let mut grants = load_grants(env, address);
+    match grants.iter().position(|g| g.role == grant.role) {
+        Some(i) => grants.set(i as u32, grant.clone()),
+        None => grants.push_back(grant.clone()),
+    }
+    store_grants(env, address, &grants);
+}
+
+/// Remove the grant of `role` from `address` and return it, if any was stored.
+fn clear_grant(env: &Env, address: &Address, role: &Role) -> Option<RoleGrant> {
+    let mut grants = load_grants(env, address);
+    let i = grants.iter().position(|g| g.role == *role)? as u32;
+    let grant = grants.get(i);
+    grants.remove(i);
+    store_grants(env, address, &grants);
+    grant
+}
+
+/// Read the unexpired grants held by `address`.
+///
+/// A `SuperAdmin` grant only counts for the address stored as SuperAdmin, so
+/// grants made before the role moved elsewhere no longer apply.
+pub fn get_grants(env: &Env, address: &Address) -> Vec<RoleGrant> {
+    let mut live = Vec::new(env);
+    for grant in load_grants(env, address).iter() {
+        if is_live(env, &grant)
+            && (grant.role != Role::SuperAdmin || get_super_admin(env).as_ref() == Some(address))
+        {
+            live.push_back(grant);
+        }
+    }
+    live
+}
+
+/// Read the most privileged unexpired role held by `address`, returning
+/// `None` if it holds none.
 pub fn get_role(env: &Env, address: &Address) -> Option<Role> {
-    env.storage()
-        .persistent()
-        .get(&RbacKey::Role(address.clone()))
+    let grants = get_grants(env, address);
+    ROLE_PRECEDENCE
+        .into_iter()
+        .find(|role| grants.iter().any(|g| g.role == *role))
+}
+
+/// Returns `true` if `address` holds an unexpired grant of `role`.
+fn holds(env: &Env, address: &Address, role: &Role) -> bool {
+    get_grants(env, address).iter().any(|g| g.role == *role)
 }
 
 /// Read the SuperAdmin address, returning `None` before init.
@@ -130,54 +354,87 @@ pub fn init_super_admin(env: &Env, super_admin: &Address) {
     env.storage()
         .persistent()
         .set(&RbacKey::SuperAdmin, super_admin);
-    store_role(env, super_admin, &Role::SuperAdmin);
+    store_grant(env, super_admin, &permanent(Role::SuperAdmin));
 
-    emit(env, super_admin, &Role::SuperAdmin, None::<Address>);
+    emit(env, super_admin, &permanent(Role::SuperAdmin), None::<Address>);
 }
 
 // ─────────────────────────────────────────────────────────
 // Role assignment
 // ─────────────────────────────────────────────────────────
 
-/// Grant `role` to `target`.
+/// Grant `role` to `target`, optionally until ledger `expires_at`.
 ///
 /// - `caller` must hold `SuperAdmin` or `Admin`.
-/// - `Admin` callers cannot grant `SuperAdmin` — only SuperAdmin can elevate.
-/// - Assigning a role to an address that already has one replaces it.
+/// - `SuperAdmin` cannot be granted; it only moves with `transfer_super_admin`
+///   or to the council with `set_council`.
+/// - Roles `target` already holds are kept; granting a role it holds again
+///   replaces that grant's expiry.
 ///
 /// Emits a `role_set` event.
-pub fn grant_role(env: &Env, caller: &Address, target: &Address, role: Role) {
-    let caller_role = get_role(env, caller);
-
-    match &role {
-        // Only SuperAdmin can grant SuperAdmin
-        Role::SuperAdmin => {
-            require_role(env, caller, &Role::SuperAdmin);
-        }
-        // Admin or SuperAdmin can grant everything else
-        _ => {
-            require_any_of(env, caller, &[Role::SuperAdmin, Role::Admin]);
-        }
+pub fn grant_role(
+    env: &Env,
+    caller: &Address,
+    target: &Address,
+    role: Role,
+    expires_at: Option<u32>,
+) {
+    require_can_grant(env, caller, &role);
+    validate_expiry(env, &role, expires_at);
+    if role == Role::SuperAdmin {
+        panic_with_error_rbac(env, Error::NotAuthorized);
     }
 
-    // Prevent demotion of the SuperAdmin via grant_role
-    if let Some(Role::SuperAdmin) = get_role(env, target) {
-        if role != Role::SuperAdmin {
-            panic_with_error_rbac(env, Error::NotAuthorized);
-        }
+    let grant = RoleGrant { role, expires_at };
+    store_grant(env, target, &grant);
+    emit(env, target, &grant, Some(caller.clone()));
+}
+
+/// Move the expiry of a role `target` currently holds to `expires_at`.
+///
+/// - `caller` needs the same authority as for granting `role`.
+/// - `None` makes the grant permanent.
+/// - Panics with `Error::RoleNotFound` if `target` has no unexpired grant of
+///   `role`; a lapsed grant must be granted again.
+/// - Unless `may_extend` is set, panics with `Error::TimelockRequired` if the
+///   new expiry is later than the current one.
+///
+/// Emits a `role_set` event.
+pub fn renew_role(
+    env: &Env,
+    caller: &Address,
+    target: &Address,
+    role: Role,
+    expires_at: Option<u32>,
+    may_extend: bool,
+) {
+    require_can_grant(env, caller, &role);
+    validate_expiry(env, &role, expires_at);
+    let current = match get_grants(env, target).iter().find(|g| g.role == role) {
+        Some(grant) => grant.expires_at,
+        None => panic_with_error_rbac(env, Error::RoleNotFound),
+    };
+    let extends = match (current, expires_at) {
+        (None, _) => false,
+        (Some(_), None) => true,
+        (Some(current), Some(new)) => new > current,
+    };
+    if extends && !may_extend {
+        panic_with_error_rbac(env, Error::TimelockRequired);
     }
 
-    store_role(env, target, &role);
-    emit(env, target, &role, Some(caller.clone()));
+    let grant = RoleGrant { role, expires_at };
+    store_grant(env, target, &grant);
+    emit(env, target, &grant, Some(caller.clone()));
 }
 
-/// Revoke any role from `target`.
+/// Revoke every role from `target`.
 ///
 /// - `caller` must hold `SuperAdmin` or `Admin`.
 /// - The SuperAdmin address itself cannot be revoked; use `transfer_super_admin`.
-/// - Revoking a role from an address with no role is a no-op.
+/// - Revoking from an address with no role is a no-op.
 ///
-/// Emits a `role_del` event if a role existed.
+/// Emits a `role_del` event for each unexpired role removed.
 pub fn revoke_role(env: &Env, caller: &Address, target: &Address) {
     require_any_of(env, caller, &[Role::SuperAdmin, Role::Admin]);
 
@@ -187,9 +444,35 @@ pub fn revoke_role(env: &Env, caller: &Address, target: &Address) {
         panic_with_error_rbac(env, Error::NotAuthorized);
     }
 
-    if get_role(env, target).is_some() {
-        clear_role(env, target);
-        emit_revoke(env, target, Some(caller.clone()));
+    let grants = get_grants(env, target);
+    store_grants(env, target, &Vec::new(env));
+    // A revoked oracle must not be able to keep relaying signed attestations.
+    clear_oracle_key(env, target);
+    for grant in grants.iter() {
+        emit_revoke(env, target, &grant, Some(caller.clone()));
+    }
+}
+
+/// Revoke a single role from `target`, leaving its other roles in place.
+///
+/// - `caller` must hold `SuperAdmin` or `Admin`.
+/// - `SuperAdmin` cannot be revoked; use `transfer_super_admin`.
+/// - Revoking a role `target` does not hold is a no-op.
+///
+/// Emits a `role_del` event if the role was held.
+pub fn revoke_single_role(env: &Env, caller: &Address, target: &Address, role: Role) {
+    require_any_of(env, caller, &[Role::SuperAdmin, Role::Admin]);
+    if role == Role::SuperAdmin {
+        panic_with_error_rbac(env, Error::NotAuthorized);
+    }
+
+    if let Some(grant) = clear_grant(env, target, &role) {
+        if role == Role::Oracle {
+            clear_oracle_key(env, target);
+        }
+        if is_live(env, &grant) {
+            emit_revoke(env, target, &grant, Some(caller.clone()));
+        }
     }
 }
 
@@ -203,40 +486,285 @@ pub fn revoke_role(env: &Env, caller: &Address, target: &Address) {
 pub fn transfer_super_admin(env: &Env, current: &Address, new: &Address) {
     require_role(env, current, &Role::SuperAdmin);
 
-    // Clear old SuperAdmin
-    clear_role(env, current);
-    emit_revoke(env, current, Some(current.clone()));
+    // Clear old SuperAdmin; any other roles it holds are kept
+    clear_grant(env, current, &Role::SuperAdmin);
+    emit_revoke(env, current, &permanent(Role::SuperAdmin), Some(current.clone()));
 
     // Set new SuperAdmin
     env.storage().persistent().set(&RbacKey::SuperAdmin, new);
-    store_role(env, new, &Role::SuperAdmin);
-    emit(env, new, &Role::SuperAdmin, Some(current.clone()));
+    store_grant(env, new, &permanent(Role::SuperAdmin));
+    emit(env, new, &permanent(Role::SuperAdmin), Some(current.clone()));
+}
+
+// ─────────────────────────────────────────────────────────
+// Oracle signing keys
+// ─────────────────────────────────────────────────────────
+
+/// Register or rotate the ed25519 public key `oracle` signs attestations with.
+///
+/// - `caller` must hold `SuperAdmin` or `Admin`.
+/// - `oracle` must currently hold the `Oracle` role.
+///
+/// Emits an `oracle_key_set` event.
+pub fn set_oracle_key(env: &Env, caller: &Address, oracle: &Address, public_key: &BytesN<32>) {
+    require_admin_or_above(env, caller);
+    require_oracle(env, oracle);
+
+    env.storage()
+        .persistent()
+        .set(&RbacKey::OracleKey(oracle.clone()), public_key);
+
+    OracleKeySet {
+        oracle: oracle.clone(),
+        public_key: public_key.clone(),
+        by: caller.clone(),
+    }
+    .publish(env);
+}
+
+/// Read the signing key registered for `oracle`, returning `None` if unset.
+pub fn get_oracle_key(env: &Env, oracle: &Address) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&RbacKey::OracleKey(oracle.clone()))
+}
+
+/// Mark `nonce` as used for `oracle`.
+/// Panics with `Error::NonceAlreadyUsed` if it was consumed before.
+pub fn consume_oracle_nonce(env: &Env, oracle: &Address, nonce: u64) {
+    let key = RbacKey::OracleNonce(oracle.clone(), nonce);
+    if env.storage().persistent().has(&key) {
+        panic_with_error_rbac(env, Error::NonceAlreadyUsed);
+    }
+    env.storage().persistent().set(&key, &());
+}
+
+// ─────────────────────────────────────────────────────────
+// Admin council
+// ─────────────────────────────────────────────────────────
+
+/// Read the council, returning `None` while a single SuperAdmin is in charge.
+pub fn get_council(env: &Env) -> Option<Council> {
+    env.storage().persistent().get(&RbacKey::Council)
+}
+
+/// Store a new council after validating it.
+///
+/// - `signers` must be non-empty, unique, and at most `MAX_COUNCIL_SIGNERS`.
+/// - `threshold` must be between 1 and the number of signers.
+///
+/// Emits a `council_set` event.
+pub fn store_council(env: &Env, signers: &Vec<Address>, threshold: u32) {
+    if signers.is_empty()
+        || signers.len() > MAX_COUNCIL_SIGNERS
+        || threshold == 0
+        || threshold > signers.len()
+    {
+        panic_with_error_rbac(env, Error::InvalidCouncil);
+    }
+    for i in 0..signers.len() {
+        let signer = signers.get(i).unwrap();
+        if signers.last_index_of(&signer) != Some(i) {
+            panic_with_error_rbac(env, Error::InvalidCouncil);
+        }
+    }
+
+    env.storage().persistent().set(
+        &RbacKey::Council,
+        &Council {
+            signers: signers.clone(),
+            threshold,
+        },
+    );
+    CouncilSet {
+        signers: signers.clone(),
+        threshold,
+    }
+    .publish(env);
+}
+
+/// Remove the council; used when SuperAdmin is handed back to one address.
+pub fn clear_council(env: &Env) {
+    env.storage().persistent().remove(&RbacKey::Council);
+}
+
+/// Assert that `signer` is a member of the council.
+/// Panics with `Error::NotAuthorized` otherwise, or if no council exists.
+pub fn require_council_signer(env: &Env, signer: &Address) {
+    match get_council(env) {
+        Some(council) if council.signers.contains(signer) => {}
+        _ => panic_with_error_rbac(env, Error::NotAuthorized),
+    }
+}
+
+/// Store a new council proposal approved by its proposer and return its ID.
+///
+/// Emits a `council_proposed` event.
+pub fn create_council_proposal(env: &Env, proposer: &Address, action: CouncilAction) -> u64 {
+    require_council_signer(env, proposer);
+
+    let id: u64 = env
+        .storage()
+        .persistent()
+        .get(&RbacKey::CouncilProposalCount)
+        .unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&RbacKey::CouncilProposalCount, &(id + 1));
+
+    let mut approvals = Vec::new(env);
+    approvals.push_back(proposer.clone());
+    env.storage().persistent().set(
+        &RbacKey::CouncilProposal(id),
+        &CouncilProposal {
+            id,
+            action: action.clone(),
+            proposer: proposer.clone(),
+            approvals,
+            expires_at: env.ledger().timestamp() + COUNCIL_PROPOSAL_LIFETIME,
+        },
+    );
+
+    CouncilProposed {
+        proposal_id: id,
+        action,
+        proposer: proposer.clone(),
+    }
+    .publish(env);
+    id
+}
+
+/// Read a pending council proposal.
+pub fn get_council_proposal(env: &Env, id: u64) -> Option<CouncilProposal> {
+    env.storage()
+        .persistent()
+        .get(&RbacKey::CouncilProposal(id))
+}
+
+/// Read a pending council proposal, panicking with `ProposalNotFound` if it
+/// does not exist and with `CouncilProposalExpired` if it has expired.
+fn load_open_council_proposal(env: &Env, id: u64) -> CouncilProposal {
+    let proposal = match get_council_proposal(env, id) {
+        Some(p) => p,
+        None => panic_with_error_rbac(env, Error::ProposalNotFound),
+    };
+    if env.ledger().timestamp() >= proposal.expires_at {
+        panic_with_error_rbac(env, Error::CouncilProposalExpired);
+    }
+    proposal
+}
+
+/// Record `signer`'s approval of proposal `id` and return the approval count.
+///
+/// Emits a `council_approved` event.
+pub fn approve_council_proposal(env: &Env, signer: &Address, id: u64) -> u32 {
+    require_council_signer(env, signer);
+
+    let mut proposal = load_open_council_proposal(env, id);
+    if proposal.approvals.contains(signer) {
+        panic_with_error_rbac(env, Error::AlreadyApproved);
+    }
+    proposal.approvals.push_back(signer.clone());
+    env.storage()
+        .persistent()
+        .set(&RbacKey::CouncilProposal(id), &proposal);
+
+    let approvals = proposal.approvals.len();
+    CouncilApproved {
+        proposal_id: id,
+        signer: signer.clone(),
+        approvals,
+    }
+    .publish(env);
+    approvals
+}
+
+/// Remove proposal `id` and return its action if enough current signers
+/// approved it.
+///
+/// Approvals from addresses that have since left the council do not count.
+/// Panics with `Error::ThresholdNotMet` otherwise.
+pub fn take_approved_council_action(env: &Env, id: u64) -> CouncilAction {
+    let council = match get_council(env) {
+        Some(c) => c,
+        None => panic_with_error_rbac(env, Error::NotAuthorized),
+    };
+    let proposal = load_open_council_proposal(env, id);
+
+    let mut valid: u32 = 0;
+    for approver in proposal.approvals.iter() {
+        if council.signers.contains(&approver) {
+            valid += 1;
+        }
+    }
+    if valid < council.threshold {
+        panic_with_error_rbac(env, Error::ThresholdNotMet);
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&RbacKey::CouncilProposal(id));
+    proposal.action
+}
+
+/// Remove proposal `id` without executing it.
+///
+/// - `signer` must be a council member.
+/// - Before the proposal expires only its proposer may cancel it; once
+///   expired, any signer may clear it.
+///
+/// Emits a `council_cancelled` event.
+pub fn cancel_council_proposal(env: &Env, signer: &Address, id: u64) {
+    require_council_signer(env, signer);
+    let proposal = match get_council_proposal(env, id) {
+        Some(p) => p,
+        None => panic_with_error_rbac(env, Error::ProposalNotFound),
+    };
+    if proposal.proposer != *signer && env.ledger().timestamp() < proposal.expires_at {
+        panic_with_error_rbac(env, Error::NotAuthorized);
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&RbacKey::CouncilProposal(id));
+    CouncilCancelled {
+        proposal_id: id,
+        by: signer.clone(),
+    }
+    .publish(env);
 }
 
 // ─────────────────────────────────────────────────────────
 // Access guards (called from lib.rs handlers)
 // ─────────────────────────────────────────────────────────
 
-/// Assert that `address` holds exactly `required_role`.
+/// Assert that `address` holds an unexpired grant of `required_role`.
 /// Panics with `Error::NotAuthorized` on failure.
 pub fn require_role(env: &Env, address: &Address, required_role: &Role) {
-    match get_role(env, address) {
-        Some(ref r) if r == required_role => {}
-        _ => panic_with_error_rbac(env, Error::NotAuthorized),
+    if !holds(env, address, required_role) {
+        panic_with_error_rbac(env, Error::NotAuthorized);
     }
 }
 
 /// Assert that `address` holds one of the roles in `allowed`.
 /// Panics with `Error::NotAuthorized` if none match.
 pub fn require_any_of(env: &Env, address: &Address, allowed: &[Role]) {
-    if let Some(ref r) = get_role(env, address) {
-        if allowed.contains(r) {
-            return;
-        }
+    if get_grants(env, address)
+        .iter()
+        .any(|g| allowed.contains(&g.role))
+    {
+        return;
     }
     panic_with_error_rbac(env, Error::NotAuthorized);
 }
 
+/// Assert that `address` is the SuperAdmin.
+/// Used to gate upgrades and protocol-wide configuration.
+#[inline]
+pub fn require_super_admin(env: &Env, address: &Address) {
+    require_role(env, address, &Role::SuperAdmin);
+}
+
 /// Assert that `address` is the SuperAdmin OR an Admin.
 /// Convenience wrapper used on configuration-level operations.
 #[inline]
@@ -251,6 +779,20 @@ pub fn require_oracle(env: &Env, address: &Address) {
     require_role(env, address, &Role::Oracle);
 }
 
+/// Assert that `address` holds the Auditor role.
+/// Used to gate `dispute`.
+#[inline]
+pub fn require_auditor(env: &Env, address: &Address) {
+    require_role(env, address, &Role::Auditor);
+}
+
+/// Assert that `address` may freeze and unfreeze projects.
+/// SuperAdmin, Admin, and Auditor are permitted.
+#[inline]
+pub fn require_can_freeze(env: &Env, address: &Address) {
+    require_any_of(env, address, &[Role::SuperAdmin, Role::Admin, Role::Auditor]);
+}
+
 /// Assert that `address` may register and manage projects.
 /// ProjectManager, Admin, and SuperAdmin may all register projects.
 #[inline]
@@ -273,34 +815,77 @@ pub fn require_can_cancel_project(env: &Env, address: &Address) {
 // Queries
 // ─────────────────────────────────────────────────────────
 
-/// Returns the role held by `address`, or `None`.
+/// Returns the most privileged unexpired role held by `address`, or `None`.
 pub fn role_of(env: &Env, address: Address) -> Option<Role> {
     get_role(env, &address)
 }
 
-/// Returns `true` if `address` holds `role`.
+/// Returns the unexpired roles held by `address` with their expiry ledgers.
+pub fn roles_of(env: &Env, address: Address) -> Vec<RoleGrant> {
+    get_grants(env, &address)
+}
+
+/// Returns `true` if `address` holds an unexpired grant of `role`.
 pub fn has_role(env: &Env, address: Address, role: Role) -> bool {
-    get_role(env, &address).map(|r| r == role).unwrap_or(false)
+    holds(env, &address, &role)
 }
 
 // ─────────────────────────────────────────────────────────
 // Internal helpers
 // ─────────────────────────────────────────────────────────
 
+/// A grant of `role` that never expires.
+fn permanent(role: Role) -> RoleGrant {
+    RoleGrant {
+        role,
+        expires_at: None,
+    }
+}
+
+/// Assert that `caller` may grant or renew `role`.
+fn require_can_grant(env: &Env, caller: &Address, role: &Role) {
+    match role {
+        // Only SuperAdmin can grant SuperAdmin
+        Role::SuperAdmin => require_role(env, caller, &Role::SuperAdmin),
+        // Admin or SuperAdmin can grant everything else
+        _ => require_any_of(env, caller, &[Role::SuperAdmin, Role::Admin]),
+    }
+}
+
+/// Assert that `expires_at` is a future ledger and that `role` may expire.
+/// Panics with `Error::InvalidRoleExpiry` otherwise.
+fn validate_expiry(env: &Env, role: &Role, expires_at: Option<u32>) {
+    if let Some(at) = expires_at {
+        if *role == Role::SuperAdmin || at <= env.ledger().sequence() {
+            panic_with_error_rbac(env, Error::InvalidRoleExpiry);
+        }
+    }
+}
+
+/// Remove the signing key registered for `oracle`, if any.
+fn clear_oracle_key(env: &Env, oracle: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&RbacKey::OracleKey(oracle.clone()));
+}
+
 /// Emit a role assignment event.
-fn emit(env: &Env, target: &Address, role: &Role, by: Option<Address>) {
+fn emit(env: &Env, target: &Address, grant: &RoleGrant, by: Option<Address>) {
     RoleSet {
         target: target.clone(),
-        role: role.clone(),
+        role: grant.role.clone(),
+        expires_at: grant.expires_at,
         by,
     }
     .publish(env);
 }
 
 /// Emit a role revocation event.
-fn emit_revoke(env: &Env, target: &Address, by: Option<Address>) {
+fn emit_revoke(env: &Env, target: &Address, grant: &RoleGrant, by: Option<Address>) {
     RoleDel {
         target: target.clone(),
+        role: grant.role.clone(),
+        expires_at: grant.expires_at,
         by,
     }
     .publish(env);
@@ -1,7 +1,24 @@
 extern crate std;
 
 use crate::{test_utils::TestContext, Role};
-use soroban_sdk::{vec, Bytes};
+use soroban_sdk::{
+    testutils::{MockAuth, MockAuthInvoke},
+    vec, Address, IntoVal, Val, Vec,
+};
+
+/// Mock only `signer`'s authorization of `fn_name` called with `args`, so
+/// calls that need anyone else's signature fail.
+fn mock_auth_of(ctx: &TestContext, signer: &Address, fn_name: &str, args: Vec<Val>) {
+    ctx.env.mock_auths(&[MockAuth {
+        address: signer,
+        invoke: &MockAuthInvoke {
+            contract: &ctx.client.address,
+            fn_name,
+            args,
+            sub_invokes: &[],
+        },
+    }]);
+}
 
 #[test]
 fn test_init_sets_super_admin() {
@@ -72,7 +89,8 @@ fn test_transfer_super_admin() {
 #[test]
 fn test_project_manager_can_register() {
     let ctx = TestContext::new();
-    let tokens = vec![&ctx.env, ctx.generate_address()];
+    let (token, _) = ctx.create_token();
+    let tokens = vec![&ctx.env, token.address.clone()];
 
     let metadata_uri = ctx.dummy_metadata_uri();
     let project = ctx.client.register_project(
@@ -82,6 +100,8 @@ fn test_project_manager_can_register() {
         &ctx.dummy_proof(),
         &metadata_uri,
         &(ctx.env.ledger().timestamp() + 86400),
+        &false,
+        &Vec::new(&ctx.env),
     );
     assert_eq!(project.creator, ctx.manager);
 }
@@ -97,3 +117,144 @@ fn test_oracle_can_verify() {
     let completed = ctx.client.get_project(&project.id);
     assert_eq!(completed.status, crate::ProjectStatus::Completed);
 }
+
+#[test]
+fn test_address_can_hold_several_roles() {
+    let ctx = TestContext::new();
+    ctx.client.grant_role(&ctx.admin, &ctx.manager, &Role::Oracle);
+
+    assert!(ctx.client.has_role(&ctx.manager, &Role::ProjectManager));
+    assert!(ctx.client.has_role(&ctx.manager, &Role::Oracle));
+    assert_eq!(ctx.client.role_of(&ctx.manager), Some(Role::Oracle));
+    assert_eq!(ctx.client.roles_of(&ctx.manager).len(), 2);
+}
+
+#[test]
+fn test_revoke_single_role_keeps_others() {
+    let ctx = TestContext::new();
+    ctx.client.grant_role(&ctx.admin, &ctx.manager, &Role::Oracle);
+
+    ctx.client
+        .revoke_single_role(&ctx.admin, &ctx.manager, &Role::Oracle);
+    assert!(!ctx.client.has_role(&ctx.manager, &Role::Oracle));
+    assert!(ctx.client.has_role(&ctx.manager, &Role::ProjectManager));
+}
+
+#[test]
+fn test_timed_grant_lapses_at_expiry() {
+    let ctx = TestContext::new();
+    let oracle = ctx.generate_address();
+    let expires_at = ctx.env.ledger().sequence() + 10;
+    ctx.client
+        .grant_role_until(&ctx.admin, &oracle, &Role::Oracle, &expires_at);
+
+    ctx.jump_ledgers(9);
+    assert!(ctx.client.has_role(&oracle, &Role::Oracle));
+    ctx.jump_ledgers(1);
+    assert!(!ctx.client.has_role(&oracle, &Role::Oracle));
+    assert_eq!(ctx.client.role_of(&oracle), None);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_lapsed_oracle_cannot_verify() {
+    let ctx = TestContext::new();
+    let oracle = ctx.generate_address();
+    let expires_at = ctx.env.ledger().sequence() + 10;
+    ctx.client
+        .grant_role_until(&ctx.admin, &oracle, &Role::Oracle, &expires_at);
+    let (project, _, _) = ctx.setup_project(100);
+
+    ctx.jump_ledgers(10);
+    ctx.client
+        .verify_and_release(&oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+fn test_renew_role_extends_expiry() {
+    let ctx = TestContext::new();
+    let oracle = ctx.generate_address();
+    let expires_at = ctx.env.ledger().sequence() + 10;
+    ctx.client
+        .grant_role_until(&ctx.admin, &oracle, &Role::Oracle, &expires_at);
+
+    ctx.client
+        .renew_role(&ctx.admin, &oracle, &Role::Oracle, &Some(expires_at + 10));
+    ctx.jump_ledgers(15);
+    assert!(ctx.client.has_role(&oracle, &Role::Oracle));
+    assert_eq!(
+        ctx.client.roles_of(&oracle).get(0).unwrap().expires_at,
+        Some(expires_at + 10)
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #9)")]
+fn test_lapsed_role_cannot_be_renewed() {
+    let ctx = TestContext::new();
+    let oracle = ctx.generate_address();
+    let expires_at = ctx.env.ledger().sequence() + 10;
+    ctx.client
+        .grant_role_until(&ctx.admin, &oracle, &Role::Oracle, &expires_at);
+
+    ctx.jump_ledgers(10);
+    ctx.client
+        .renew_role(&ctx.admin, &oracle, &Role::Oracle, &None);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #67)")]
+fn test_super_admin_grant_cannot_expire() {
+    let ctx = TestContext::new();
+    let expires_at = ctx.env.ledger().sequence() + 10;
+    ctx.client.grant_role_until(
+        &ctx.admin,
+        &ctx.generate_address(),
+        &Role::SuperAdmin,
+        &expires_at,
+    );
+}
+
+#[test]
+fn test_grant_role_with_caller_auth() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    let args = (&ctx.admin, &admin, Role::Admin).into_val(&ctx.env);
+    mock_auth_of(&ctx, &ctx.admin, "grant_role", args);
+
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+    assert!(ctx.client.has_role(&admin, &Role::Admin));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_grant_role_requires_caller_auth() {
+    let ctx = TestContext::new();
+    let attacker = ctx.generate_address();
+    let args = (&ctx.admin, &attacker, Role::SuperAdmin).into_val(&ctx.env);
+    mock_auth_of(&ctx, &attacker, "grant_role", args);
+
+    ctx.client.grant_role(&ctx.admin, &attacker, &Role::SuperAdmin);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_revoke_role_requires_caller_auth() {
+    let ctx = TestContext::new();
+    let attacker = ctx.generate_address();
+    let args = (&ctx.admin, &ctx.oracle).into_val(&ctx.env);
+    mock_auth_of(&ctx, &attacker, "revoke_role", args);
+
+    ctx.client.revoke_role(&ctx.admin, &ctx.oracle);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+fn test_transfer_super_admin_requires_current_auth() {
+    let ctx = TestContext::new();
+    let attacker = ctx.generate_address();
+    let args = (&ctx.admin, &attacker).into_val(&ctx.env);
+    mock_auth_of(&ctx, &attacker, "transfer_super_admin", args);
+
+    ctx.client.transfer_super_admin(&ctx.admin, &attacker);
+}
@@ -8,6 +8,19 @@
 //! |------------------|-----------|------------------------------------|
 //! | `ProjectCount`   | `u64`     | Auto-increment project ID counter  |
 //! | `OracleKey`      | `Address` | Active trusted oracle address      |
+//! | `OracleQuorum`   | `u32`     | Attestations required to release   |
+//! | `SupportedTokens` | `Vec<Address>` | Tokens in the registry, in insertion order |
+//! | `SupportedToken(token)` | `SupportedToken` | Registry settings of a token |
+//! | `StatusCount(status)` | `u32` | Number of projects in `status`     |
+//! | `PendingUpgrade` | `PendingUpgrade` | Proposed WASM hash and its ETA |
+//! | `SchemaVersion`  | `u32`     | Layout version of stored data (absent = 0) |
+//! | `MigrationCursor` | `u64`    | Next project ID `migrate` will process |
+//! | `ProposalCount`  | `u64`     | Auto-increment governance proposal ID counter |
+//! | `RoundCount`     | `u64`     | Auto-increment matching round ID counter |
+//! | `PledgeCount`    | `u64`     | Auto-increment recurring pledge ID counter |
+//! | `FeeSplits`      | `Vec<FeeSplit>` | Recipients the platform fee is divided between |
+//! | `DisputeWindow`  | `u64`     | Seconds a verified release waits for Auditor disputes |
+//! | `PauseFlags`     | `PauseFlags` | Per-operation pause switches    |
 //!
 //! Instance TTL is bumped by **7 days** whenever it falls below 1 day remaining.
 //!
@@ -18,8 +31,46 @@
 //! | `ProjConfig(id)`   | `ProjectConfig` | Immutable project configuration  |
 //! | `ProjState(id)`    | `ProjectState`  | Mutable project state            |
 //! | `DonatorBalance(id, token, donator)` | `i128` | Per-donator refundable amount |
+//! | `Attestation(id, hash, oracle)` | `()` | Marks that `oracle` attested `hash` |
+//! | `Attesters(id, hash)` | `Vec<Address>` | Distinct oracles that attested `hash` |
+//! | `Rejection(id, oracle)` | `()` | Marks that `oracle` rejected the project's proof |
+//! | `Rejecters(id)` | `Vec<Address>` | Distinct oracles that rejected the project's proof |
+//! | `Commitment(id, commitment)` | `CommittedDeposit` | Anonymous donation awaiting reveal or refund |
+//! | `TokenRate(token)` | `i128` | Goal conversion rate of a token |
+//! | `ProjectCommitmentCount(id)` | `u32` | Number of commitments ever made to a project |
+//! | `ProjectCommitment(id, index)` | `BytesN<32>` | The project's `index`-th commitment |
+//! | `CreatorProjectCount(creator)` | `u32` | Number of projects `creator` currently owns |
+//! | `CreatorProject(creator, index)` | `u64` | ID of `creator`'s `index`-th project |
+//! | `CreatorProjectIndex(id)` | `u32` | Position of a project in its owner's list |
+//! | `Proposal(id)` | `Proposal` | Queued governance action awaiting execution |
+//! | `Round(id)` | `MatchingRound` | Quadratic-funding matching round |
+//! | `ProjectRound(project_id)` | `u64` | Unfinalized round the project takes part in |
+//! | `RoundTally(round_id, project_id)` | `RoundTally` | Quadratic tally of a project in a round |
+//! | `RoundContribution(round_id, project_id, donator)` | `i128` | Donor's matched contribution in a round |
+//! | `RoundMatch(round_id, project_id)` | `i128` | Match a round credited to a project, until returned |
+//! | `MatchedRounds(project_id)` | `Vec<u64>` | Rounds whose match the project still holds |
+//! | `Pledge(id)` | `RecurringPledge` | Live recurring pledge |
+//! | `ProjectPledgeCount(project_id)` | `u32` | Number of pledges ever created for a project |
+//! | `ProjectPledge(project_id, index)` | `u64` | ID of the project's `index`-th pledge |
+//! | `PledgeCursor(project_id)` | `u32` | Index at which the next `collect_pledges` batch starts |
+//! | `ProjectDonorCount(project_id)` | `u32` | Number of distinct donors recorded for a project |
+//! | `ProjectDonor(project_id, index)` | `Address` | The project's `index`-th donor |
+//! | `DonorIndexed(project_id, donator)` | `()` | Marks that `donator` is in the project's donor list |
+//! | `RefundCursor(project_id)` | `u32` | Index at which the next `process_refunds` batch starts |
+//! | `BumpCursor(project_id)` | `BumpProgress` | Unfinished `bump_project` pass over donors, pledges and commitments |
+//! | `ProjectFee(project_id)` | `u32` | Fee override of a project, in basis points |
+//! | `QueuedRelease(project_id)` | `QueuedRelease` | Verified release waiting out the dispute window |
+//! | `Frozen(project_id)` | `()` | Marks a project frozen by an Admin or Auditor |
+//! | `PendingOwner(project_id)` | `Address` | Proposed new owner awaiting acceptance |
+//! | `Beneficiaries(project_id)` | `Vec<Beneficiary>` | Recipients of released funds; absent = creator |
+//! | `MetadataHistory(project_id)` | `Vec<MetadataVersion>` | Most recent metadata URIs; absent = never updated |
+//! | `ReceiptCount(donator)` | `u32` | Number of receipts issued to `donator` |
+//! | `Receipt(donator, index)` | `DonationReceipt` | `donator`'s `index`-th receipt |
+//! | `ReceiptIndex(donator, project_id, token)` | `u32` | Position of the receipt for a project and token |
 //!
 //! Persistent TTL is bumped by **30 days** whenever it falls below 7 days remaining.
+//! Entries that are only read rarely (e.g. the balances of donors who never
+//! come back) can be topped up explicitly with `bump_project`.
 //!
 //! ## Why split Config and State?
 //!
@@ -28,11 +79,14 @@
 //! ledger write costs by ~87% per deposit while keeping the public API clean via
 //! the reconstructed [`Project`] return type.
 
-use soroban_sdk::{contracttype, panic_with_error, Address, Env, Vec};
+use soroban_sdk::{contracttype, panic_with_error, Address, Bytes, BytesN, Env, Vec};
 
 use crate::errors::Error;
 use crate::types::{
-    Project, ProjectBalances, ProjectConfig, ProjectState, ProtocolConfig, TokenBalance,
+    Beneficiary, BumpProgress, CommittedDeposit, DonationLimits, DonationReceipt, FeeSplit,
+    MatchingRound, MetadataVersion, Milestone, PauseFlags, PendingUpgrade, Project,
+    ProjectBalances, ProjectConfig, ProjectState, ProjectStatus, Proposal, ProtocolConfig,
+    QueuedRelease, RecurringPledge, RoundTally, StatusCounts, SupportedToken, TokenBalance,
 };
 
 // ── TTL Constants ────────────────────────────────────────────────────
@@ -45,7 +99,7 @@ const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
 const INSTANCE_LIFETIME_THRESHOLD: u32 = DAY_IN_LEDGERS;
 
 /// Persistent storage: bump by 30 days when below 7 days remaining.
-const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 7 * DAY_IN_LEDGERS;
 
 // ── Storage Keys ─────────────────────────────────────────────────────
@@ -74,6 +128,104 @@ pub enum DataKey {
     ProtocolConfig,
     /// Whitelisted donator for a project (Persistent).
     Whitelist(u64, Address),
+    /// Number of distinct oracle attestations required to release funds (Instance).
+    OracleQuorum,
+    /// Marks that an oracle attested a proof hash, keyed by (project_id, proof_hash, oracle) (Persistent).
+    Attestation(u64, BytesN<32>, Address),
+    /// Distinct oracles that attested a proof hash, keyed by (project_id, proof_hash) (Persistent).
+    Attesters(u64, BytesN<32>),
+    /// Marks that an oracle rejected a project's proof, keyed by (project_id, oracle) (Persistent).
+    Rejection(u64, Address),
+    /// Distinct oracles that rejected a project's proof, keyed by project_id (Persistent).
+    Rejecters(u64),
+    /// Anonymous donation keyed by (project_id, commitment) (Persistent).
+    Commitment(u64, BytesN<32>),
+    /// Number of commitments ever made to a project (Persistent).
+    ProjectCommitmentCount(u64),
+    /// Commitment at position `index` of a project's commitment list, keyed by (project_id, index) (Persistent).
+    ProjectCommitment(u64, u32),
+    /// Admin-set conversion rate used to weigh a token towards funding goals (Persistent).
+    TokenRate(Address),
+    /// Addresses of the tokens in the protocol registry (Instance).
+    SupportedTokens,
+    /// Registry settings of a supported token (Instance).
+    SupportedToken(Address),
+    /// Number of projects currently stored with a given status (Instance).
+    StatusCount(ProjectStatus),
+    /// Number of projects a creator currently owns (Persistent).
+    CreatorProjectCount(Address),
+    /// Project ID at position `index` of a creator's project list, keyed by (creator, index) (Persistent).
+    CreatorProject(Address, u32),
+    /// Position of a project in its owner's project list, keyed by project_id (Persistent).
+    CreatorProjectIndex(u64),
+    /// Contract upgrade waiting out its timelock (Instance).
+    PendingUpgrade,
+    /// Layout version of stored data; absent on pre-versioning deployments (Instance).
+    SchemaVersion,
+    /// Next project ID to be processed by an in-progress migration (Instance).
+    MigrationCursor,
+    /// Global auto-increment counter for governance proposal IDs (Instance).
+    ProposalCount,
+    /// Queued governance action keyed by proposal ID (Persistent).
+    Proposal(u64),
+    /// Global auto-increment counter for matching round IDs (Instance).
+    RoundCount,
+    /// Matching round keyed by round ID (Persistent).
+    Round(u64),
+    /// Unfinalized matching round a project takes part in (Persistent).
+    ProjectRound(u64),
+    /// Quadratic tally keyed by (round_id, project_id) (Persistent).
+    RoundTally(u64, u64),
+    /// Matched contribution keyed by (round_id, project_id, donator) (Persistent).
+    RoundContribution(u64, u64, Address),
+    /// Match credited by a round, keyed by (round_id, project_id) (Persistent).
+    RoundMatch(u64, u64),
+    /// Rounds whose match a project still holds (Persistent).
+    MatchedRounds(u64),
+    /// Global auto-increment counter for recurring pledge IDs (Instance).
+    PledgeCount,
+    /// Live recurring pledge keyed by pledge ID (Persistent).
+    Pledge(u64),
+    /// Number of pledges ever created for a project (Persistent).
+    ProjectPledgeCount(u64),
+    /// Pledge ID at position `index` of a project's pledge list, keyed by (project_id, index) (Persistent).
+    ProjectPledge(u64, u32),
+    /// Position in a project's pledge list where collection resumes (Persistent).
+    PledgeCursor(u64),
+    /// Number of distinct donors recorded for a project (Persistent).
+    ProjectDonorCount(u64),
+    /// Donor at position `index` of a project's donor list, keyed by (project_id, index) (Persistent).
+    ProjectDonor(u64, u32),
+    /// Marks that a donor is already in a project's donor list, keyed by (project_id, donator) (Persistent).
+    DonorIndexed(u64, Address),
+    /// Position in a project's donor list where push refunds resume (Persistent).
+    RefundCursor(u64),
+    /// Unfinished TTL maintenance pass over a project's donors, pledges and commitments (Persistent).
+    BumpCursor(u64),
+    /// Platform fee recipients and their shares (Instance).
+    FeeSplits,
+    /// Per-project fee override in basis points (Persistent).
+    ProjectFee(u64),
+    /// Seconds a verified release waits before it can be finalized (Instance).
+    DisputeWindow,
+    /// Verified release waiting out the dispute window, keyed by project ID (Persistent).
+    QueuedRelease(u64),
+    /// Per-operation pause switches (Instance).
+    PauseFlags,
+    /// Marks a frozen project, keyed by project ID (Persistent).
+    Frozen(u64),
+    /// Proposed new project owner, keyed by project ID (Persistent).
+    PendingOwner(u64),
+    /// Weighted recipients of a project's released funds (Persistent).
+    Beneficiaries(u64),
+    /// Bounded history of a project's metadata URIs (Persistent).
+    MetadataHistory(u64),
+    /// Number of donation receipts issued to a donor (Persistent).
+    ReceiptCount(Address),
+    /// A donor's receipt, keyed by donor and position (Persistent).
+    Receipt(Address, u32),
+    /// Position of a donor's receipt for a project and token (Persistent).
+    ReceiptIndex(Address, u64, Address),
 }
 
 // ── Instance Storage Helpers ─────────────────────────────────────────
@@ -104,6 +256,71 @@ pub fn get_and_increment_project_id(env: &Env) -> u64 {
     current
 }
 
+/// Return the number of projects registered so far (the next project ID).
+pub fn get_project_count(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ProjectCount)
+        .unwrap_or(0)
+}
+
+/// Return the number of projects currently stored with `status`.
+pub fn get_status_count(env: &Env, status: &ProjectStatus) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::StatusCount(status.clone()))
+        .unwrap_or(0)
+}
+
+/// Move one project from `old` to `new` in the per-status counters.
+/// Pass `None` as `old` for a newly registered project.
+fn shift_status_count(env: &Env, old: Option<&ProjectStatus>, new: &ProjectStatus) {
+    bump_instance(env);
+    if let Some(old) = old {
+        if old == new {
+            return;
+        }
+        let count = get_status_count(env, old);
+        env.storage()
+            .instance()
+            .set(&DataKey::StatusCount(old.clone()), &count.saturating_sub(1));
+    }
+    let count = get_status_count(env, new);
+    env.storage()
+        .instance()
+        .set(&DataKey::StatusCount(new.clone()), &(count + 1));
+}
+
+/// Clear every per-status counter so that a migration can rebuild them.
+fn reset_status_counts(env: &Env) {
+    for status in [
+        ProjectStatus::Funding,
+        ProjectStatus::Active,
+        ProjectStatus::Completed,
+        ProjectStatus::Expired,
+        ProjectStatus::Cancelled,
+        ProjectStatus::PendingRelease,
+        ProjectStatus::Failed,
+    ] {
+        env.storage()
+            .instance()
+            .remove(&DataKey::StatusCount(status));
+    }
+}
+
+/// Snapshot of every per-status counter.
+pub fn get_status_counts(env: &Env) -> StatusCounts {
+    StatusCounts {
+        funding: get_status_count(env, &ProjectStatus::Funding),
+        active: get_status_count(env, &ProjectStatus::Active),
+        completed: get_status_count(env, &ProjectStatus::Completed),
+        expired: get_status_count(env, &ProjectStatus::Expired),
+        cancelled: get_status_count(env, &ProjectStatus::Cancelled),
+        pending_release: get_status_count(env, &ProjectStatus::PendingRelease),
+        failed: get_status_count(env, &ProjectStatus::Failed),
+    }
+}
+
 /// Return true if the protocol is currently paused.
 pub fn is_paused(env: &Env) -> bool {
     env.storage()
@@ -118,6 +335,36 @@ pub fn set_paused(env: &Env, paused: bool) {
     env.storage().instance().set(&DataKey::IsPaused, &paused);
 }
 
+/// Retrieve the per-operation pause flags. Defaults to nothing paused.
+pub fn get_pause_flags(env: &Env) -> PauseFlags {
+    env.storage()
+        .instance()
+        .get(&DataKey::PauseFlags)
+        .unwrap_or_default()
+}
+
+/// Save the per-operation pause flags.
+pub fn set_pause_flags(env: &Env, flags: &PauseFlags) {
+    bump_instance(env);
+    env.storage().instance().set(&DataKey::PauseFlags, flags);
+}
+
+/// Return true if `project_id` is frozen.
+pub fn is_frozen(env: &Env, project_id: u64) -> bool {
+    env.storage().persistent().has(&DataKey::Frozen(project_id))
+}
+
+/// Freeze (`true`) or unfreeze (`false`) `project_id`.
+pub fn set_frozen(env: &Env, project_id: u64, frozen: bool) {
+    let key = DataKey::Frozen(project_id);
+    if frozen {
+        env.storage().persistent().set(&key, &());
+        bump_persistent(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
 /// Retrieve the global protocol configuration.
 pub fn get_protocol_config(env: &Env) -> Option<ProtocolConfig> {
     env.storage().instance().get(&DataKey::ProtocolConfig)
@@ -129,6 +376,191 @@ pub fn set_protocol_config(env: &Env, config: &ProtocolConfig) {
     env.storage().instance().set(&DataKey::ProtocolConfig, config);
 }
 
+/// Retrieve the platform fee splits; empty when fees go to `fee_recipient`.
+pub fn get_fee_splits(env: &Env) -> Vec<FeeSplit> {
+    env.storage()
+        .instance()
+        .get(&DataKey::FeeSplits)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Save the platform fee splits.
+pub fn set_fee_splits(env: &Env, splits: &Vec<FeeSplit>) {
+    bump_instance(env);
+    env.storage().instance().set(&DataKey::FeeSplits, splits);
+}
+
+/// Retrieve a project's fee override, if any.
+pub fn get_project_fee(env: &Env, project_id: u64) -> Option<u32> {
+    let key = DataKey::ProjectFee(project_id);
+    let opt: Option<u32> = env.storage().persistent().get(&key);
+    if opt.is_some() {
+        bump_persistent(env, &key);
+    }
+    opt
+}
+
+/// Set a project's fee override, or clear it with `None`.
+pub fn set_project_fee(env: &Env, project_id: u64, fee_bps: Option<u32>) {
+    let key = DataKey::ProjectFee(project_id);
+    match fee_bps {
+        Some(bps) => {
+            env.storage().persistent().set(&key, &bps);
+            bump_persistent(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Retrieve the dispute window in seconds. Defaults to 0 (immediate release).
+pub fn get_dispute_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DisputeWindow)
+        .unwrap_or(0)
+}
+
+/// Save the dispute window in seconds.
+pub fn set_dispute_window(env: &Env, window: u64) {
+    bump_instance(env);
+    env.storage().instance().set(&DataKey::DisputeWindow, &window);
+}
+
+/// Retrieve the release queued for `project_id`, if any.
+pub fn get_queued_release(env: &Env, project_id: u64) -> Option<QueuedRelease> {
+    let key = DataKey::QueuedRelease(project_id);
+    let opt: Option<QueuedRelease> = env.storage().persistent().get(&key);
+    if opt.is_some() {
+        bump_persistent(env, &key);
+    }
+    opt
+}
+
+/// Queue a release for `project_id`.
+pub fn set_queued_release(env: &Env, project_id: u64, release: &QueuedRelease) {
+    let key = DataKey::QueuedRelease(project_id);
+    env.storage().persistent().set(&key, release);
+    bump_persistent(env, &key);
+}
+
+/// Delete the release queued for `project_id`.
+pub fn remove_queued_release(env: &Env, project_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::QueuedRelease(project_id));
+}
+
+/// Retrieve the oracle quorum. Defaults to 1 (single-oracle release).
+pub fn get_oracle_quorum(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::OracleQuorum)
+        .unwrap_or(1)
+}
+
+/// Save the oracle quorum.
+pub fn set_oracle_quorum(env: &Env, quorum: u32) {
+    bump_instance(env);
+    env.storage().instance().set(&DataKey::OracleQuorum, &quorum);
+}
+
+/// Retrieve the goal conversion rate of `token`, if one has been set.
+pub fn get_token_rate(env: &Env, token: &Address) -> Option<i128> {
+    let key = DataKey::TokenRate(token.clone());
+    let rate = env.storage().persistent().get(&key);
+    if rate.is_some() {
+        bump_persistent(env, &key);
+    }
+    rate
+}
+
+/// Save the goal conversion rate of `token`.
+pub fn set_token_rate(env: &Env, token: &Address, rate: i128) {
+    let key = DataKey::TokenRate(token.clone());
+    env.storage().persistent().set(&key, &rate);
+    bump_persistent(env, &key);
+}
+
+/// Retrieve the registry settings of `token`, or `None` if it is not supported.
+pub fn get_supported_token(env: &Env, token: &Address) -> Option<SupportedToken> {
+    env.storage()
+        .instance()
+        .get(&DataKey::SupportedToken(token.clone()))
+}
+
+/// Return the addresses of all supported tokens, in insertion order.
+pub fn get_supported_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::SupportedTokens)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Add a token to the registry, or update its settings if already listed.
+pub fn set_supported_token(env: &Env, info: &SupportedToken) {
+    bump_instance(env);
+    let mut tokens = get_supported_tokens(env);
+    if !tokens.contains(&info.token) {
+        tokens.push_back(info.token.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::SupportedTokens, &tokens);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::SupportedToken(info.token.clone()), info);
+}
+
+/// Remove a token from the registry.
+pub fn remove_supported_token(env: &Env, token: &Address) {
+    bump_instance(env);
+    let mut tokens = get_supported_tokens(env);
+    if let Some(index) = tokens.first_index_of(token) {
+        tokens.remove(index);
+        env.storage()
+            .instance()
+            .set(&DataKey::SupportedTokens, &tokens);
+    }
+    env.storage()
+        .instance()
+        .remove(&DataKey::SupportedToken(token.clone()));
+}
+
+/// Retrieve the upgrade currently waiting out its timelock, if any.
+pub fn get_pending_upgrade(env: &Env) -> Option<PendingUpgrade> {
+    env.storage().instance().get(&DataKey::PendingUpgrade)
+}
+
+/// Store a proposed upgrade.
+pub fn set_pending_upgrade(env: &Env, upgrade: &PendingUpgrade) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::PendingUpgrade, upgrade);
+}
+
+/// Clear the pending upgrade after it was executed or cancelled.
+pub fn remove_pending_upgrade(env: &Env) {
+    env.storage().instance().remove(&DataKey::PendingUpgrade);
+}
+
+/// Return the layout version of stored data. Deployments that predate
+/// schema versioning have no key and report version 0.
+pub fn get_schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(0)
+}
+
+/// Record the layout version of stored data.
+pub fn set_schema_version(env: &Env, version: u32) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::SchemaVersion, &version);
+}
+
 // ── Persistent Storage Helpers ───────────────────────────────────────
 
 /// Extend the TTL for a persistent storage key.
@@ -154,18 +586,25 @@ pub fn save_project(env: &Env, project: &Project) {
         deadline: project.deadline,
         is_private: project.is_private,
         metadata_uri: project.metadata_uri.clone(),
+        milestones: project.milestones.clone(),
+        limits: project.limits.clone(),
+        merkle_root: project.merkle_root.clone(),
     };
 
     let state = ProjectState {
         status: project.status.clone(),
         donation_count: project.donation_count,
         refund_expiry: project.refund_expiry,
+        released_milestones: project.released_milestones,
     };
 
     env.storage().persistent().set(&config_key, &config);
     env.storage().persistent().set(&state_key, &state);
     bump_persistent(env, &config_key);
     bump_persistent(env, &state_key);
+
+    shift_status_count(env, None, &state.status);
+    append_creator_project(env, &project.creator, project.id);
 }
 
 /// Save only the immutable project configuration.
@@ -202,10 +641,17 @@ pub fn load_project_state(env: &Env, id: u64) -> ProjectState {
 }
 
 /// Save only the mutable project state (optimized for deposits/verification).
+///
+/// Keeps the per-status counters in sync when the status changes.
 pub fn save_project_state(env: &Env, id: u64, state: &ProjectState) {
     let key = DataKey::ProjState(id);
+    let previous: Option<ProjectState> = env.storage().persistent().get(&key);
     env.storage().persistent().set(&key, state);
     bump_persistent(env, &key);
+
+    if let Some(previous) = previous {
+        shift_status_count(env, Some(&previous.status), &state.status);
+    }
 }
 
 // ── New retrieval helpers ─────────────────────────────────────────
@@ -295,6 +741,10 @@ pub fn load_project(env: &Env, id: u64) -> Project {
         donation_count: state.donation_count,
         is_private: config.is_private,
         refund_expiry: state.refund_expiry,
+        milestones: config.milestones,
+        released_milestones: state.released_milestones,
+        limits: config.limits,
+        merkle_root: config.merkle_root,
     }
 }
 
@@ -305,10 +755,6 @@ pub fn load_project(env: &Env, id: u64) -> Project {
 /// TTL of both underlying entries when present.
 #[allow(dead_code)]
 pub fn maybe_load_project(env: &Env, id: u64) -> Option<Project> {
-    let config = match maybe_load_project_config(env, id) {
-        Some(c) => c,
-        None => return None,
-    };
     let config = maybe_load_project_config(env, id)?;
 
     // If config exists, state must exist. This maintains the invariant while avoiding
@@ -331,6 +777,10 @@ pub fn maybe_load_project(env: &Env, id: u64) -> Option<Project> {
         donation_count: state.donation_count,
         is_private: config.is_private,
         refund_expiry: state.refund_expiry,
+        milestones: config.milestones,
+        released_milestones: state.released_milestones,
+        limits: config.limits,
+        merkle_root: config.merkle_root,
     })
 }
 
@@ -460,3 +910,1003 @@ pub fn remove_from_whitelist(env: &Env, project_id: u64, address: &Address) {
     let key = DataKey::Whitelist(project_id, address.clone());
     env.storage().persistent().remove(&key);
 }
+
+// ── Oracle Attestation Helpers ───────────────────────────────────────
+
+/// Return true if `oracle` has already attested `proof_hash` for `project_id`.
+pub fn has_attested(env: &Env, project_id: u64, proof_hash: &BytesN<32>, oracle: &Address) -> bool {
+    let key = DataKey::Attestation(project_id, proof_hash.clone(), oracle.clone());
+    env.storage().persistent().has(&key)
+}
+
+/// Distinct oracles that attested `proof_hash` for `project_id`, in order.
+pub fn get_attesters(env: &Env, project_id: u64, proof_hash: &BytesN<32>) -> Vec<Address> {
+    let key = DataKey::Attesters(project_id, proof_hash.clone());
+    match env.storage().persistent().get::<DataKey, Vec<Address>>(&key) {
+        Some(attesters) => {
+            bump_persistent(env, &key);
+            attesters
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Number of distinct oracles that attested `proof_hash` for `project_id`.
+pub fn get_attestation_count(env: &Env, project_id: u64, proof_hash: &BytesN<32>) -> u32 {
+    get_attesters(env, project_id, proof_hash).len()
+}
+
+/// Clear the tally of `proof_hash` and every attester's marker after a
+/// dispute, so the proof can be verified again from scratch.
+pub fn clear_attestations(env: &Env, project_id: u64, proof_hash: &BytesN<32>) {
+    for oracle in get_attesters(env, project_id, proof_hash).iter() {
+        let key = DataKey::Attestation(project_id, proof_hash.clone(), oracle);
+        env.storage().persistent().remove(&key);
+    }
+    let key = DataKey::Attesters(project_id, proof_hash.clone());
+    env.storage().persistent().remove(&key);
+}
+
+/// Mark `oracle` as having attested `proof_hash` and return the new tally.
+///
+/// Callers must check [`has_attested`] first; this helper does not dedupe.
+pub fn record_attestation(
+    env: &Env,
+    project_id: u64,
+    proof_hash: &BytesN<32>,
+    oracle: &Address,
+) -> u32 {
+    let key = DataKey::Attestation(project_id, proof_hash.clone(), oracle.clone());
+    env.storage().persistent().set(&key, &());
+    bump_persistent(env, &key);
+
+    let mut attesters = get_attesters(env, project_id, proof_hash);
+    attesters.push_back(oracle.clone());
+    let attesters_key = DataKey::Attesters(project_id, proof_hash.clone());
+    env.storage().persistent().set(&attesters_key, &attesters);
+    bump_persistent(env, &attesters_key);
+    attesters.len()
+}
+
+/// Returns `true` if `oracle` already rejected the proof of `project_id`.
+pub fn has_rejected(env: &Env, project_id: u64, oracle: &Address) -> bool {
+    let key = DataKey::Rejection(project_id, oracle.clone());
+    env.storage().persistent().has(&key)
+}
+
+/// Distinct oracles that rejected the proof of `project_id`, in order.
+pub fn get_rejecters(env: &Env, project_id: u64) -> Vec<Address> {
+    let key = DataKey::Rejecters(project_id);
+    match env.storage().persistent().get::<DataKey, Vec<Address>>(&key) {
+        Some(rejecters) => {
+            bump_persistent(env, &key);
+            rejecters
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Mark `oracle` as having rejected the proof of `project_id`.
+///
+/// Callers must check [`has_rejected`] first; this helper does not dedupe.
+pub fn record_rejection(env: &Env, project_id: u64, oracle: &Address) {
+    let key = DataKey::Rejection(project_id, oracle.clone());
+    env.storage().persistent().set(&key, &());
+    bump_persistent(env, &key);
+
+    let mut rejecters = get_rejecters(env, project_id);
+    rejecters.push_back(oracle.clone());
+    let rejecters_key = DataKey::Rejecters(project_id);
+    env.storage().persistent().set(&rejecters_key, &rejecters);
+    bump_persistent(env, &rejecters_key);
+}
+
+// ── Committed Donation Helpers ───────────────────────────────────────
+
+/// Retrieve the committed donation stored under `commitment`, if any.
+pub fn get_commitment(
+    env: &Env,
+    project_id: u64,
+    commitment: &BytesN<32>,
+) -> Option<CommittedDeposit> {
+    let key = DataKey::Commitment(project_id, commitment.clone());
+    let opt: Option<CommittedDeposit> = env.storage().persistent().get(&key);
+    if opt.is_some() {
+        bump_persistent(env, &key);
+    }
+    opt
+}
+
+/// Store a committed donation.
+pub fn set_commitment(
+    env: &Env,
+    project_id: u64,
+    commitment: &BytesN<32>,
+    deposit: &CommittedDeposit,
+) {
+    let key = DataKey::Commitment(project_id, commitment.clone());
+    env.storage().persistent().set(&key, deposit);
+    bump_persistent(env, &key);
+}
+
+/// Delete a committed donation once it has been revealed or refunded.
+pub fn remove_commitment(env: &Env, project_id: u64, commitment: &BytesN<32>) {
+    let key = DataKey::Commitment(project_id, commitment.clone());
+    env.storage().persistent().remove(&key);
+}
+
+/// Return the number of commitments ever made to `project_id`.
+pub fn get_project_commitment_count(env: &Env, project_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ProjectCommitmentCount(project_id))
+        .unwrap_or(0)
+}
+
+/// Append `commitment` to its project's commitment list.
+pub fn append_project_commitment(env: &Env, project_id: u64, commitment: &BytesN<32>) {
+    let index = get_project_commitment_count(env, project_id);
+    let key = DataKey::ProjectCommitment(project_id, index);
+    env.storage().persistent().set(&key, commitment);
+    bump_persistent(env, &key);
+
+    let count_key = DataKey::ProjectCommitmentCount(project_id);
+    env.storage().persistent().set(&count_key, &(index + 1));
+    bump_persistent(env, &count_key);
+}
+
+// ── Creator Index Helpers ────────────────────────────────────────────
+
+/// Return how many projects `creator` currently owns.
+pub fn get_creator_project_count(env: &Env, creator: &Address) -> u32 {
+    let key = DataKey::CreatorProjectCount(creator.clone());
+    let opt: Option<u32> = env.storage().persistent().get(&key);
+    match opt {
+        Some(count) => {
+            bump_persistent(env, &key);
+            count
+        }
+        None => 0,
+    }
+}
+
+/// Return the ID of `creator`'s `index`-th project, if it exists.
+pub fn get_creator_project(env: &Env, creator: &Address, index: u32) -> Option<u64> {
+    let key = DataKey::CreatorProject(creator.clone(), index);
+    let opt: Option<u64> = env.storage().persistent().get(&key);
+    if opt.is_some() {
+        bump_persistent(env, &key);
+    }
+    opt
+}
+
+/// Append `project_id` to `creator`'s project list.
+pub fn append_creator_project(env: &Env, creator: &Address, project_id: u64) {
+    let index = get_creator_project_count(env, creator);
+    let entry_key = DataKey::CreatorProject(creator.clone(), index);
+    env.storage().persistent().set(&entry_key, &project_id);
+    bump_persistent(env, &entry_key);
+
+    let count_key = DataKey::CreatorProjectCount(creator.clone());
+    env.storage().persistent().set(&count_key, &(index + 1));
+    bump_persistent(env, &count_key);
+
+    let index_key = DataKey::CreatorProjectIndex(project_id);
+    env.storage().persistent().set(&index_key, &index);
+    bump_persistent(env, &index_key);
+}
+
+/// Remove `project_id` from `creator`'s project list.
+///
+/// The creator's last project moves into the freed slot, so the list stays
+/// dense. Does nothing if the project is not in the list.
+pub fn remove_creator_project(env: &Env, creator: &Address, project_id: u64) {
+    let index_key = DataKey::CreatorProjectIndex(project_id);
+    let index: u32 = match env.storage().persistent().get(&index_key) {
+        Some(i) => i,
+        None => return,
+    };
+    if get_creator_project(env, creator, index) != Some(project_id) {
+        return;
+    }
+
+    let last = get_creator_project_count(env, creator) - 1;
+    if index != last {
+        if let Some(moved) = get_creator_project(env, creator, last) {
+            let entry_key = DataKey::CreatorProject(creator.clone(), index);
+            env.storage().persistent().set(&entry_key, &moved);
+            bump_persistent(env, &entry_key);
+            let moved_key = DataKey::CreatorProjectIndex(moved);
+            env.storage().persistent().set(&moved_key, &index);
+            bump_persistent(env, &moved_key);
+        }
+    }
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CreatorProject(creator.clone(), last));
+    env.storage().persistent().remove(&index_key);
+
+    let count_key = DataKey::CreatorProjectCount(creator.clone());
+    env.storage().persistent().set(&count_key, &last);
+    bump_persistent(env, &count_key);
+}
+
+// ── Ownership Helpers ────────────────────────────────────────────────
+
+/// Retrieve the proposed new owner of `project_id`, if any.
+pub fn get_pending_owner(env: &Env, project_id: u64) -> Option<Address> {
+    let key = DataKey::PendingOwner(project_id);
+    let opt: Option<Address> = env.storage().persistent().get(&key);
+    if opt.is_some() {
+        bump_persistent(env, &key);
+    }
+    opt
+}
+
+/// Record `owner` as the proposed new owner of `project_id`.
+pub fn set_pending_owner(env: &Env, project_id: u64, owner: &Address) {
+    let key = DataKey::PendingOwner(project_id);
+    env.storage().persistent().set(&key, owner);
+    bump_persistent(env, &key);
+}
+
+/// Delete the pending ownership transfer of `project_id`.
+pub fn remove_pending_owner(env: &Env, project_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PendingOwner(project_id));
+}
+
+/// Retrieve the beneficiaries of `project_id`; empty when funds go to the creator.
+pub fn get_beneficiaries(env: &Env, project_id: u64) -> Vec<Beneficiary> {
+    let key = DataKey::Beneficiaries(project_id);
+    match env.storage().persistent().get(&key) {
+        Some(beneficiaries) => {
+            bump_persistent(env, &key);
+            beneficiaries
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Replace the beneficiaries of `project_id`; an empty list removes them.
+pub fn set_beneficiaries(env: &Env, project_id: u64, beneficiaries: &Vec<Beneficiary>) {
+    let key = DataKey::Beneficiaries(project_id);
+    if beneficiaries.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, beneficiaries);
+        bump_persistent(env, &key);
+    }
+}
+
+// ── Metadata History Helpers ─────────────────────────────────────────
+
+/// Retrieve the stored metadata history of `project_id`; empty if the
+/// metadata was never updated.
+pub fn get_metadata_history(env: &Env, project_id: u64) -> Vec<MetadataVersion> {
+    let key = DataKey::MetadataHistory(project_id);
+    match env.storage().persistent().get(&key) {
+        Some(history) => {
+            bump_persistent(env, &key);
+            history
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Replace the metadata history of `project_id`.
+pub fn set_metadata_history(env: &Env, project_id: u64, history: &Vec<MetadataVersion>) {
+    let key = DataKey::MetadataHistory(project_id);
+    env.storage().persistent().set(&key, history);
+    bump_persistent(env, &key);
+}
+
+// ── Donation Receipt Helpers ─────────────────────────────────────────
+
+/// Return the number of receipts issued to `donator`.
+pub fn get_receipt_count(env: &Env, donator: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReceiptCount(donator.clone()))
+        .unwrap_or(0)
+}
+
+/// Retrieve `donator`'s `index`-th receipt.
+pub fn get_receipt(env: &Env, donator: &Address, index: u32) -> Option<DonationReceipt> {
+    let key = DataKey::Receipt(donator.clone(), index);
+    let opt: Option<DonationReceipt> = env.storage().persistent().get(&key);
+    if opt.is_some() {
+        bump_persistent(env, &key);
+    }
+    opt
+}
+
+/// Add `amount` to `donator`'s receipt for `project_id` and `token`,
+/// issuing a new receipt on their first donation there.
+pub fn record_receipt(
+    env: &Env,
+    donator: &Address,
+    project_id: u64,
+    token: &Address,
+    amount: i128,
+) {
+    let ledger = env.ledger().sequence();
+    let index_key = DataKey::ReceiptIndex(donator.clone(), project_id, token.clone());
+
+    let (index, receipt) = match env.storage().persistent().get::<_, u32>(&index_key) {
+        Some(index) => {
+            let mut receipt = get_receipt(env, donator, index).expect("receipt missing");
+            receipt.amount = receipt
+                .amount
+                .checked_add(amount)
+                .expect("receipt amount overflow");
+            receipt.last_ledger = ledger;
+            (index, receipt)
+        }
+        None => {
+            let index = get_receipt_count(env, donator);
+            let count_key = DataKey::ReceiptCount(donator.clone());
+            env.storage().persistent().set(&count_key, &(index + 1));
+            bump_persistent(env, &count_key);
+            env.storage().persistent().set(&index_key, &index);
+            let receipt = DonationReceipt {
+                project_id,
+                token: token.clone(),
+                amount,
+                refunded: 0,
+                first_ledger: ledger,
+                last_ledger: ledger,
+            };
+            (index, receipt)
+        }
+    };
+    bump_persistent(env, &index_key);
+
+    let key = DataKey::Receipt(donator.clone(), index);
+    env.storage().persistent().set(&key, &receipt);
+    bump_persistent(env, &key);
+}
+
+/// Add `amount` to the refunded total of `donator`'s receipt for
+/// `project_id` and `token`. A no-op if no receipt was issued, e.g. for an
+/// unrevealed committed donation.
+pub fn record_receipt_refund(
+    env: &Env,
+    donator: &Address,
+    project_id: u64,
+    token: &Address,
+    amount: i128,
+) {
+    if amount <= 0 {
+        return;
+    }
+    let index_key = DataKey::ReceiptIndex(donator.clone(), project_id, token.clone());
+    let index = match env.storage().persistent().get::<_, u32>(&index_key) {
+        Some(index) => index,
+        None => return,
+    };
+    bump_persistent(env, &index_key);
+
+    let mut receipt = get_receipt(env, donator, index).expect("receipt missing");
+    receipt.refunded = receipt
+        .refunded
+        .checked_add(amount)
+        .expect("receipt refund overflow");
+    let key = DataKey::Receipt(donator.clone(), index);
+    env.storage().persistent().set(&key, &receipt);
+    bump_persistent(env, &key);
+}
+
+// ── Governance Proposal Helpers ──────────────────────────────────────
+
+/// Atomically read and increment the proposal counter.
+pub fn get_and_increment_proposal_id(env: &Env) -> u64 {
+    bump_instance(env);
+    let current: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::ProposalCount)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::ProposalCount, &(current + 1));
+    current
+}
+
+/// Retrieve a queued proposal, if it exists.
+pub fn get_proposal(env: &Env, id: u64) -> Option<Proposal> {
+    let key = DataKey::Proposal(id);
+    let opt: Option<Proposal> = env.storage().persistent().get(&key);
+    if opt.is_some() {
+        bump_persistent(env, &key);
+    }
+    opt
+}
+
+/// Store a queued proposal.
+pub fn set_proposal(env: &Env, proposal: &Proposal) {
+    let key = DataKey::Proposal(proposal.id);
+    env.storage().persistent().set(&key, proposal);
+    bump_persistent(env, &key);
+}
+
+/// Delete a proposal once it has been executed or cancelled.
+pub fn remove_proposal(env: &Env, id: u64) {
+    env.storage().persistent().remove(&DataKey::Proposal(id));
+}
+
+// ── Matching Round Helpers ───────────────────────────────────────────
+
+/// Atomically read and increment the matching round counter.
+pub fn get_and_increment_round_id(env: &Env) -> u64 {
+    bump_instance(env);
+    let current: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::RoundCount)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::RoundCount, &(current + 1));
+    current
+}
+
+/// Retrieve a matching round, if it exists.
+pub fn get_round(env: &Env, round_id: u64) -> Option<MatchingRound> {
+    let key = DataKey::Round(round_id);
+    let opt: Option<MatchingRound> = env.storage().persistent().get(&key);
+    if opt.is_some() {
+        bump_persistent(env, &key);
+    }
+    opt
+}
+
+/// Store a matching round.
+pub fn set_round(env: &Env, round: &MatchingRound) {
+    let key = DataKey::Round(round.id);
+    env.storage().persistent().set(&key, round);
+    bump_persistent(env, &key);
+}
+
+/// Return the unfinalized round `project_id` takes part in, if any.
+pub fn get_project_round(env: &Env, project_id: u64) -> Option<u64> {
+    let key = DataKey::ProjectRound(project_id);
+    let opt: Option<u64> = env.storage().persistent().get(&key);
+    if opt.is_some() {
+        bump_persistent(env, &key);
+    }
+    opt
+}
+
+/// Link `project_id` to a round, or unlink it with `None`.
+pub fn set_project_round(env: &Env, project_id: u64, round_id: Option<u64>) {
+    let key = DataKey::ProjectRound(project_id);
+    match round_id {
+        Some(id) => {
+            env.storage().persistent().set(&key, &id);
+            bump_persistent(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Retrieve the quadratic tally of a project in a round.
+pub fn get_round_tally(env: &Env, round_id: u64, project_id: u64) -> RoundTally {
+    let key = DataKey::RoundTally(round_id, project_id);
+    match env.storage().persistent().get::<DataKey, RoundTally>(&key) {
+        Some(tally) => {
+            bump_persistent(env, &key);
+            tally
+        }
+        None => RoundTally::default(),
+    }
+}
+
+/// Store the quadratic tally of a project in a round.
+pub fn set_round_tally(env: &Env, round_id: u64, project_id: u64, tally: &RoundTally) {
+    let key = DataKey::RoundTally(round_id, project_id);
+    env.storage().persistent().set(&key, tally);
+    bump_persistent(env, &key);
+}
+
+/// Retrieve a donor's matched contribution to a project in a round.
+pub fn get_round_contribution(
+    env: &Env,
+    round_id: u64,
+    project_id: u64,
+    donator: &Address,
+) -> i128 {
+    let key = DataKey::RoundContribution(round_id, project_id, donator.clone());
+    match env.storage().persistent().get::<DataKey, i128>(&key) {
+        Some(amount) => {
+            bump_persistent(env, &key);
+            amount
+        }
+        None => 0,
+    }
+}
+
+/// Store a donor's matched contribution to a project in a round.
+pub fn set_round_contribution(
+    env: &Env,
+    round_id: u64,
+    project_id: u64,
+    donator: &Address,
+    amount: i128,
+) {
+    let key = DataKey::RoundContribution(round_id, project_id, donator.clone());
+    env.storage().persistent().set(&key, &amount);
+    bump_persistent(env, &key);
+}
+
+/// Rounds whose match `project_id` still holds, oldest first.
+pub fn get_matched_rounds(env: &Env, project_id: u64) -> Vec<u64> {
+    let key = DataKey::MatchedRounds(project_id);
+    match env.storage().persistent().get::<DataKey, Vec<u64>>(&key) {
+        Some(rounds) => {
+            bump_persistent(env, &key);
+            rounds
+        }
+        None => Vec::new(env),
+    }
+}
+
+/// Retrieve the match `round_id` credited to `project_id`, or 0.
+pub fn get_round_match(env: &Env, round_id: u64, project_id: u64) -> i128 {
+    let key = DataKey::RoundMatch(round_id, project_id);
+    match env.storage().persistent().get::<DataKey, i128>(&key) {
+        Some(amount) => {
+            bump_persistent(env, &key);
+            amount
+        }
+        None => 0,
+    }
+}
+
+/// Record that `round_id` credited `amount` to `project_id`.
+pub fn record_round_match(env: &Env, round_id: u64, project_id: u64, amount: i128) {
+    let key = DataKey::RoundMatch(round_id, project_id);
+    env.storage().persistent().set(&key, &amount);
+    bump_persistent(env, &key);
+
+    let mut rounds = get_matched_rounds(env, project_id);
+    rounds.push_back(round_id);
+    let rounds_key = DataKey::MatchedRounds(project_id);
+    env.storage().persistent().set(&rounds_key, &rounds);
+    bump_persistent(env, &rounds_key);
+}
+
+/// Forget every match recorded for `project_id` once it has been returned.
+pub fn clear_round_matches(env: &Env, project_id: u64) {
+    for round_id in get_matched_rounds(env, project_id).iter() {
+        let key = DataKey::RoundMatch(round_id, project_id);
+        env.storage().persistent().remove(&key);
+    }
+    env.storage()
+        .persistent()
+        .remove(&DataKey::MatchedRounds(project_id));
+}
+
+// ── Recurring Pledge Helpers ─────────────────────────────────────────
+
+/// Atomically read and increment the pledge counter.
+pub fn get_and_increment_pledge_id(env: &Env) -> u64 {
+    bump_instance(env);
+    let current: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::PledgeCount)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::PledgeCount, &(current + 1));
+    current
+}
+
+/// Retrieve a live pledge, if it exists.
+pub fn get_pledge(env: &Env, pledge_id: u64) -> Option<RecurringPledge> {
+    let key = DataKey::Pledge(pledge_id);
+    let opt: Option<RecurringPledge> = env.storage().persistent().get(&key);
+    if opt.is_some() {
+        bump_persistent(env, &key);
+    }
+    opt
+}
+
+/// Store a pledge.
+pub fn set_pledge(env: &Env, pledge: &RecurringPledge) {
+    let key = DataKey::Pledge(pledge.id);
+    env.storage().persistent().set(&key, pledge);
+    bump_persistent(env, &key);
+}
+
+/// Delete a pledge once it has completed, lapsed or been cancelled.
+///
+/// Its slot in the project's pledge list is left in place and skipped by
+/// `collect_pledges`.
+pub fn remove_pledge(env: &Env, pledge_id: u64) {
+    env.storage().persistent().remove(&DataKey::Pledge(pledge_id));
+}
+
+/// Return the number of pledges ever created for `project_id`.
+pub fn get_project_pledge_count(env: &Env, project_id: u64) -> u32 {
+    let key = DataKey::ProjectPledgeCount(project_id);
+    match env.storage().persistent().get::<DataKey, u32>(&key) {
+        Some(count) => {
+            bump_persistent(env, &key);
+            count
+        }
+        None => 0,
+    }
+}
+
+/// Return the pledge ID at position `index` of a project's pledge list.
+pub fn get_project_pledge(env: &Env, project_id: u64, index: u32) -> Option<u64> {
+    let key = DataKey::ProjectPledge(project_id, index);
+    let opt: Option<u64> = env.storage().persistent().get(&key);
+    if opt.is_some() {
+        bump_persistent(env, &key);
+    }
+    opt
+}
+
+/// Append `pledge_id` to its project's pledge list.
+pub fn append_project_pledge(env: &Env, project_id: u64, pledge_id: u64) {
+    let index = get_project_pledge_count(env, project_id);
+    let key = DataKey::ProjectPledge(project_id, index);
+    env.storage().persistent().set(&key, &pledge_id);
+    bump_persistent(env, &key);
+
+    let count_key = DataKey::ProjectPledgeCount(project_id);
+    env.storage().persistent().set(&count_key, &(index + 1));
+    bump_persistent(env, &count_key);
+}
+
+/// Return the position where the next `collect_pledges` batch starts.
+pub fn get_pledge_cursor(env: &Env, project_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PledgeCursor(project_id))
+        .unwrap_or(0)
+}
+
+/// Save the position where the next `collect_pledges` batch starts.
+pub fn set_pledge_cursor(env: &Env, project_id: u64, cursor: u32) {
+    let key = DataKey::PledgeCursor(project_id);
+    env.storage().persistent().set(&key, &cursor);
+    bump_persistent(env, &key);
+}
+
+// ── Donor Index Helpers ──────────────────────────────────────────────
+
+/// Return the number of distinct donors recorded for `project_id`.
+pub fn get_project_donor_count(env: &Env, project_id: u64) -> u32 {
+    let key = DataKey::ProjectDonorCount(project_id);
+    match env.storage().persistent().get::<DataKey, u32>(&key) {
+        Some(count) => {
+            bump_persistent(env, &key);
+            count
+        }
+        None => 0,
+    }
+}
+
+/// Return the donor at position `index` of a project's donor list.
+pub fn get_project_donor(env: &Env, project_id: u64, index: u32) -> Option<Address> {
+    let key = DataKey::ProjectDonor(project_id, index);
+    let opt: Option<Address> = env.storage().persistent().get(&key);
+    if opt.is_some() {
+        bump_persistent(env, &key);
+    }
+    opt
+}
+
+/// Append `donator` to the project's donor list unless already present.
+pub fn index_project_donor(env: &Env, project_id: u64, donator: &Address) {
+    let marker_key = DataKey::DonorIndexed(project_id, donator.clone());
+    if env.storage().persistent().has(&marker_key) {
+        bump_persistent(env, &marker_key);
+        return;
+    }
+    env.storage().persistent().set(&marker_key, &());
+    bump_persistent(env, &marker_key);
+
+    let index = get_project_donor_count(env, project_id);
+    let entry_key = DataKey::ProjectDonor(project_id, index);
+    env.storage().persistent().set(&entry_key, donator);
+    bump_persistent(env, &entry_key);
+
+    let count_key = DataKey::ProjectDonorCount(project_id);
+    env.storage().persistent().set(&count_key, &(index + 1));
+    bump_persistent(env, &count_key);
+}
+
+/// Return the position where the next `process_refunds` batch starts.
+pub fn get_refund_cursor(env: &Env, project_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RefundCursor(project_id))
+        .unwrap_or(0)
+}
+
+/// Save the position where the next `process_refunds` batch starts.
+pub fn set_refund_cursor(env: &Env, project_id: u64, cursor: u32) {
+    let key = DataKey::RefundCursor(project_id);
+    env.storage().persistent().set(&key, &cursor);
+    bump_persistent(env, &key);
+}
+
+// ── TTL Maintenance Helpers ──────────────────────────────────────────
+
+/// Extend `key` to the full persistent TTL if the entry exists.
+///
+/// Unlike [`bump_persistent`], which only acts once the TTL drops below the
+/// threshold, this always tops the entry up to [`PERSISTENT_BUMP_AMOUNT`].
+fn extend_full(env: &Env, key: &DataKey) {
+    let storage = env.storage().persistent();
+    if storage.has(key) {
+        storage.extend_ttl(key, PERSISTENT_BUMP_AMOUNT, PERSISTENT_BUMP_AMOUNT);
+    }
+}
+
+/// Extend a project's own entries to the full TTL: its config, state, token
+/// balances and the goal rates of its tokens, list counts, per-project
+/// settings, the tally of the round it takes part in and the matches of
+/// earlier rounds it still holds.
+pub fn extend_project_entries(env: &Env, config: &ProjectConfig) {
+    bump_instance(env);
+    let id = config.id;
+    for key in [
+        DataKey::ProjConfig(id),
+        DataKey::ProjState(id),
+        DataKey::ProjectDonorCount(id),
+        DataKey::RefundCursor(id),
+        DataKey::Rejecters(id),
+        DataKey::ProjectFee(id),
+        DataKey::QueuedRelease(id),
+        DataKey::Frozen(id),
+        DataKey::PendingOwner(id),
+        DataKey::Beneficiaries(id),
+        DataKey::CreatorProjectIndex(id),
+        DataKey::MetadataHistory(id),
+        DataKey::ProjectRound(id),
+        DataKey::MatchedRounds(id),
+        DataKey::ProjectPledgeCount(id),
+        DataKey::PledgeCursor(id),
+        DataKey::ProjectCommitmentCount(id),
+    ] {
+        extend_full(env, &key);
+    }
+    for token in config.accepted_tokens.iter() {
+        extend_full(env, &DataKey::TokenBalance(id, token.clone()));
+        extend_full(env, &DataKey::TokenRate(token));
+    }
+    let round_key = DataKey::ProjectRound(id);
+    if let Some(round_id) = env.storage().persistent().get::<DataKey, u64>(&round_key) {
+        extend_full(env, &DataKey::Round(round_id));
+        extend_full(env, &DataKey::RoundTally(round_id, id));
+    }
+    let rounds_key = DataKey::MatchedRounds(id);
+    if let Some(rounds) = env.storage().persistent().get::<DataKey, Vec<u64>>(&rounds_key) {
+        for round_id in rounds.iter() {
+            extend_full(env, &DataKey::Round(round_id));
+            extend_full(env, &DataKey::RoundMatch(round_id, id));
+        }
+    }
+}
+
+/// Extend the entries of the donor at position `index` of a project's donor
+/// list to the full TTL: the list slot, the index marker and the donor's
+/// balance in each of `tokens`.
+pub fn extend_donor_entries(env: &Env, project_id: u64, index: u32, tokens: &Vec<Address>) {
+    let entry_key = DataKey::ProjectDonor(project_id, index);
+    if let Some(donator) = env.storage().persistent().get::<DataKey, Address>(&entry_key) {
+        extend_full(env, &entry_key);
+        extend_full(env, &DataKey::DonorIndexed(project_id, donator.clone()));
+        for token in tokens.iter() {
+            extend_full(env, &DataKey::DonatorBalance(project_id, token, donator.clone()));
+        }
+    }
+}
+
+/// Extend the pledge at position `index` of a project's pledge list to the
+/// full TTL: the list slot and the pledge itself, while it is live.
+pub fn extend_pledge_entries(env: &Env, project_id: u64, index: u32) {
+    let entry_key = DataKey::ProjectPledge(project_id, index);
+    if let Some(pledge_id) = env.storage().persistent().get::<DataKey, u64>(&entry_key) {
+        extend_full(env, &entry_key);
+        extend_full(env, &DataKey::Pledge(pledge_id));
+    }
+}
+
+/// Extend the commitment at position `index` of a project's commitment list
+/// to the full TTL: the list slot and the committed donation, until it is
+/// revealed or refunded.
+pub fn extend_commitment_entries(env: &Env, project_id: u64, index: u32) {
+    let entry_key = DataKey::ProjectCommitment(project_id, index);
+    if let Some(commitment) = env.storage().persistent().get::<DataKey, BytesN<32>>(&entry_key) {
+        extend_full(env, &entry_key);
+        extend_full(env, &DataKey::Commitment(project_id, commitment));
+    }
+}
+
+/// Return the unfinished `bump_project` pass of a project, if any.
+pub fn get_bump_progress(env: &Env, project_id: u64) -> Option<BumpProgress> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BumpCursor(project_id))
+}
+
+/// Save the progress of a `bump_project` pass, or clear it once the pass is done.
+pub fn set_bump_progress(env: &Env, project_id: u64, progress: Option<&BumpProgress>) {
+    let key = DataKey::BumpCursor(project_id);
+    match progress {
+        Some(progress) => {
+            env.storage().persistent().set(&key, progress);
+            bump_persistent(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+// ── Schema Migration Helpers ─────────────────────────────────────────
+//
+// Schema versions:
+//
+// | Version | Layout change |
+// |---------|---------------|
+// | 0       | Original layout (no `SchemaVersion` key) |
+// | 1       | `milestones` in `ProjConfig`, `released_milestones` in `ProjState`, creator and status indexes |
+// | 2       | `limits` and `merkle_root` in `ProjConfig` |
+
+/// `ProjectConfig` as stored before schema version 1.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectConfigV0 {
+    pub id: u64,
+    pub creator: Address,
+    pub accepted_tokens: Vec<Address>,
+    pub goal: i128,
+    pub proof_hash: BytesN<32>,
+    pub deadline: u64,
+    pub is_private: bool,
+    pub metadata_uri: Bytes,
+}
+
+/// `ProjectState` as stored before schema version 1.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectStateV0 {
+    pub status: ProjectStatus,
+    pub donation_count: u32,
+    pub refund_expiry: u64,
+}
+
+/// `ProjectConfig` as stored in schema version 1.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectConfigV1 {
+    pub id: u64,
+    pub creator: Address,
+    pub accepted_tokens: Vec<Address>,
+    pub goal: i128,
+    pub proof_hash: BytesN<32>,
+    pub deadline: u64,
+    pub is_private: bool,
+    pub metadata_uri: Bytes,
+    pub milestones: Vec<Milestone>,
+}
+
+impl ProjectConfigV0 {
+    fn into_v1(self, env: &Env) -> ProjectConfigV1 {
+        ProjectConfigV1 {
+            id: self.id,
+            creator: self.creator,
+            accepted_tokens: self.accepted_tokens,
+            goal: self.goal,
+            proof_hash: self.proof_hash,
+            deadline: self.deadline,
+            is_private: self.is_private,
+            metadata_uri: self.metadata_uri,
+            milestones: Vec::new(env),
+        }
+    }
+}
+
+impl ProjectStateV0 {
+    fn into_current(self) -> ProjectState {
+        ProjectState {
+            status: self.status,
+            donation_count: self.donation_count,
+            refund_expiry: self.refund_expiry,
+            released_milestones: 0,
+        }
+    }
+}
+
+impl ProjectConfigV1 {
+    fn into_current(self) -> ProjectConfig {
+        ProjectConfig {
+            id: self.id,
+            creator: self.creator,
+            accepted_tokens: self.accepted_tokens,
+            goal: self.goal,
+            proof_hash: self.proof_hash,
+            deadline: self.deadline,
+            is_private: self.is_private,
+            metadata_uri: self.metadata_uri,
+            milestones: self.milestones,
+            limits: DonationLimits::default(),
+            merkle_root: None,
+        }
+    }
+}
+
+/// Return the next project ID an in-progress migration will process.
+pub fn get_migration_cursor(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MigrationCursor)
+        .unwrap_or(0)
+}
+
+/// Save the migration cursor; `None` clears it once a migration completes.
+pub fn set_migration_cursor(env: &Env, cursor: Option<u64>) {
+    bump_instance(env);
+    match cursor {
+        Some(c) => env.storage().instance().set(&DataKey::MigrationCursor, &c),
+        None => env.storage().instance().remove(&DataKey::MigrationCursor),
+    }
+}
+
+/// Prepare a migration from `from_version`; called before the first project
+/// is processed.
+pub fn begin_migration(env: &Env, from_version: u32) {
+    if from_version < 1 {
+        reset_status_counts(env);
+    }
+}
+
+/// Upgrade one project from the `from_version` layout to the current one in
+/// place.
+///
+/// v0 entries are first lifted to the v1 layout, so every older project
+/// takes the same path into the current `ProjectConfig`. Projects coming
+/// from v0 are then added to the creator and status indexes introduced in
+/// v1.
+pub fn migrate_project(env: &Env, id: u64, from_version: u32) {
+    let config_key = DataKey::ProjConfig(id);
+    let state_key = DataKey::ProjState(id);
+    let storage = env.storage().persistent();
+
+    let (config, state) = if from_version < 1 {
+        let config: ProjectConfigV0 = match storage.get(&config_key) {
+            Some(c) => c,
+            None => return,
+        };
+        let state: ProjectStateV0 = match storage.get(&state_key) {
+            Some(s) => s,
+            None => panic_with_error!(env, Error::ProjectNotFound),
+        };
+        (config.into_v1(env), state.into_current())
+    } else {
+        let config: ProjectConfigV1 = match storage.get(&config_key) {
+            Some(c) => c,
+            None => return,
+        };
+        (config, load_project_state(env, id))
+    };
+
+    let config = config.into_current();
+    storage.set(&config_key, &config);
+    storage.set(&state_key, &state);
+    bump_persistent(env, &config_key);
+    bump_persistent(env, &state_key);
+
+    if from_version >= 1 {
+        return;
+    }
+    shift_status_count(env, None, &state.status);
+    append_creator_project(env, &config.creator, id);
+}
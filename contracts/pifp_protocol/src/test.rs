@@ -1,6 +1,6 @@
 extern crate std;
 
-use crate::{test_utils::TestContext, ProjectStatus, Role};
+use crate::{test_utils::TestContext, GovAction, ProjectStatus, Role};
 use soroban_sdk::Vec;
 
 #[test]
@@ -20,7 +20,8 @@ fn test_init_twice_panics() {
 #[test]
 fn test_register_project_success() {
     let ctx = TestContext::new();
-    let token = ctx.generate_address();
+    let (token, _) = ctx.create_token();
+    let token = token.address;
     let tokens = Vec::from_array(&ctx.env, [token.clone()]);
     let goal: i128 = 1_000;
 
@@ -69,6 +70,8 @@ fn test_register_past_deadline_fails() {
         &ctx.dummy_proof(),
         &ctx.dummy_metadata_uri(),
         &past_deadline,
+        &false,
+        &Vec::new(&ctx.env),
     );
 }
 
@@ -157,7 +160,7 @@ fn test_deposit_fails_when_paused() {
     let ctx = TestContext::new();
     let (project, token, _) = ctx.setup_project(1000);
 
-    ctx.client.pause(&ctx.admin);
+    ctx.govern(&GovAction::SetPaused(true));
     ctx.client
         .deposit(&project.id, &ctx.manager, &token.address, &100i128);
 }
@@ -167,7 +170,7 @@ fn test_queries_work_when_paused() {
     let ctx = TestContext::new();
     let (project, _, _) = ctx.setup_project(1000);
 
-    ctx.client.pause(&ctx.admin);
+    ctx.govern(&GovAction::SetPaused(true));
 
     // Query should still work
     let loaded = ctx.client.get_project(&project.id);
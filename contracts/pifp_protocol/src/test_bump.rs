@@ -0,0 +1,119 @@
+extern crate std;
+
+use soroban_sdk::{testutils::storage::Persistent as _, Address, BytesN};
+
+use crate::{
+    storage::{self, DataKey},
+    test_utils::TestContext,
+};
+
+const DAY_IN_LEDGERS: u32 = 17_280;
+const FULL_TTL: u32 = 30 * DAY_IN_LEDGERS;
+
+fn ttl(ctx: &TestContext, key: &DataKey) -> u32 {
+    ctx.env
+        .as_contract(&ctx.client.address, || ctx.env.storage().persistent().get_ttl(key))
+}
+
+fn balance_key(project_id: u64, token: &Address, donator: &Address) -> DataKey {
+    DataKey::DonatorBalance(project_id, token.clone(), donator.clone())
+}
+
+#[test]
+fn test_bump_project_extends_donors_in_batches() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donors: std::vec::Vec<_> = (0..3).map(|_| ctx.generate_address()).collect();
+    for donator in &donors {
+        sac.mint(donator, &100);
+        ctx.client.deposit(&project.id, donator, &token.address, &100);
+    }
+    ctx.jump_ledgers(20 * DAY_IN_LEDGERS);
+
+    assert!(!ctx.client.bump_project(&project.id, &2));
+    assert_eq!(ttl(&ctx, &DataKey::ProjConfig(project.id)), FULL_TTL);
+    assert_eq!(ttl(&ctx, &DataKey::ProjState(project.id)), FULL_TTL);
+    assert_eq!(
+        ttl(&ctx, &DataKey::TokenBalance(project.id, token.address.clone())),
+        FULL_TTL
+    );
+    assert_eq!(ttl(&ctx, &balance_key(project.id, &token.address, &donors[1])), FULL_TTL);
+    assert!(ttl(&ctx, &balance_key(project.id, &token.address, &donors[2])) < FULL_TTL);
+
+    assert!(ctx.client.bump_project(&project.id, &2));
+    assert_eq!(ttl(&ctx, &balance_key(project.id, &token.address, &donors[2])), FULL_TTL);
+}
+
+#[test]
+fn test_completed_pass_restarts_from_first_donor() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+
+    assert!(ctx.client.bump_project(&project.id, &10));
+    ctx.env.as_contract(&ctx.client.address, || {
+        assert_eq!(storage::get_bump_progress(&ctx.env, project.id), None);
+    });
+
+    ctx.jump_ledgers(25 * DAY_IN_LEDGERS);
+    assert!(ctx.client.bump_project(&project.id, &10));
+    assert_eq!(ttl(&ctx, &balance_key(project.id, &token.address, &donator)), FULL_TTL);
+}
+
+#[test]
+fn test_bump_project_walks_pledges_and_commitments() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+    let pledge_id = ctx
+        .client
+        .create_recurring_pledge(&donator, &project.id, &token.address, &100, &3_600, &5);
+    let funder = ctx.generate_address();
+    sac.mint(&funder, &100);
+    let commitment = BytesN::from_array(&ctx.env, &[7u8; 32]);
+    ctx.client
+        .deposit_committed(&project.id, &funder, &token.address, &100, &commitment);
+    ctx.jump_ledgers(20 * DAY_IN_LEDGERS);
+
+    // One donor and one pledge fit the first batch; the commitment is next.
+    assert!(!ctx.client.bump_project(&project.id, &2));
+    assert_eq!(ttl(&ctx, &DataKey::Pledge(pledge_id)), FULL_TTL);
+    let commitment_key = DataKey::Commitment(project.id, commitment);
+    assert!(ttl(&ctx, &commitment_key) < FULL_TTL);
+
+    assert!(ctx.client.bump_project(&project.id, &2));
+    assert_eq!(ttl(&ctx, &commitment_key), FULL_TTL);
+}
+
+#[test]
+fn test_bump_project_extends_token_rates() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(1_000);
+    ctx.client.set_token_rate(&ctx.admin, &token.address, &1);
+    let rate_key = DataKey::TokenRate(token.address.clone());
+    ctx.jump_ledgers(20 * DAY_IN_LEDGERS);
+    assert!(ttl(&ctx, &rate_key) < FULL_TTL);
+
+    assert!(ctx.client.bump_project(&project.id, &10));
+    assert_eq!(ttl(&ctx, &rate_key), FULL_TTL);
+}
+
+#[test]
+fn test_bump_project_without_donors() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+
+    assert!(ctx.client.bump_project(&project.id, &0));
+    assert_eq!(ttl(&ctx, &DataKey::ProjConfig(project.id)), FULL_TTL);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_bump_unknown_project_rejected() {
+    let ctx = TestContext::new();
+    ctx.client.bump_project(&99, &10);
+}
@@ -0,0 +1,185 @@
+extern crate std;
+
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env};
+
+use crate::{test_utils::TestContext, GovAction, PauseFlags};
+
+fn salt(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed; 32])
+}
+
+fn commitment(env: &Env, owner: &Address, salt: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = owner.to_xdr(env);
+    preimage.append(&Bytes::from(salt.clone()));
+    env.crypto().sha256(&preimage).into()
+}
+
+#[test]
+fn test_committed_deposit_credits_project_without_donor_balance() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let funder = ctx.generate_address();
+    let owner = ctx.generate_address();
+    sac.mint(&funder, &400);
+
+    let salt = salt(&ctx.env, 9);
+    let commitment = commitment(&ctx.env, &owner, &salt);
+    ctx.client
+        .deposit_committed(&project.id, &funder, &token.address, &400, &commitment);
+
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 400);
+    assert_eq!(ctx.client.get_project(&project.id).donation_count, 1);
+    assert_eq!(token.balance(&ctx.client.address), 400);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #33)")]
+fn test_duplicate_commitment_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let funder = ctx.generate_address();
+    let owner = ctx.generate_address();
+    sac.mint(&funder, &400);
+
+    let commitment = commitment(&ctx.env, &owner, &salt(&ctx.env, 9));
+    ctx.client
+        .deposit_committed(&project.id, &funder, &token.address, &200, &commitment);
+    ctx.client
+        .deposit_committed(&project.id, &funder, &token.address, &200, &commitment);
+}
+
+#[test]
+fn test_reveal_then_refund() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let funder = ctx.generate_address();
+    let owner = ctx.generate_address();
+    sac.mint(&funder, &400);
+
+    let salt = salt(&ctx.env, 9);
+    let commitment = commitment(&ctx.env, &owner, &salt);
+    ctx.client
+        .deposit_committed(&project.id, &funder, &token.address, &400, &commitment);
+    ctx.client.reveal(&project.id, &commitment, &owner, &salt);
+
+    ctx.jump_time(86_401);
+    ctx.client.refund(&owner, &project.id, &token.address);
+
+    assert_eq!(token.balance(&owner), 400);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+}
+
+#[test]
+fn test_refund_committed_pays_bound_recipient() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let funder = ctx.generate_address();
+    let owner = ctx.generate_address();
+    sac.mint(&funder, &400);
+
+    let salt = salt(&ctx.env, 9);
+    let commitment = commitment(&ctx.env, &owner, &salt);
+    ctx.client
+        .deposit_committed(&project.id, &funder, &token.address, &400, &commitment);
+
+    ctx.jump_time(86_401);
+    ctx.client
+        .refund_committed(&project.id, &commitment, &owner, &salt);
+
+    assert_eq!(token.balance(&owner), 400);
+    assert_eq!(token.balance(&funder), 0);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #35)")]
+fn test_refund_committed_wrong_salt_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let funder = ctx.generate_address();
+    let owner = ctx.generate_address();
+    sac.mint(&funder, &400);
+
+    let commitment = commitment(&ctx.env, &owner, &salt(&ctx.env, 9));
+    ctx.client
+        .deposit_committed(&project.id, &funder, &token.address, &400, &commitment);
+
+    ctx.jump_time(86_401);
+    ctx.client
+        .refund_committed(&project.id, &commitment, &owner, &salt(&ctx.env, 8));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #34)")]
+fn test_commitment_cannot_be_revealed_twice() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let funder = ctx.generate_address();
+    let owner = ctx.generate_address();
+    sac.mint(&funder, &400);
+
+    let salt = salt(&ctx.env, 9);
+    let commitment = commitment(&ctx.env, &owner, &salt);
+    ctx.client
+        .deposit_committed(&project.id, &funder, &token.address, &400, &commitment);
+    ctx.client.reveal(&project.id, &commitment, &owner, &salt);
+    ctx.client.reveal(&project.id, &commitment, &owner, &salt);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #15)")]
+fn test_reveal_on_cancelled_project_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let funder = ctx.generate_address();
+    let owner = ctx.generate_address();
+    sac.mint(&funder, &1_000);
+
+    let salt = salt(&ctx.env, 9);
+    let commitment = commitment(&ctx.env, &owner, &salt);
+    ctx.client
+        .deposit_committed(&project.id, &funder, &token.address, &1_000, &commitment);
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+
+    ctx.client.reveal(&project.id, &commitment, &owner, &salt);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #14)")]
+fn test_reveal_after_deadline_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let funder = ctx.generate_address();
+    let owner = ctx.generate_address();
+    sac.mint(&funder, &400);
+
+    let salt = salt(&ctx.env, 9);
+    let commitment = commitment(&ctx.env, &owner, &salt);
+    ctx.client
+        .deposit_committed(&project.id, &funder, &token.address, &400, &commitment);
+
+    ctx.jump_time(86_401);
+    ctx.client.reveal(&project.id, &commitment, &owner, &salt);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #19)")]
+fn test_reveal_halted_by_deposit_pause() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let funder = ctx.generate_address();
+    let owner = ctx.generate_address();
+    sac.mint(&funder, &400);
+
+    let salt = salt(&ctx.env, 9);
+    let commitment = commitment(&ctx.env, &owner, &salt);
+    ctx.client
+        .deposit_committed(&project.id, &funder, &token.address, &400, &commitment);
+    let flags = PauseFlags {
+        deposits: true,
+        ..PauseFlags::default()
+    };
+    ctx.govern(&GovAction::SetPauseFlags(flags));
+
+    ctx.client.reveal(&project.id, &commitment, &owner, &salt);
+}
@@ -0,0 +1,232 @@
+extern crate std;
+
+use soroban_sdk::{Address, Vec};
+
+use crate::{
+    rbac::{RbacKey, COUNCIL_PROPOSAL_LIFETIME},
+    test_utils::TestContext,
+    CouncilAction, GovAction, Role, RoleGrant,
+};
+
+fn setup_council(ctx: &TestContext, threshold: u32) -> std::vec::Vec<Address> {
+    let signers = std::vec![
+        ctx.generate_address(),
+        ctx.generate_address(),
+        ctx.generate_address(),
+    ];
+    let list = Vec::from_array(
+        &ctx.env,
+        [signers[0].clone(), signers[1].clone(), signers[2].clone()],
+    );
+    ctx.client.set_council(&ctx.admin, &list, &threshold);
+    signers
+}
+
+#[test]
+fn test_set_council_moves_super_admin_to_contract() {
+    let ctx = TestContext::new();
+    setup_council(&ctx, 2);
+
+    assert_eq!(ctx.client.get_council().unwrap().threshold, 2);
+    assert!(!ctx.client.has_role(&ctx.admin, &Role::SuperAdmin));
+    assert!(ctx
+        .client
+        .has_role(&ctx.client.address, &Role::SuperAdmin));
+}
+
+#[test]
+fn test_action_executes_after_threshold() {
+    let ctx = TestContext::new();
+    let signers = setup_council(&ctx, 2);
+    let new_admin = ctx.generate_address();
+
+    let id = ctx.client.council_propose(
+        &signers[0],
+        &CouncilAction::Apply(GovAction::GrantRole(new_admin.clone(), Role::Admin)),
+    );
+    assert_eq!(ctx.client.council_approve(&signers[1], &id), 2);
+    ctx.client.council_execute(&id);
+
+    assert!(ctx.client.has_role(&new_admin, &Role::Admin));
+    assert_eq!(ctx.client.get_council_proposal(&id), None);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #45)")]
+fn test_execute_below_threshold_rejected() {
+    let ctx = TestContext::new();
+    let signers = setup_council(&ctx, 2);
+
+    let id = ctx
+        .client
+        .council_propose(&signers[0], &CouncilAction::Apply(GovAction::SetPaused(true)));
+    ctx.client.council_execute(&id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #44)")]
+fn test_signer_cannot_approve_twice() {
+    let ctx = TestContext::new();
+    let signers = setup_council(&ctx, 2);
+
+    let id = ctx
+        .client
+        .council_propose(&signers[0], &CouncilAction::Apply(GovAction::SetPaused(true)));
+    ctx.client.council_approve(&signers[0], &id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_non_signer_cannot_propose() {
+    let ctx = TestContext::new();
+    setup_council(&ctx, 2);
+
+    ctx.client
+        .council_propose(&ctx.admin, &CouncilAction::Apply(GovAction::SetPaused(true)));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #43)")]
+fn test_threshold_above_signers_rejected() {
+    let ctx = TestContext::new();
+    setup_council(&ctx, 4);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #45)")]
+fn test_approvals_of_removed_signers_do_not_count() {
+    let ctx = TestContext::new();
+    let signers = setup_council(&ctx, 2);
+
+    let pending = ctx
+        .client
+        .council_propose(&signers[2], &CouncilAction::Apply(GovAction::SetPaused(true)));
+    ctx.client.council_approve(&signers[1], &pending);
+
+    // Rotate signers[2] out before the pending proposal is executed.
+    let rotated = Vec::from_array(&ctx.env, [signers[0].clone(), signers[1].clone()]);
+    let id = ctx
+        .client
+        .council_propose(&signers[0], &CouncilAction::SetCouncil(rotated, 2));
+    ctx.client.council_approve(&signers[1], &id);
+    ctx.client.council_execute(&id);
+
+    ctx.client.council_execute(&pending);
+}
+
+#[test]
+fn test_council_queues_upgrade() {
+    let ctx = TestContext::new();
+    let signers = setup_council(&ctx, 2);
+    let wasm_hash = soroban_sdk::BytesN::from_array(&ctx.env, &[0x42u8; 32]);
+
+    let id = ctx.client.council_propose(
+        &signers[0],
+        &CouncilAction::ProposeUpgrade(wasm_hash.clone()),
+    );
+    ctx.client.council_approve(&signers[2], &id);
+    ctx.client.council_execute(&id);
+
+    assert_eq!(ctx.client.get_pending_upgrade().unwrap().wasm_hash, wasm_hash);
+}
+
+#[test]
+fn test_council_can_hand_back_super_admin() {
+    let ctx = TestContext::new();
+    let signers = setup_council(&ctx, 1);
+    let successor = ctx.generate_address();
+
+    let id = ctx.client.council_propose(
+        &signers[0],
+        &CouncilAction::TransferSuperAdmin(successor.clone()),
+    );
+    ctx.client.council_execute(&id);
+
+    assert_eq!(ctx.client.get_council(), None);
+    assert!(ctx.client.has_role(&successor, &Role::SuperAdmin));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_super_admin_cannot_be_granted() {
+    let ctx = TestContext::new();
+    ctx.client
+        .grant_role(&ctx.admin, &ctx.generate_address(), &Role::SuperAdmin);
+}
+
+#[test]
+fn test_set_council_leaves_no_other_super_admin() {
+    let ctx = TestContext::new();
+    // A SuperAdmin grant stored for another address, e.g. by an older release.
+    let other = ctx.generate_address();
+    ctx.env.as_contract(&ctx.client.address, || {
+        ctx.env.storage().persistent().set(
+            &RbacKey::Roles(other.clone()),
+            &Vec::from_array(
+                &ctx.env,
+                [RoleGrant {
+                    role: Role::SuperAdmin,
+                    expires_at: None,
+                }],
+            ),
+        );
+    });
+    setup_council(&ctx, 2);
+
+    assert!(!ctx.client.has_role(&other, &Role::SuperAdmin));
+    assert_eq!(ctx.client.role_of(&other), None);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #76)")]
+fn test_expired_proposal_cannot_be_executed() {
+    let ctx = TestContext::new();
+    let signers = setup_council(&ctx, 2);
+
+    let id = ctx
+        .client
+        .council_propose(&signers[0], &CouncilAction::Apply(GovAction::SetPaused(true)));
+    ctx.client.council_approve(&signers[1], &id);
+    ctx.jump_time(COUNCIL_PROPOSAL_LIFETIME);
+
+    ctx.client.council_execute(&id);
+}
+
+#[test]
+fn test_proposer_can_cancel_proposal() {
+    let ctx = TestContext::new();
+    let signers = setup_council(&ctx, 2);
+
+    let id = ctx
+        .client
+        .council_propose(&signers[0], &CouncilAction::Apply(GovAction::SetPaused(true)));
+    ctx.client.council_cancel(&signers[0], &id);
+
+    assert_eq!(ctx.client.get_council_proposal(&id), None);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_other_signer_cannot_cancel_open_proposal() {
+    let ctx = TestContext::new();
+    let signers = setup_council(&ctx, 2);
+
+    let id = ctx
+        .client
+        .council_propose(&signers[0], &CouncilAction::Apply(GovAction::SetPaused(true)));
+    ctx.client.council_cancel(&signers[1], &id);
+}
+
+#[test]
+fn test_any_signer_clears_expired_proposal() {
+    let ctx = TestContext::new();
+    let signers = setup_council(&ctx, 2);
+
+    let id = ctx
+        .client
+        .council_propose(&signers[0], &CouncilAction::Apply(GovAction::SetPaused(true)));
+    ctx.jump_time(COUNCIL_PROPOSAL_LIFETIME);
+    ctx.client.council_cancel(&signers[1], &id);
+
+    assert_eq!(ctx.client.get_council_proposal(&id), None);
+}
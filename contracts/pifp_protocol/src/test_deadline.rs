@@ -1,169 +1,77 @@
-#![cfg(test)]
+extern crate std;
 
-use crate::test_utils::{create_token, setup_test};
-use crate::{Error, ProjectStatus, Role};
-use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Vec};
+use crate::test_utils::TestContext;
 
 #[test]
 fn test_extend_deadline_success() {
-    let (env, client, admin) = setup_test();
-    let creator = Address::generate(&env);
-    let token = create_token(&env, &admin);
-    let accepted_tokens = Vec::from_array(&env, [token.address.clone()]);
-    
-    // Register project manager
-    client.grant_role(&admin, &creator, &Role::ProjectManager);
-    
-    let now = 1000;
-    env.ledger().set_timestamp(now);
-    let deadline = now + 10000;
-    
-    let project = client.register_project(
-        &creator,
-        &accepted_tokens,
-        &1000,
-        &[0u8; 32].into(),
-        &deadline,
-        &deadline, &false,
-    );
-    
-    let new_deadline = deadline + 5000;
-    client.extend_deadline(&creator, &project.id, &new_deadline);
-    
-    let updated_project = client.get_project(&project.id);
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    let new_deadline = project.deadline + 5000;
+    ctx.client
+        .extend_deadline(&ctx.manager, &project.id, &new_deadline);
+
+    let updated_project = ctx.client.get_project(&project.id);
     assert_eq!(updated_project.deadline, new_deadline);
 }
 
 #[test]
 fn test_extend_deadline_by_admin() {
-    let (env, client, admin) = setup_test();
-    let creator = Address::generate(&env);
-    let token = create_token(&env, &admin);
-    let accepted_tokens = Vec::from_array(&env, [token.address.clone()]);
-    
-    client.grant_role(&admin, &creator, &Role::ProjectManager);
-    
-    let now = 1000;
-    env.ledger().set_timestamp(now);
-    let deadline = now + 10000;
-    
-    let project = client.register_project(
-        &creator,
-        &accepted_tokens,
-        &1000,
-        &[0u8; 32].into(),
-        &deadline,
-        &deadline, &false,
-    );
-    
-    let new_deadline = deadline + 5000;
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
     // Admin can also extend
-    client.extend_deadline(&admin, &project.id, &new_deadline);
-    
-    let updated_project = client.get_project(&project.id);
+    let new_deadline = project.deadline + 5000;
+    ctx.client
+        .extend_deadline(&ctx.admin, &project.id, &new_deadline);
+
+    let updated_project = ctx.client.get_project(&project.id);
     assert_eq!(updated_project.deadline, new_deadline);
 }
 
 #[test]
 #[should_panic(expected = "HostError: Error(Contract, #6)")]
 fn test_extend_deadline_unauthorized() {
-    let (env, client, admin) = setup_test();
-    let creator = Address::generate(&env);
-    let stranger = Address::generate(&env);
-    let token = create_token(&env, &admin);
-    let accepted_tokens = Vec::from_array(&env, [token.address.clone()]);
-    
-    client.grant_role(&admin, &creator, &Role::ProjectManager);
-    
-    let project = client.register_project(
-        &creator,
-        &accepted_tokens,
-        &1000,
-        &[0u8; 32].into(),
-        &(env.ledger().timestamp() + 10000),
-        &(env.ledger().timestamp() + 10000), &false,
-    );
-    
-    client.extend_deadline(&stranger, &project.id, &(env.ledger().timestamp() + 15000));
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let stranger = ctx.generate_address();
+
+    ctx.client
+        .extend_deadline(&stranger, &project.id, &(project.deadline + 5000));
 }
 
 #[test]
 #[should_panic(expected = "HostError: Error(Contract, #13)")]
 fn test_extend_deadline_backwards() {
-    let (env, client, admin) = setup_test();
-    let creator = Address::generate(&env);
-    let token = create_token(&env, &admin);
-    let accepted_tokens = Vec::from_array(&env, [token.address.clone()]);
-    
-    client.grant_role(&admin, &creator, &Role::ProjectManager);
-    
-    let deadline = env.ledger().timestamp() + 10000;
-    let project = client.register_project(
-        &creator,
-        &accepted_tokens,
-        &1000,
-        &[0u8; 32].into(),
-        &deadline,
-        &deadline, &false,
-    );
-    
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
     // New deadline same as or earlier than current is Error::InvalidDeadline (13)
-    client.extend_deadline(&creator, &project.id, &deadline);
+    ctx.client
+        .extend_deadline(&ctx.manager, &project.id, &project.deadline);
 }
 
 #[test]
 #[should_panic(expected = "HostError: Error(Contract, #14)")]
 fn test_extend_deadline_expired() {
-    let (env, client, admin) = setup_test();
-    let creator = Address::generate(&env);
-    let token = create_token(&env, &admin);
-    let accepted_tokens = Vec::from_array(&env, [token.address.clone()]);
-    
-    client.grant_role(&admin, &creator, &Role::ProjectManager);
-    
-    let now = 1000;
-    env.ledger().set_timestamp(now);
-    let deadline = now + 10000;
-    
-    let project = client.register_project(
-        &creator,
-        &accepted_tokens,
-        &1000,
-        &[0u8; 32].into(),
-        &deadline,
-        &deadline, &false,
-    );
-    
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
     // Fast forward past deadline
-    env.ledger().set_timestamp(deadline + 1);
-    
-    client.extend_deadline(&creator, &project.id, &(deadline + 5000));
+    ctx.jump_time(86_401);
+
+    ctx.client
+        .extend_deadline(&ctx.manager, &project.id, &(project.deadline + 5000));
 }
 
 #[test]
-#[should_panic(expected = "HostError: Error(Contract, #24)")]
+#[should_panic(expected = "HostError: Error(Contract, #70)")]
 fn test_extend_deadline_too_long() {
-    let (env, client, admin) = setup_test();
-    let creator = Address::generate(&env);
-    let token = create_token(&env, &admin);
-    let accepted_tokens = Vec::from_array(&env, [token.address.clone()]);
-    
-    client.grant_role(&admin, &creator, &Role::ProjectManager);
-    
-    let now = 1000;
-    env.ledger().set_timestamp(now);
-    let deadline = now + 10000;
-    
-    let project = client.register_project(
-        &creator,
-        &accepted_tokens,
-        &1000,
-        &[0u8; 32].into(),
-        &deadline,
-        &deadline, &false,
-    );
-    
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
     // 1 year + 1 second
-    let too_late = now + 31_536_000 + 1;
-    client.extend_deadline(&creator, &project.id, &too_late);
+    let too_late = ctx.env.ledger().timestamp() + 31_536_000 + 1;
+    ctx.client
+        .extend_deadline(&ctx.manager, &project.id, &too_late);
 }
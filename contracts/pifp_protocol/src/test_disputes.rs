@@ -0,0 +1,205 @@
+extern crate std;
+
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+use crate::{test_utils::TestContext, Milestone, ProjectStatus, Role};
+
+const WINDOW: u64 = 12 * 60 * 60;
+
+fn setup(ctx: &TestContext) -> Address {
+    ctx.client.set_dispute_window(&ctx.admin, &WINDOW);
+    let auditor = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &auditor, &Role::Auditor);
+    auditor
+}
+
+fn reason(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[9u8; 32])
+}
+
+#[test]
+fn test_release_waits_for_window() {
+    let ctx = TestContext::new();
+    setup(&ctx);
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_000);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::PendingRelease
+    );
+    assert_eq!(token.balance(&ctx.manager), 0);
+
+    ctx.jump_time(WINDOW);
+    ctx.client.finalize_release(&project.id);
+
+    assert_eq!(ctx.client.get_project(&project.id).status, ProjectStatus::Completed);
+    assert_eq!(token.balance(&ctx.manager), 1_000);
+    assert_eq!(ctx.client.get_queued_release(&project.id), None);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #62)")]
+fn test_finalize_during_window_rejected() {
+    let ctx = TestContext::new();
+    setup(&ctx);
+    let (project, _, _) = ctx.setup_project(1_000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.jump_time(WINDOW - 1);
+    ctx.client.finalize_release(&project.id);
+}
+
+#[test]
+fn test_dispute_returns_project_for_reverification() {
+    let ctx = TestContext::new();
+    let auditor = setup(&ctx);
+    let oracle = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &oracle, &Role::Oracle);
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.client
+        .dispute(&auditor, &project.id, &reason(&ctx.env), &false);
+
+    assert_eq!(ctx.client.get_project(&project.id).status, ProjectStatus::Active);
+    assert_eq!(ctx.client.get_queued_release(&project.id), None);
+
+    // A different oracle can verify again.
+    ctx.client
+        .verify_and_release(&oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::PendingRelease
+    );
+}
+
+#[test]
+fn test_reverify_after_dispute() {
+    let ctx = TestContext::new();
+    let auditor = setup(&ctx);
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.client
+        .dispute(&auditor, &project.id, &reason(&ctx.env), &false);
+    assert_eq!(
+        ctx.client.get_attestation_count(&project.id, &ctx.dummy_proof()),
+        0
+    );
+
+    // The same oracle can verify the corrected proof again.
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::PendingRelease
+    );
+
+    ctx.jump_time(WINDOW);
+    ctx.client.finalize_release(&project.id);
+    assert_eq!(ctx.client.get_project(&project.id).status, ProjectStatus::Completed);
+    assert_eq!(token.balance(&ctx.manager), 1_000);
+}
+
+#[test]
+fn test_dispute_with_cancel_allows_refunds() {
+    let ctx = TestContext::new();
+    let auditor = setup(&ctx);
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.client
+        .dispute(&auditor, &project.id, &reason(&ctx.env), &true);
+    assert_eq!(ctx.client.get_project(&project.id).status, ProjectStatus::Cancelled);
+
+    ctx.client.refund(&donator, &project.id, &token.address);
+    assert_eq!(token.balance(&donator), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_only_auditor_can_dispute() {
+    let ctx = TestContext::new();
+    setup(&ctx);
+    let (project, _, _) = ctx.setup_project(1_000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.client
+        .dispute(&ctx.manager, &project.id, &reason(&ctx.env), &true);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #61)")]
+fn test_dispute_after_window_rejected() {
+    let ctx = TestContext::new();
+    let auditor = setup(&ctx);
+    let (project, _, _) = ctx.setup_project(1_000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.jump_time(WINDOW);
+    ctx.client
+        .dispute(&auditor, &project.id, &reason(&ctx.env), &false);
+}
+
+#[test]
+fn test_milestone_release_resumes_active() {
+    let ctx = TestContext::new();
+    setup(&ctx);
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let first = BytesN::from_array(&ctx.env, &[1u8; 32]);
+    let milestones = Vec::from_array(
+        &ctx.env,
+        [
+            Milestone {
+                proof_hash: first.clone(),
+                share_bps: 4_000,
+            },
+            Milestone {
+                proof_hash: BytesN::from_array(&ctx.env, &[2u8; 32]),
+                share_bps: 6_000,
+            },
+        ],
+    );
+    let project = ctx.register_project_with_milestones(&tokens, 1_000, &milestones);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_000);
+
+    ctx.client
+        .verify_milestone(&ctx.oracle, &project.id, &0, &first);
+    ctx.jump_time(WINDOW);
+    ctx.client.finalize_release(&project.id);
+
+    let project = ctx.client.get_project(&project.id);
+    assert_eq!(project.status, ProjectStatus::Active);
+    assert_eq!(project.released_milestones, 1);
+    assert_eq!(token.balance(&ctx.manager), 400);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #63)")]
+fn test_window_longer_than_thirty_days_rejected() {
+    let ctx = TestContext::new();
+    ctx.client
+        .set_dispute_window(&ctx.admin, &(31 * 24 * 60 * 60));
+}
@@ -103,6 +103,8 @@ fn test_register_deadline_too_far_in_future_fails() {
         &ctx.dummy_proof(),
         &ctx.dummy_metadata_uri(),
         &too_far_deadline,
+        &false,
+        &Vec::new(&ctx.env),
     );
 }
 
@@ -143,7 +145,7 @@ fn test_verify_when_paused_fails() {
     let ctx = TestContext::new();
     let (project, _, _) = ctx.setup_project(1000);
 
-    ctx.client.pause(&ctx.admin);
+    ctx.govern(&crate::GovAction::SetPaused(true));
     ctx.client
         .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
 }
@@ -203,12 +205,12 @@ fn test_deposit_unaccepted_token_fails() {
 #[should_panic(expected = "HostError: Error(Contract, #6)")]
 fn test_admin_cannot_cancel_project() {
     let ctx = TestContext::new();
-    let (project, token, sac) = ctx.setup_project(500);
-    let donator = ctx.generate_address();
     let other_admin = ctx.generate_address();
-
     ctx.client
         .grant_role(&ctx.admin, &other_admin, &crate::Role::Admin);
+
+    let (project, token, sac) = ctx.setup_project(500);
+    let donator = ctx.generate_address();
     sac.mint(&donator, &600i128);
     ctx.client
         .deposit(&project.id, &donator, &token.address, &600i128);
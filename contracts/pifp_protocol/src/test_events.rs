@@ -1,6 +1,6 @@
 extern crate std;
 
-use soroban_sdk::{vec, Bytes};
+use soroban_sdk::{vec, Vec};
 
 use crate::test_utils::TestContext;
 
@@ -55,6 +55,8 @@ fn test_get_project_balances() {
         &ctx.dummy_proof(),
         &metadata_uri,
         &(ctx.env.ledger().timestamp() + 86400),
+        &false,
+        &Vec::new(&ctx.env),
     );
 
     let donator = ctx.generate_address();
@@ -0,0 +1,125 @@
+extern crate std;
+
+use soroban_sdk::{Address, Vec};
+
+use crate::{test_utils::TestContext, FeeSplit, GovAction, Role};
+
+fn split(recipient: &Address, share_bps: u32) -> FeeSplit {
+    FeeSplit {
+        recipient: recipient.clone(),
+        share_bps,
+    }
+}
+
+#[test]
+fn test_fee_is_divided_between_splits() {
+    let ctx = TestContext::new();
+    let treasury = ctx.generate_address();
+    let operator = ctx.generate_address();
+    let referrer = ctx.generate_address();
+    ctx.client.update_protocol_config(&ctx.admin, &treasury, &500);
+    ctx.client.set_fee_splits(
+        &ctx.admin,
+        &Vec::from_array(
+            &ctx.env,
+            [
+                split(&treasury, 6_000),
+                split(&operator, 3_000),
+                split(&referrer, 1_000),
+            ],
+        ),
+    );
+
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    assert_eq!(token.balance(&treasury), 30);
+    assert_eq!(token.balance(&operator), 15);
+    assert_eq!(token.balance(&referrer), 5);
+    assert_eq!(token.balance(&ctx.manager), 950);
+}
+
+#[test]
+fn test_project_fee_override_waives_fee() {
+    let ctx = TestContext::new();
+    let treasury = ctx.generate_address();
+    ctx.client.update_protocol_config(&ctx.admin, &treasury, &500);
+
+    let (project, token, sac) = ctx.setup_project(1_000);
+    ctx.client.set_project_fee(&ctx.admin, &project.id, &Some(0));
+    assert_eq!(ctx.client.get_effective_fee_bps(&project.id), 0);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    assert_eq!(token.balance(&treasury), 0);
+    assert_eq!(token.balance(&ctx.manager), 1_000);
+}
+
+#[test]
+fn test_override_cannot_raise_fee() {
+    let ctx = TestContext::new();
+    ctx.client
+        .update_protocol_config(&ctx.admin, &ctx.generate_address(), &200);
+    let (project, _, _) = ctx.setup_project(1_000);
+
+    ctx.client.set_project_fee(&ctx.admin, &project.id, &Some(800));
+    assert_eq!(ctx.client.get_effective_fee_bps(&project.id), 200);
+
+    ctx.govern(&GovAction::SetProjectFee(project.id, None));
+    assert_eq!(ctx.client.get_effective_fee_bps(&project.id), 200);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #42)")]
+fn test_removing_waiver_requires_timelock() {
+    let ctx = TestContext::new();
+    ctx.client
+        .update_protocol_config(&ctx.admin, &ctx.generate_address(), &500);
+    let (project, _, _) = ctx.setup_project(1_000);
+    ctx.client.set_project_fee(&ctx.admin, &project.id, &Some(0));
+
+    ctx.client.set_project_fee(&ctx.admin, &project.id, &None);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #42)")]
+fn test_raising_override_requires_timelock() {
+    let ctx = TestContext::new();
+    ctx.client
+        .update_protocol_config(&ctx.admin, &ctx.generate_address(), &500);
+    let (project, _, _) = ctx.setup_project(1_000);
+    ctx.client.set_project_fee(&ctx.admin, &project.id, &Some(300));
+    ctx.client.set_project_fee(&ctx.admin, &project.id, &Some(100));
+
+    ctx.client.set_project_fee(&ctx.admin, &project.id, &Some(200));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #58)")]
+fn test_splits_must_sum_to_whole_fee() {
+    let ctx = TestContext::new();
+    let treasury = ctx.generate_address();
+    ctx.client.set_fee_splits(
+        &ctx.admin,
+        &Vec::from_array(&ctx.env, [split(&treasury, 9_000)]),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_admin_cannot_set_fee_override() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+    let (project, _, _) = ctx.setup_project(1_000);
+
+    ctx.client.set_project_fee(&admin, &project.id, &Some(0));
+}
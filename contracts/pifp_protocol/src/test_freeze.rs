@@ -0,0 +1,123 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, GovAction, PauseFlags, Role};
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #64)")]
+fn test_frozen_project_rejects_deposits() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    ctx.client.freeze_project(&ctx.admin, &project.id);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #64)")]
+fn test_frozen_project_rejects_verification() {
+    let ctx = TestContext::new();
+    let auditor = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &auditor, &Role::Auditor);
+    let (project, _, _) = ctx.setup_project(1_000);
+    ctx.client.freeze_project(&auditor, &project.id);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+fn test_freeze_leaves_other_projects_running() {
+    let ctx = TestContext::new();
+    let (frozen, _, _) = ctx.setup_project(1_000);
+    let (project, token, sac) = ctx.setup_project(1_000);
+    ctx.client.freeze_project(&ctx.admin, &frozen.id);
+    assert!(ctx.client.is_project_frozen(&frozen.id));
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+}
+
+#[test]
+fn test_unfreeze_resumes_deposits() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    ctx.client.freeze_project(&ctx.admin, &project.id);
+    ctx.client.unfreeze_project(&ctx.admin, &project.id);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+    assert!(!ctx.client.is_project_frozen(&project.id));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_manager_cannot_freeze() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+    ctx.client.freeze_project(&ctx.manager, &project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #19)")]
+fn test_deposit_flag_halts_deposits() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let flags = PauseFlags {
+        deposits: true,
+        ..PauseFlags::default()
+    };
+    ctx.govern(&GovAction::SetPauseFlags(flags));
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+}
+
+#[test]
+fn test_deposit_flag_leaves_registration_open() {
+    let ctx = TestContext::new();
+    let flags = PauseFlags {
+        deposits: true,
+        ..PauseFlags::default()
+    };
+    ctx.client.set_pause_flags(&ctx.admin, &flags);
+
+    let (project, _, _) = ctx.setup_project(1_000);
+    assert_eq!(ctx.client.get_pause_flags(), flags);
+    assert_eq!(ctx.client.get_project(&project.id).id, project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #19)")]
+fn test_refund_flag_halts_refunds() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+
+    let flags = PauseFlags {
+        refunds: true,
+        ..PauseFlags::default()
+    };
+    ctx.govern(&GovAction::SetPauseFlags(flags));
+    ctx.client.refund(&donator, &project.id, &token.address);
+}
+
+#[test]
+fn test_refunds_ignore_global_pause() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+
+    ctx.govern(&GovAction::SetPaused(true));
+    ctx.client.refund(&donator, &project.id, &token.address);
+    assert_eq!(token.balance(&donator), 100);
+}
@@ -0,0 +1,174 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, GovAction, ProtocolConfig, Role};
+
+const DELAY: u64 = 2 * 24 * 60 * 60;
+
+fn fee_config(ctx: &TestContext, fee_bps: u32) -> ProtocolConfig {
+    ProtocolConfig {
+        fee_recipient: ctx.admin.clone(),
+        fee_bps,
+    }
+}
+
+#[test]
+fn test_fee_change_applies_after_delay() {
+    let ctx = TestContext::new();
+    let recipient = ctx.generate_address();
+    ctx.client.update_protocol_config(&ctx.admin, &recipient, &100);
+
+    let id = ctx.client.propose_action(
+        &ctx.admin,
+        &GovAction::UpdateProtocolConfig(fee_config(&ctx, 500)),
+    );
+    let proposal = ctx.client.get_proposal(&id).unwrap();
+    assert_eq!(proposal.eta, ctx.env.ledger().timestamp() + DELAY);
+
+    ctx.jump_time(DELAY);
+    ctx.client.execute_proposal(&id);
+
+    assert_eq!(ctx.client.get_protocol_config(), Some(fee_config(&ctx, 500)));
+    assert_eq!(ctx.client.get_proposal(&id), None);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #42)")]
+fn test_direct_fee_change_rejected_once_configured() {
+    let ctx = TestContext::new();
+    let recipient = ctx.generate_address();
+    ctx.client.update_protocol_config(&ctx.admin, &recipient, &100);
+    ctx.client.update_protocol_config(&ctx.admin, &recipient, &500);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #39)")]
+fn test_execute_before_eta_rejected() {
+    let ctx = TestContext::new();
+    let id = ctx
+        .client
+        .propose_action(&ctx.admin, &GovAction::SetPaused(true));
+
+    ctx.jump_time(DELAY - 1);
+    ctx.client.execute_proposal(&id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #41)")]
+fn test_cancelled_proposal_cannot_execute() {
+    let ctx = TestContext::new();
+    let id = ctx
+        .client
+        .propose_action(&ctx.admin, &GovAction::SetPaused(true));
+    ctx.client.cancel_proposal(&ctx.admin, &id);
+
+    ctx.jump_time(DELAY);
+    ctx.client.execute_proposal(&id);
+}
+
+#[test]
+fn test_role_grant_executed_with_proposer_authority() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    let auditor = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+
+    let id = ctx
+        .client
+        .propose_action(&admin, &GovAction::GrantRole(auditor.clone(), Role::Auditor));
+    ctx.jump_time(DELAY);
+    ctx.client.execute_proposal(&id);
+
+    assert!(ctx.client.has_role(&auditor, &Role::Auditor));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_execution_fails_if_proposer_lost_role() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+
+    let id = ctx
+        .client
+        .propose_action(&admin, &GovAction::SetPaused(true));
+    ctx.client.revoke_role(&ctx.admin, &admin);
+
+    ctx.jump_time(DELAY);
+    ctx.client.execute_proposal(&id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_admin_cannot_propose_fee_change() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+
+    ctx.client.propose_action(
+        &admin,
+        &GovAction::UpdateProtocolConfig(fee_config(&ctx, 500)),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #42)")]
+fn test_direct_grant_rejected_once_projects_exist() {
+    let ctx = TestContext::new();
+    ctx.setup_project(1_000);
+
+    ctx.client
+        .grant_role(&ctx.admin, &ctx.generate_address(), &Role::Oracle);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #42)")]
+fn test_direct_pause_rejected_once_projects_exist() {
+    let ctx = TestContext::new();
+    ctx.setup_project(1_000);
+
+    ctx.client.pause(&ctx.admin);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #42)")]
+fn test_direct_dispute_window_rejected_once_projects_exist() {
+    let ctx = TestContext::new();
+    ctx.setup_project(1_000);
+
+    ctx.client.set_dispute_window(&ctx.admin, &0);
+}
+
+#[test]
+fn test_timed_grant_through_proposal() {
+    let ctx = TestContext::new();
+    ctx.setup_project(1_000);
+    let auditor = ctx.generate_address();
+    let expires_at = ctx.env.ledger().sequence() + 100;
+
+    ctx.govern(&GovAction::GrantRoleUntil(
+        auditor.clone(),
+        Role::Auditor,
+        expires_at,
+    ));
+    assert_eq!(
+        ctx.client.roles_of(&auditor).get(0).unwrap().expires_at,
+        Some(expires_at)
+    );
+
+    // Bringing the expiry forward does not need the timelock.
+    ctx.client
+        .renew_role(&ctx.admin, &auditor, &Role::Auditor, &Some(expires_at - 50));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #42)")]
+fn test_direct_renewal_cannot_extend_once_projects_exist() {
+    let ctx = TestContext::new();
+    let expires_at = ctx.env.ledger().sequence() + 100;
+    ctx.client
+        .grant_role_until(&ctx.admin, &ctx.oracle, &Role::Oracle, &expires_at);
+    ctx.setup_project(1_000);
+
+    ctx.client
+        .renew_role(&ctx.admin, &ctx.oracle, &Role::Oracle, &None);
+}
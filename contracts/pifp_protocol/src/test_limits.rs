@@ -0,0 +1,128 @@
+extern crate std;
+
+use soroban_sdk::BytesN;
+
+use crate::{test_utils::TestContext, DonationLimits, OverfundPolicy, ProjectStatus};
+
+fn limits(min_donation: i128, max_per_donor: i128, hard_cap: i128) -> DonationLimits {
+    DonationLimits {
+        min_donation,
+        max_per_donor,
+        hard_cap,
+        overflow: OverfundPolicy::Reject,
+    }
+}
+
+#[test]
+fn test_limits_are_stored_on_project() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+
+    ctx.client
+        .set_donation_limits(&ctx.manager, &project.id, &limits(10, 500, 2_000));
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).limits,
+        limits(10, 500, 2_000)
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #53)")]
+fn test_donation_below_minimum_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    ctx.client
+        .set_donation_limits(&ctx.manager, &project.id, &limits(10, 0, 0));
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &9);
+    ctx.client.deposit(&project.id, &donator, &token.address, &9);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #54)")]
+fn test_per_donor_limit_is_cumulative() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    ctx.client
+        .set_donation_limits(&ctx.manager, &project.id, &limits(0, 300, 0));
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client.deposit(&project.id, &donator, &token.address, &200);
+    ctx.client.deposit(&project.id, &donator, &token.address, &200);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #55)")]
+fn test_hard_cap_rejects_overfunding() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    ctx.client
+        .set_donation_limits(&ctx.manager, &project.id, &limits(0, 0, 1_200));
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_300);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &300);
+}
+
+#[test]
+fn test_hard_cap_takes_only_what_fits() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let mut refund_excess = limits(0, 0, 1_200);
+    refund_excess.overflow = OverfundPolicy::RefundExcess;
+    ctx.client
+        .set_donation_limits(&ctx.manager, &project.id, &refund_excess);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_500);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_500);
+
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 1_200);
+    assert_eq!(token.balance(&donator), 300);
+    assert_eq!(ctx.client.get_project(&project.id).status, ProjectStatus::Active);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #54)")]
+fn test_committed_deposit_refused_with_per_donor_limit() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    ctx.client
+        .set_donation_limits(&ctx.manager, &project.id, &limits(0, 300, 0));
+
+    let funder = ctx.generate_address();
+    sac.mint(&funder, &100);
+    ctx.client.deposit_committed(
+        &project.id,
+        &funder,
+        &token.address,
+        &100,
+        &BytesN::from_array(&ctx.env, &[7u8; 32]),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #56)")]
+fn test_hard_cap_below_goal_rejected() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+    ctx.client
+        .set_donation_limits(&ctx.manager, &project.id, &limits(0, 0, 999));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #57)")]
+fn test_limits_locked_after_first_donation() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+
+    ctx.client
+        .set_donation_limits(&ctx.manager, &project.id, &limits(10, 0, 0));
+}
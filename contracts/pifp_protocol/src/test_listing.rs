@@ -0,0 +1,117 @@
+extern crate std;
+
+use soroban_sdk::Vec;
+
+use crate::{test_utils::TestContext, Role, StatusCounts};
+
+#[test]
+fn test_list_projects_paginates_by_id() {
+    let ctx = TestContext::new();
+    for _ in 0..5 {
+        ctx.setup_project(1_000);
+    }
+
+    let first = ctx.client.list_projects(&0, &3);
+    assert_eq!(first.len(), 3);
+    assert_eq!(first.get(0).unwrap().id, 0);
+    assert_eq!(first.get(2).unwrap().id, 2);
+
+    let second = ctx.client.list_projects(&3, &3);
+    assert_eq!(second.len(), 2);
+    assert_eq!(second.get(1).unwrap().id, 4);
+
+    assert_eq!(ctx.client.list_projects(&5, &3).len(), 0);
+}
+
+#[test]
+fn test_list_projects_caps_page_size() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    for _ in 0..35 {
+        ctx.register_project(&tokens, 1_000);
+    }
+
+    assert_eq!(ctx.client.list_projects(&0, &100).len(), 30);
+}
+
+#[test]
+fn test_list_projects_by_creator() {
+    let ctx = TestContext::new();
+    let other = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &other, &Role::ProjectManager);
+
+    let (token, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let deadline = ctx.env.ledger().timestamp() + 86_400;
+
+    ctx.register_project(&tokens, 1_000);
+    let theirs = ctx.client.register_project(
+        &other,
+        &tokens,
+        &1_000,
+        &ctx.dummy_proof(),
+        &ctx.dummy_metadata_uri(),
+        &deadline,
+        &false,
+        &Vec::new(&ctx.env),
+    );
+    ctx.register_project(&tokens, 1_000);
+
+    assert_eq!(ctx.client.get_creator_project_count(&ctx.manager), 2);
+    assert_eq!(ctx.client.get_creator_project_count(&other), 1);
+
+    let mine = ctx.client.list_projects_by_creator(&ctx.manager, &0, &10);
+    assert_eq!(mine.len(), 2);
+    assert_eq!(mine.get(0).unwrap().id, 0);
+    assert_eq!(mine.get(1).unwrap().id, 2);
+
+    let page = ctx.client.list_projects_by_creator(&ctx.manager, &1, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, 2);
+
+    let listed = ctx.client.list_projects_by_creator(&other, &0, &10);
+    assert_eq!(listed.get(0).unwrap().id, theirs.id);
+}
+
+#[test]
+fn test_count_by_status_tracks_transitions() {
+    let ctx = TestContext::new();
+    let (funded, token, sac) = ctx.setup_project(1_000);
+    let (expiring, _, _) = ctx.setup_project(1_000);
+    ctx.setup_project(1_000);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&funded.id, &donator, &token.address, &1_000);
+    assert_eq!(
+        ctx.client.count_by_status(),
+        StatusCounts {
+            funding: 2,
+            active: 1,
+            completed: 0,
+            expired: 0,
+            cancelled: 0,
+            pending_release: 0,
+            failed: 0,
+        }
+    );
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &funded.id, &ctx.dummy_proof());
+    ctx.jump_time(86_401);
+    ctx.client.expire_project(&expiring.id);
+
+    assert_eq!(
+        ctx.client.count_by_status(),
+        StatusCounts {
+            funding: 1,
+            active: 0,
+            completed: 1,
+            expired: 1,
+            cancelled: 0,
+            pending_release: 0,
+            failed: 0,
+        }
+    );
+}
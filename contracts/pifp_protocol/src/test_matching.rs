@@ -0,0 +1,262 @@
+extern crate std;
+
+use soroban_sdk::{token, Address, BytesN, Vec};
+
+use crate::{test_utils::TestContext, ProjectStatus, RoundTally};
+
+const ROUND_LENGTH: u64 = 1_000;
+
+struct Round {
+    id: u64,
+    projects: std::vec::Vec<u64>,
+    token: token::Client<'static>,
+    sac: token::StellarAssetClient<'static>,
+}
+
+fn setup_round(ctx: &TestContext, goals: &[i128], pool: i128) -> Round {
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let projects: std::vec::Vec<u64> = goals
+        .iter()
+        .map(|goal| ctx.register_project(&tokens, *goal).id)
+        .collect();
+
+    let mut ids = Vec::new(&ctx.env);
+    for id in &projects {
+        ids.push_back(*id);
+    }
+    let now = ctx.env.ledger().timestamp();
+    let id = ctx
+        .client
+        .create_round(&ctx.admin, &token.address, &now, &(now + ROUND_LENGTH), &ids);
+
+    let sponsor = ctx.generate_address();
+    sac.mint(&sponsor, &pool);
+    ctx.client.fund_round(&sponsor, &id, &pool);
+
+    Round {
+        id,
+        projects,
+        token,
+        sac,
+    }
+}
+
+fn donate(ctx: &TestContext, round: &Round, project_id: u64, amount: i128) -> Address {
+    let donator = ctx.generate_address();
+    round.sac.mint(&donator, &amount);
+    ctx.client
+        .deposit(&project_id, &donator, &round.token.address, &amount);
+    donator
+}
+
+#[test]
+fn test_many_small_donors_outweigh_one_large() {
+    let ctx = TestContext::new();
+    let round = setup_round(&ctx, &[1_000_000, 1_000_000], 1_000);
+    let (broad, narrow) = (round.projects[0], round.projects[1]);
+
+    for _ in 0..4 {
+        donate(&ctx, &round, broad, 100);
+    }
+    for _ in 0..2 {
+        donate(&ctx, &round, narrow, 100);
+    }
+    assert_eq!(
+        ctx.client.get_round_tally(&round.id, &broad),
+        RoundTally {
+            sqrt_sum: 40,
+            total: 400,
+        }
+    );
+
+    ctx.jump_time(ROUND_LENGTH);
+    ctx.client.finalize_round(&round.id);
+
+    // Weights: 40² − 400 = 1200 and 20² − 200 = 200.
+    assert_eq!(ctx.client.get_balance(&broad, &round.token.address), 400 + 857);
+    assert_eq!(ctx.client.get_balance(&narrow, &round.token.address), 200 + 142);
+    // The rounding remainder returns to the round creator.
+    assert_eq!(round.token.balance(&ctx.admin), 1);
+    assert!(ctx.client.get_round(&round.id).finalized);
+}
+
+#[test]
+fn test_large_balances_do_not_overflow_the_match() {
+    // 18-decimal token: 1 000 units per donation, a 1 000 000 unit pool.
+    const UNIT: i128 = 1_000_000_000_000_000_000;
+    let ctx = TestContext::new();
+    let round = setup_round(
+        &ctx,
+        &[1_000_000_000 * UNIT, 1_000_000_000 * UNIT],
+        1_000_000 * UNIT,
+    );
+    let (broad, narrow) = (round.projects[0], round.projects[1]);
+
+    for _ in 0..4 {
+        donate(&ctx, &round, broad, 1_000 * UNIT);
+    }
+    for _ in 0..2 {
+        donate(&ctx, &round, narrow, 1_000 * UNIT);
+    }
+
+    ctx.jump_time(ROUND_LENGTH);
+    ctx.client.finalize_round(&round.id);
+
+    assert_eq!(
+        ctx.client.get_balance(&broad, &round.token.address),
+        4_000 * UNIT + 857_142_857_146_387_500_963_401
+    );
+    assert_eq!(
+        ctx.client.get_balance(&narrow, &round.token.address),
+        2_000 * UNIT + 142_857_142_853_612_499_036_598
+    );
+    assert_eq!(round.token.balance(&ctx.admin), 1);
+}
+
+#[test]
+fn test_single_donor_earns_no_match() {
+    let ctx = TestContext::new();
+    let round = setup_round(&ctx, &[1_000_000], 1_000);
+    donate(&ctx, &round, round.projects[0], 500);
+
+    ctx.jump_time(ROUND_LENGTH);
+    ctx.client.finalize_round(&round.id);
+
+    assert_eq!(
+        ctx.client.get_balance(&round.projects[0], &round.token.address),
+        500
+    );
+    assert_eq!(round.token.balance(&ctx.admin), 1_000);
+}
+
+#[test]
+fn test_match_can_complete_goal() {
+    let ctx = TestContext::new();
+    let round = setup_round(&ctx, &[1_000], 1_000);
+    donate(&ctx, &round, round.projects[0], 100);
+    donate(&ctx, &round, round.projects[0], 100);
+
+    ctx.jump_time(ROUND_LENGTH);
+    ctx.client.finalize_round(&round.id);
+
+    let project = ctx.client.get_project(&round.projects[0]);
+    assert_eq!(project.status, ProjectStatus::Active);
+}
+
+#[test]
+fn test_expired_project_returns_match_to_round_creator() {
+    let ctx = TestContext::new();
+    let round = setup_round(&ctx, &[1_000_000], 1_000);
+    let project_id = round.projects[0];
+    let donator = donate(&ctx, &round, project_id, 100);
+    donate(&ctx, &round, project_id, 100);
+
+    ctx.jump_time(ROUND_LENGTH);
+    ctx.client.finalize_round(&round.id);
+    assert_eq!(ctx.client.get_balance(&project_id, &round.token.address), 1_200);
+
+    ctx.jump_time(86_400);
+    ctx.client.return_round_matches(&project_id);
+
+    assert_eq!(ctx.client.get_project(&project_id).status, ProjectStatus::Expired);
+    assert_eq!(round.token.balance(&ctx.admin), 1_000);
+    assert_eq!(ctx.client.get_balance(&project_id, &round.token.address), 200);
+
+    // Donors still get their own donations back, and the match is not paid twice.
+    ctx.client.refund(&donator, &project_id, &round.token.address);
+    assert_eq!(round.token.balance(&donator), 100);
+    ctx.client.return_round_matches(&project_id);
+    assert_eq!(round.token.balance(&ctx.admin), 1_000);
+}
+
+#[test]
+fn test_failed_project_match_is_not_reclaimed_by_creator() {
+    let ctx = TestContext::new();
+    let round = setup_round(&ctx, &[1_000_000], 1_000);
+    let project_id = round.projects[0];
+    donate(&ctx, &round, project_id, 100);
+    donate(&ctx, &round, project_id, 100);
+
+    ctx.jump_time(ROUND_LENGTH);
+    ctx.client.finalize_round(&round.id);
+    ctx.client.reject_verification(
+        &ctx.oracle,
+        &project_id,
+        &BytesN::from_array(&ctx.env, &[4u8; 32]),
+    );
+    assert_eq!(ctx.client.get_project(&project_id).status, ProjectStatus::Failed);
+
+    // Nobody claimed anything before the refund window closed.
+    ctx.jump_time(6 * 30 * 24 * 60 * 60);
+    ctx.client.reclaim_expired_funds(&ctx.manager, &project_id);
+
+    assert_eq!(round.token.balance(&ctx.admin), 1_000);
+    assert_eq!(round.token.balance(&ctx.manager), 200);
+    assert_eq!(ctx.client.get_balance(&project_id, &round.token.address), 0);
+}
+
+#[test]
+fn test_withdrawn_pledges_are_not_matched() {
+    let ctx = TestContext::new();
+    let round = setup_round(&ctx, &[1_000_000], 1_000);
+    let project_id = round.projects[0];
+    let donator = donate(&ctx, &round, project_id, 100);
+    donate(&ctx, &round, project_id, 100);
+
+    ctx.client
+        .withdraw_pledge(&donator, &project_id, &round.token.address, &100);
+    assert_eq!(
+        ctx.client.get_round_tally(&round.id, &project_id),
+        RoundTally {
+            sqrt_sum: 10,
+            total: 100,
+        }
+    );
+}
+
+#[test]
+fn test_donations_after_end_are_not_tallied() {
+    let ctx = TestContext::new();
+    let round = setup_round(&ctx, &[1_000_000], 1_000);
+
+    ctx.jump_time(ROUND_LENGTH);
+    donate(&ctx, &round, round.projects[0], 100);
+
+    assert_eq!(
+        ctx.client.get_round_tally(&round.id, &round.projects[0]),
+        RoundTally::default()
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #50)")]
+fn test_finalize_before_end_rejected() {
+    let ctx = TestContext::new();
+    let round = setup_round(&ctx, &[1_000_000], 1_000);
+    ctx.client.finalize_round(&round.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #49)")]
+fn test_fund_after_end_rejected() {
+    let ctx = TestContext::new();
+    let round = setup_round(&ctx, &[1_000_000], 1_000);
+    ctx.jump_time(ROUND_LENGTH);
+
+    let sponsor = ctx.generate_address();
+    round.sac.mint(&sponsor, &100);
+    ctx.client.fund_round(&sponsor, &round.id, &100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #47)")]
+fn test_project_cannot_join_two_open_rounds() {
+    let ctx = TestContext::new();
+    let round = setup_round(&ctx, &[1_000_000], 1_000);
+
+    let now = ctx.env.ledger().timestamp();
+    let ids = Vec::from_array(&ctx.env, [round.projects[0]]);
+    ctx.client
+        .create_round(&ctx.admin, &round.token.address, &now, &(now + 10), &ids);
+}
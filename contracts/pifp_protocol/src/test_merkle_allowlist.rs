@@ -0,0 +1,242 @@
+extern crate std;
+
+use soroban_sdk::{
+    token::{self, StellarAssetClient},
+    xdr::ToXdr,
+    Address, Bytes, BytesN, Env, Vec,
+};
+
+use crate::{test_utils::TestContext, Project};
+
+fn leaf(env: &Env, donor: &Address) -> BytesN<32> {
+    env.crypto().sha256(&donor.to_xdr(env)).into()
+}
+
+fn parent(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (low, high) = if a <= b { (a, b) } else { (b, a) };
+    let mut preimage = Bytes::from(low.clone());
+    preimage.append(&Bytes::from(high.clone()));
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Build a four-member tree and return its root and the proof of `members[0]`.
+fn tree(env: &Env, members: &[Address; 4]) -> (BytesN<32>, Vec<BytesN<32>>) {
+    let leaves: std::vec::Vec<BytesN<32>> = members.iter().map(|m| leaf(env, m)).collect();
+    let left = parent(env, &leaves[0], &leaves[1]);
+    let right = parent(env, &leaves[2], &leaves[3]);
+    let root = parent(env, &left, &right);
+    (root, Vec::from_array(env, [leaves[1].clone(), right]))
+}
+
+fn private_project(ctx: &TestContext) -> (Project, Address, StellarAssetClient<'static>) {
+    let (token, sac) = ctx.create_token();
+    let project = ctx.client.register_project(
+        &ctx.manager,
+        &Vec::from_array(&ctx.env, [token.address.clone()]),
+        &1_000,
+        &ctx.dummy_proof(),
+        &ctx.dummy_metadata_uri(),
+        &(ctx.env.ledger().timestamp() + 86_400),
+        &true,
+        &Vec::new(&ctx.env),
+    );
+    (project, token.address, sac)
+}
+
+fn members(ctx: &TestContext) -> [Address; 4] {
+    [
+        ctx.generate_address(),
+        ctx.generate_address(),
+        ctx.generate_address(),
+        ctx.generate_address(),
+    ]
+}
+
+#[test]
+fn test_member_deposits_with_proof() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = private_project(&ctx);
+    let members = members(&ctx);
+    let (root, proof) = tree(&ctx.env, &members);
+    ctx.client
+        .set_merkle_root(&ctx.manager, &project.id, &Some(root.clone()));
+
+    sac.mint(&members[0], &100);
+    ctx.client
+        .deposit_with_proof(&project.id, &members[0], &token, &100, &proof);
+
+    assert_eq!(ctx.client.get_balance(&project.id, &token), 100);
+    assert_eq!(ctx.client.get_project(&project.id).merkle_root, Some(root));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #72)")]
+fn test_proof_for_another_member_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = private_project(&ctx);
+    let members = members(&ctx);
+    let (root, proof) = tree(&ctx.env, &members);
+    ctx.client
+        .set_merkle_root(&ctx.manager, &project.id, &Some(root));
+
+    sac.mint(&members[2], &100);
+    ctx.client
+        .deposit_with_proof(&project.id, &members[2], &token, &100, &proof);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #72)")]
+fn test_proof_rejected_without_root() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = private_project(&ctx);
+    let members = members(&ctx);
+    let (_, proof) = tree(&ctx.env, &members);
+
+    sac.mint(&members[0], &100);
+    ctx.client
+        .deposit_with_proof(&project.id, &members[0], &token, &100, &proof);
+}
+
+#[test]
+fn test_batch_whitelist_admits_donors() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = private_project(&ctx);
+    let members = members(&ctx);
+    ctx.client.add_to_whitelist_batch(
+        &ctx.manager,
+        &project.id,
+        &Vec::from_array(&ctx.env, members.clone()),
+    );
+
+    for member in members.iter() {
+        sac.mint(member, &10);
+        ctx.client.deposit(&project.id, member, &token, &10);
+    }
+    assert_eq!(ctx.client.get_balance(&project.id, &token), 40);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #72)")]
+fn test_batch_removal_blocks_deposits() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = private_project(&ctx);
+    let members = members(&ctx);
+    let batch = Vec::from_array(&ctx.env, members.clone());
+    ctx.client
+        .add_to_whitelist_batch(&ctx.manager, &project.id, &batch);
+    ctx.client
+        .remove_from_whitelist_batch(&ctx.manager, &project.id, &batch);
+
+    sac.mint(&members[0], &10);
+    ctx.client.deposit(&project.id, &members[0], &token, &10);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #68)")]
+fn test_oversized_batch_rejected() {
+    let ctx = TestContext::new();
+    let (project, _, _) = private_project(&ctx);
+    let mut batch = Vec::new(&ctx.env);
+    for _ in 0..51 {
+        batch.push_back(ctx.generate_address());
+    }
+
+    ctx.client
+        .add_to_whitelist_batch(&ctx.manager, &project.id, &batch);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_stranger_cannot_set_root() {
+    let ctx = TestContext::new();
+    let (project, _, _) = private_project(&ctx);
+
+    ctx.client.set_merkle_root(
+        &ctx.generate_address(),
+        &project.id,
+        &Some(BytesN::from_array(&ctx.env, &[1u8; 32])),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #72)")]
+fn test_pledge_on_private_project_requires_allowlist() {
+    let ctx = TestContext::new();
+    let (project, token, _) = private_project(&ctx);
+
+    ctx.client.create_recurring_pledge(
+        &ctx.generate_address(),
+        &project.id,
+        &token,
+        &100,
+        &3_600,
+        &5,
+    );
+}
+
+#[test]
+fn test_member_pledges_with_proof() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = private_project(&ctx);
+    let members = members(&ctx);
+    let (root, proof) = tree(&ctx.env, &members);
+    ctx.client
+        .set_merkle_root(&ctx.manager, &project.id, &Some(root));
+
+    sac.mint(&members[0], &500);
+    let expiration = ctx.env.ledger().sequence() + 100_000;
+    token::Client::new(&ctx.env, &token).approve(
+        &members[0],
+        &ctx.client.address,
+        &500,
+        &expiration,
+    );
+    ctx.client.create_pledge_with_proof(
+        &members[0],
+        &project.id,
+        &token,
+        &100,
+        &3_600,
+        &5,
+        &proof,
+    );
+
+    assert_eq!(ctx.client.collect_pledges(&project.id), 1);
+    assert_eq!(ctx.client.get_balance(&project.id, &token), 100);
+}
+
+#[test]
+fn test_rotated_root_stops_pledge_collection() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = private_project(&ctx);
+    let members = members(&ctx);
+    let (root, proof) = tree(&ctx.env, &members);
+    ctx.client
+        .set_merkle_root(&ctx.manager, &project.id, &Some(root));
+
+    sac.mint(&members[0], &500);
+    let expiration = ctx.env.ledger().sequence() + 100_000;
+    token::Client::new(&ctx.env, &token).approve(
+        &members[0],
+        &ctx.client.address,
+        &500,
+        &expiration,
+    );
+    ctx.client.create_pledge_with_proof(
+        &members[0],
+        &project.id,
+        &token,
+        &100,
+        &3_600,
+        &5,
+        &proof,
+    );
+    ctx.client.set_merkle_root(
+        &ctx.manager,
+        &project.id,
+        &Some(BytesN::from_array(&ctx.env, &[9u8; 32])),
+    );
+
+    assert_eq!(ctx.client.collect_pledges(&project.id), 0);
+    assert_eq!(ctx.client.get_balance(&project.id, &token), 0);
+}
@@ -0,0 +1,100 @@
+extern crate std;
+
+use soroban_sdk::Bytes;
+
+use crate::{test_utils::TestContext, Role};
+
+fn cid(ctx: &TestContext, n: u8) -> Bytes {
+    let mut uri = Bytes::from_slice(&ctx.env, b"bafy-v");
+    uri.push_back(b'0' + n);
+    uri
+}
+
+#[test]
+fn test_update_replaces_current_metadata() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+
+    ctx.jump_time(100);
+    ctx.client
+        .update_metadata(&ctx.manager, &project.id, &cid(&ctx, 1));
+
+    assert_eq!(ctx.client.get_project_metadata(&project.id), cid(&ctx, 1));
+    let history = ctx.client.get_metadata_history(&project.id);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().metadata_uri, ctx.dummy_metadata_uri());
+    assert_eq!(history.get(0).unwrap().updated_at, 0);
+    assert_eq!(history.get(1).unwrap().version, 1);
+    assert_eq!(history.get(1).unwrap().updated_at, ctx.env.ledger().timestamp());
+}
+
+#[test]
+fn test_never_updated_project_has_registration_entry() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+
+    let history = ctx.client.get_metadata_history(&project.id);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().version, 0);
+}
+
+#[test]
+fn test_history_keeps_latest_versions() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+
+    for n in 1..=9 {
+        ctx.client
+            .update_metadata(&ctx.manager, &project.id, &cid(&ctx, n));
+    }
+    ctx.client
+        .update_metadata(&ctx.manager, &project.id, &cid(&ctx, 0));
+
+    let history = ctx.client.get_metadata_history(&project.id);
+    assert_eq!(history.len(), 10);
+    assert_eq!(history.get(0).unwrap().version, 1);
+    assert_eq!(history.get(9).unwrap().version, 10);
+}
+
+#[test]
+fn test_admin_can_update_metadata() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+    let (project, _, _) = ctx.setup_project(1_000);
+
+    ctx.client.update_metadata(&admin, &project.id, &cid(&ctx, 1));
+    assert_eq!(ctx.client.get_project(&project.id).metadata_uri, cid(&ctx, 1));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_stranger_cannot_update_metadata() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+
+    ctx.client
+        .update_metadata(&ctx.generate_address(), &project.id, &cid(&ctx, 1));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #15)")]
+fn test_completed_project_metadata_is_frozen() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.client
+        .update_metadata(&ctx.manager, &project.id, &cid(&ctx, 1));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #26)")]
+fn test_empty_metadata_rejected() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+
+    ctx.client
+        .update_metadata(&ctx.manager, &project.id, &Bytes::new(&ctx.env));
+}
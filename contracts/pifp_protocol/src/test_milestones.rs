@@ -0,0 +1,203 @@
+extern crate std;
+
+use soroban_sdk::{BytesN, Env, Vec};
+
+use crate::{test_utils::TestContext, Milestone, ProjectStatus};
+
+fn milestone(env: &Env, seed: u8, share_bps: u32) -> Milestone {
+    Milestone {
+        proof_hash: BytesN::from_array(env, &[seed; 32]),
+        share_bps,
+    }
+}
+
+fn three_milestones(env: &Env) -> Vec<Milestone> {
+    Vec::from_array(
+        env,
+        [
+            milestone(env, 1, 2_000),
+            milestone(env, 2, 3_000),
+            milestone(env, 3, 5_000),
+        ],
+    )
+}
+
+#[test]
+fn test_register_project_stores_milestones() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+
+    let project = ctx.register_project_with_milestones(&tokens, 1_000, &three_milestones(&ctx.env));
+
+    assert_eq!(project.milestones.len(), 3);
+    assert_eq!(project.released_milestones, 0);
+    assert_eq!(ctx.client.get_project(&project.id).milestones, project.milestones);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #5)")]
+fn test_register_project_rejects_shares_not_summing_to_total() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let milestones = Vec::from_array(
+        &ctx.env,
+        [milestone(&ctx.env, 1, 4_000), milestone(&ctx.env, 2, 4_000)],
+    );
+
+    ctx.register_project_with_milestones(&tokens, 1_000, &milestones);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #5)")]
+fn test_register_project_rejects_zero_share_milestone() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let milestones = Vec::from_array(
+        &ctx.env,
+        [milestone(&ctx.env, 1, 10_000), milestone(&ctx.env, 2, 0)],
+    );
+
+    ctx.register_project_with_milestones(&tokens, 1_000, &milestones);
+}
+
+#[test]
+fn test_verify_milestone_releases_only_its_tranche() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let project = ctx.register_project_with_milestones(&tokens, 1_000, &three_milestones(&ctx.env));
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_000);
+
+    ctx.client.verify_milestone(
+        &ctx.oracle,
+        &project.id,
+        &0,
+        &BytesN::from_array(&ctx.env, &[1u8; 32]),
+    );
+
+    assert_eq!(token.balance(&ctx.manager), 200);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 800);
+
+    let updated = ctx.client.get_project(&project.id);
+    assert!(updated.is_milestone_released(0));
+    assert!(!updated.is_milestone_released(1));
+    assert_eq!(updated.status, ProjectStatus::Active);
+}
+
+#[test]
+fn test_all_milestones_released_completes_project() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let project = ctx.register_project_with_milestones(&tokens, 1_000, &three_milestones(&ctx.env));
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_000);
+
+    // Out-of-order release is allowed; each tranche is relative to what is left.
+    ctx.client.verify_milestone(&ctx.oracle, &project.id, &2, &BytesN::from_array(&ctx.env, &[3u8; 32]));
+    assert_eq!(token.balance(&ctx.manager), 500);
+    ctx.client.verify_milestone(&ctx.oracle, &project.id, &0, &BytesN::from_array(&ctx.env, &[1u8; 32]));
+    assert_eq!(token.balance(&ctx.manager), 700);
+    ctx.client.verify_milestone(&ctx.oracle, &project.id, &1, &BytesN::from_array(&ctx.env, &[2u8; 32]));
+
+    assert_eq!(token.balance(&ctx.manager), 1_000);
+    assert_eq!(token.balance(&ctx.client.address), 0);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn test_verify_milestone_twice_fails() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let project = ctx.register_project_with_milestones(&tokens, 1_000, &three_milestones(&ctx.env));
+    let proof = BytesN::from_array(&ctx.env, &[1u8; 32]);
+
+    ctx.client.verify_milestone(&ctx.oracle, &project.id, &0, &proof);
+    ctx.client.verify_milestone(&ctx.oracle, &project.id, &0, &proof);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #2)")]
+fn test_verify_milestone_unknown_index_fails() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let project = ctx.register_project_with_milestones(&tokens, 1_000, &three_milestones(&ctx.env));
+
+    ctx.client.verify_milestone(&ctx.oracle, &project.id, &3, &ctx.dummy_proof());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_verify_milestone_wrong_proof_fails() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let project = ctx.register_project_with_milestones(&tokens, 1_000, &three_milestones(&ctx.env));
+
+    // Milestone 0's proof submitted for milestone 1.
+    ctx.client.verify_milestone(&ctx.oracle, &project.id, &1, &BytesN::from_array(&ctx.env, &[1u8; 32]));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #5)")]
+fn test_verify_and_release_rejects_milestone_project() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let project = ctx.register_project_with_milestones(&tokens, 1_000, &three_milestones(&ctx.env));
+
+    ctx.client.verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+fn test_refund_after_partial_release_is_pro_rata() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let project = ctx.register_project_with_milestones(&tokens, 1_000, &three_milestones(&ctx.env));
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_000);
+    ctx.client.verify_milestone(&ctx.oracle, &project.id, &0, &BytesN::from_array(&ctx.env, &[1u8; 32]));
+
+    ctx.jump_time(86_401);
+    ctx.client.refund(&donator, &project.id, &token.address);
+
+    // 20 % was released to the creator; the remaining 80 % goes back.
+    assert_eq!(token.balance(&donator), 800);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #75)")]
+fn test_deposit_after_partial_release_fails() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let project = ctx.register_project_with_milestones(&tokens, 1_000, &three_milestones(&ctx.env));
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_000);
+    ctx.client.verify_milestone(&ctx.oracle, &project.id, &0, &BytesN::from_array(&ctx.env, &[1u8; 32]));
+
+    // A late donor would only get 80 % back on refund, so the deposit is refused.
+    let late = ctx.generate_address();
+    sac.mint(&late, &500);
+    ctx.client.deposit(&project.id, &late, &token.address, &500);
+}
@@ -0,0 +1,91 @@
+extern crate std;
+
+use soroban_sdk::Vec;
+
+use crate::{test_utils::TestContext, ProjectStatus};
+
+#[test]
+fn test_second_token_ignored_without_rates() {
+    let ctx = TestContext::new();
+    let (usdc, _) = ctx.create_token();
+    let (xlm, xlm_sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [usdc.address.clone(), xlm.address.clone()]);
+    let project = ctx.register_project(&tokens, 1_000);
+
+    let donator = ctx.generate_address();
+    xlm_sac.mint(&donator, &50_000);
+    ctx.client.deposit(&project.id, &donator, &xlm.address, &50_000);
+
+    assert_eq!(ctx.client.get_funded_value(&project.id), 0);
+    assert_eq!(ctx.client.get_project(&project.id).status, ProjectStatus::Funding);
+}
+
+#[test]
+fn test_weighted_tokens_reach_goal_together() {
+    let ctx = TestContext::new();
+    let (usdc, usdc_sac) = ctx.create_token();
+    let (xlm, xlm_sac) = ctx.create_token();
+    ctx.client.set_token_rate(&ctx.admin, &usdc.address, &10);
+    ctx.client.set_token_rate(&ctx.admin, &xlm.address, &1);
+
+    let tokens = Vec::from_array(&ctx.env, [usdc.address.clone(), xlm.address.clone()]);
+    let project = ctx.register_project(&tokens, 1_000);
+
+    let donator = ctx.generate_address();
+    usdc_sac.mint(&donator, &600);
+    xlm_sac.mint(&donator, &4_000);
+
+    ctx.client.deposit(&project.id, &donator, &usdc.address, &600);
+    assert_eq!(ctx.client.get_project(&project.id).status, ProjectStatus::Funding);
+
+    // 4 000 XLM at a 1:10 rate is worth 400 USDC, completing the goal.
+    ctx.client.deposit(&project.id, &donator, &xlm.address, &4_000);
+    assert_eq!(ctx.client.get_funded_value(&project.id), 1_000);
+    assert_eq!(ctx.client.get_project(&project.id).status, ProjectStatus::Active);
+}
+
+#[test]
+fn test_token_without_rate_not_counted() {
+    let ctx = TestContext::new();
+    let (usdc, usdc_sac) = ctx.create_token();
+    let (other, other_sac) = ctx.create_token();
+    ctx.client.set_token_rate(&ctx.admin, &usdc.address, &1);
+
+    let tokens = Vec::from_array(&ctx.env, [usdc.address.clone(), other.address.clone()]);
+    let project = ctx.register_project(&tokens, 1_000);
+
+    let donator = ctx.generate_address();
+    usdc_sac.mint(&donator, &500);
+    other_sac.mint(&donator, &5_000);
+    ctx.client.deposit(&project.id, &donator, &usdc.address, &500);
+    ctx.client.deposit(&project.id, &donator, &other.address, &5_000);
+
+    assert_eq!(ctx.client.get_funded_value(&project.id), 500);
+    assert_eq!(ctx.client.get_project(&project.id).status, ProjectStatus::Funding);
+}
+
+#[test]
+fn test_set_token_rate_updates_value() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+
+    assert_eq!(ctx.client.get_token_rate(&token.address), None);
+    ctx.client.set_token_rate(&ctx.admin, &token.address, &7);
+    assert_eq!(ctx.client.get_token_rate(&token.address), Some(7));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #36)")]
+fn test_zero_rate_rejected() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    ctx.client.set_token_rate(&ctx.admin, &token.address, &0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_non_admin_cannot_set_rate() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    ctx.client.set_token_rate(&ctx.oracle, &token.address, &1);
+}
@@ -0,0 +1,211 @@
+extern crate std;
+
+use soroban_sdk::{Address, Vec};
+
+use crate::{test_utils::TestContext, Beneficiary};
+
+fn beneficiary(recipient: &Address, share_bps: u32) -> Beneficiary {
+    Beneficiary {
+        recipient: recipient.clone(),
+        share_bps,
+    }
+}
+
+#[test]
+fn test_two_step_ownership_transfer() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+    let new_owner = ctx.generate_address();
+
+    ctx.client
+        .transfer_project_ownership(&ctx.manager, &project.id, &new_owner);
+    // Nothing changes until the new owner accepts.
+    assert_eq!(ctx.client.get_project(&project.id).creator, ctx.manager);
+    assert_eq!(ctx.client.get_pending_owner(&project.id), Some(new_owner.clone()));
+
+    ctx.client.accept_project_ownership(&new_owner, &project.id);
+
+    assert_eq!(ctx.client.get_project(&project.id).creator, new_owner);
+    assert_eq!(ctx.client.get_pending_owner(&project.id), None);
+    assert_eq!(
+        ctx.client.list_projects_by_creator(&new_owner, &0, &10).len(),
+        1
+    );
+    assert_eq!(
+        ctx.client.list_projects_by_creator(&ctx.manager, &0, &10).len(),
+        0
+    );
+}
+
+#[test]
+fn test_transfer_back_lists_project_once() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+    let other = ctx.generate_address();
+
+    ctx.client
+        .transfer_project_ownership(&ctx.manager, &project.id, &other);
+    ctx.client.accept_project_ownership(&other, &project.id);
+    ctx.client
+        .transfer_project_ownership(&other, &project.id, &ctx.manager);
+    ctx.client.accept_project_ownership(&ctx.manager, &project.id);
+
+    assert_eq!(ctx.client.get_creator_project_count(&ctx.manager), 1);
+    assert_eq!(ctx.client.get_creator_project_count(&other), 0);
+    let listed = ctx.client.list_projects_by_creator(&ctx.manager, &0, &10);
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed.get(0).unwrap().id, project.id);
+}
+
+#[test]
+fn test_transfer_fills_the_freed_listing_slot() {
+    let ctx = TestContext::new();
+    let (first, token, _) = ctx.setup_project(1_000);
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let second = ctx.register_project(&tokens, 1_000);
+    let third = ctx.register_project(&tokens, 1_000);
+    let new_owner = ctx.generate_address();
+
+    ctx.client
+        .transfer_project_ownership(&ctx.manager, &first.id, &new_owner);
+    ctx.client.accept_project_ownership(&new_owner, &first.id);
+
+    let listed = ctx.client.list_projects_by_creator(&ctx.manager, &0, &10);
+    assert_eq!(listed.len(), 2);
+    assert_eq!(listed.get(0).unwrap().id, third.id);
+    assert_eq!(listed.get(1).unwrap().id, second.id);
+}
+
+#[test]
+fn test_accepting_ownership_clears_beneficiaries() {
+    let ctx = TestContext::new();
+    let treasury = ctx.generate_address();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    ctx.client.set_beneficiaries(
+        &ctx.manager,
+        &project.id,
+        &Vec::from_array(&ctx.env, [beneficiary(&treasury, 10_000)]),
+    );
+    let new_owner = ctx.generate_address();
+
+    ctx.client
+        .transfer_project_ownership(&ctx.manager, &project.id, &new_owner);
+    ctx.client.accept_project_ownership(&new_owner, &project.id);
+    assert_eq!(ctx.client.get_beneficiaries(&project.id).len(), 0);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    assert_eq!(token.balance(&new_owner), 1_000);
+    assert_eq!(token.balance(&treasury), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #66)")]
+fn test_only_pending_owner_can_accept() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+    ctx.client
+        .transfer_project_ownership(&ctx.manager, &project.id, &ctx.generate_address());
+
+    ctx.client
+        .accept_project_ownership(&ctx.generate_address(), &project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_stranger_cannot_propose_transfer() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+    let stranger = ctx.generate_address();
+
+    ctx.client
+        .transfer_project_ownership(&stranger, &project.id, &stranger);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_admin_cannot_propose_transfer() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+
+    ctx.client
+        .transfer_project_ownership(&ctx.admin, &project.id, &ctx.admin);
+}
+
+#[test]
+fn test_release_is_split_between_beneficiaries() {
+    let ctx = TestContext::new();
+    let treasury = ctx.generate_address();
+    let partner = ctx.generate_address();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    ctx.client.set_beneficiaries(
+        &ctx.manager,
+        &project.id,
+        &Vec::from_array(
+            &ctx.env,
+            [beneficiary(&treasury, 7_000), beneficiary(&partner, 3_000)],
+        ),
+    );
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_001);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_001);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    assert_eq!(token.balance(&treasury), 700);
+    assert_eq!(token.balance(&partner), 301);
+    assert_eq!(token.balance(&ctx.manager), 0);
+}
+
+#[test]
+fn test_reclaimed_funds_go_to_beneficiaries() {
+    let ctx = TestContext::new();
+    let treasury = ctx.generate_address();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    ctx.client.set_beneficiaries(
+        &ctx.manager,
+        &project.id,
+        &Vec::from_array(&ctx.env, [beneficiary(&treasury, 10_000)]),
+    );
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+    ctx.jump_time(86_401);
+    ctx.client.expire_project(&project.id);
+    ctx.jump_time(6 * 30 * 24 * 60 * 60);
+
+    ctx.client.reclaim_expired_funds(&ctx.manager, &project.id);
+    assert_eq!(token.balance(&treasury), 100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #65)")]
+fn test_beneficiary_shares_must_sum_to_total() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+
+    ctx.client.set_beneficiaries(
+        &ctx.manager,
+        &project.id,
+        &Vec::from_array(&ctx.env, [beneficiary(&ctx.generate_address(), 5_000)]),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_admin_cannot_redirect_payouts() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+
+    ctx.client.set_beneficiaries(
+        &ctx.admin,
+        &project.id,
+        &Vec::from_array(&ctx.env, [beneficiary(&ctx.admin, 10_000)]),
+    );
+}
@@ -0,0 +1,83 @@
+extern crate std;
+
+use soroban_sdk::Vec;
+
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_process_refunds_pays_donors_in_batches() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donors: std::vec::Vec<_> = (0..3).map(|_| ctx.generate_address()).collect();
+    for donator in &donors {
+        sac.mint(donator, &100);
+        ctx.client.deposit(&project.id, donator, &token.address, &100);
+    }
+    // Donating again does not list the donor twice.
+    sac.mint(&donors[0], &50);
+    ctx.client.deposit(&project.id, &donors[0], &token.address, &50);
+
+    ctx.jump_time(86_401);
+    assert!(!ctx.client.process_refunds(&project.id, &2));
+    assert_eq!(token.balance(&donors[0]), 150);
+    assert_eq!(token.balance(&donors[1]), 100);
+    assert_eq!(token.balance(&donors[2]), 0);
+
+    assert!(ctx.client.process_refunds(&project.id, &2));
+    assert_eq!(token.balance(&donors[2]), 100);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+}
+
+#[test]
+fn test_refund_all_covers_every_token() {
+    let ctx = TestContext::new();
+    let (token_a, sac_a) = ctx.create_token();
+    let (token_b, sac_b) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token_a.address.clone(), token_b.address.clone()]);
+    let project = ctx.register_project(&tokens, 10_000);
+
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &100);
+    sac_b.mint(&donator, &200);
+    ctx.client.deposit(&project.id, &donator, &token_a.address, &100);
+    ctx.client.deposit(&project.id, &donator, &token_b.address, &200);
+
+    ctx.jump_time(86_401);
+    ctx.client.refund_all(&donator, &project.id);
+
+    assert_eq!(token_a.balance(&donator), 100);
+    assert_eq!(token_b.balance(&donator), 200);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_refund_all_without_balance_rejected() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(10_000);
+
+    ctx.jump_time(86_401);
+    ctx.client.refund_all(&ctx.generate_address(), &project.id);
+}
+
+#[test]
+fn test_claimed_donors_are_skipped() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+
+    ctx.jump_time(86_401);
+    ctx.client.refund(&donator, &project.id, &token.address);
+    assert!(ctx.client.process_refunds(&project.id, &10));
+
+    assert_eq!(token.balance(&donator), 100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #21)")]
+fn test_process_refunds_requires_refundable_project() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(10_000);
+    ctx.client.process_refunds(&project.id, &10);
+}
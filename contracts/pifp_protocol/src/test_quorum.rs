@@ -0,0 +1,227 @@
+extern crate std;
+
+use soroban_sdk::{Address, BytesN, Vec};
+
+use crate::{test_utils::TestContext, Error, GovAction, Milestone, ProjectStatus, Role};
+
+fn add_oracles(ctx: &TestContext, count: usize) -> std::vec::Vec<Address> {
+    let mut oracles = std::vec![ctx.oracle.clone()];
+    for _ in 1..count {
+        let oracle = ctx.generate_address();
+        ctx.client.grant_role(&ctx.admin, &oracle, &Role::Oracle);
+        oracles.push(oracle);
+    }
+    oracles
+}
+
+#[test]
+fn test_default_quorum_is_one() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_oracle_quorum(), 1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #29)")]
+fn test_zero_quorum_rejected() {
+    let ctx = TestContext::new();
+    ctx.client.set_oracle_quorum(&ctx.admin, &0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_non_admin_cannot_set_quorum() {
+    let ctx = TestContext::new();
+    ctx.client.set_oracle_quorum(&ctx.oracle, &2);
+}
+
+#[test]
+fn test_release_waits_for_quorum() {
+    let ctx = TestContext::new();
+    let oracles = add_oracles(&ctx, 3);
+    ctx.client.set_oracle_quorum(&ctx.admin, &2);
+
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_000);
+
+    let proof = ctx.dummy_proof();
+    ctx.client.submit_attestation(&oracles[0], &project.id, &proof);
+    assert_eq!(ctx.client.get_attestation_count(&project.id, &proof), 1);
+    assert_eq!(ctx.client.get_project(&project.id).status, ProjectStatus::Active);
+    assert_eq!(token.balance(&ctx.manager), 0);
+
+    ctx.client.verify_and_release(&oracles[1], &project.id, &proof);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+    assert_eq!(token.balance(&ctx.manager), 1_000);
+}
+
+#[test]
+fn test_mismatched_attestation_is_rejected() {
+    let ctx = TestContext::new();
+    let oracles = add_oracles(&ctx, 2);
+    ctx.client.set_oracle_quorum(&ctx.admin, &2);
+
+    let (project, _, _) = ctx.setup_project(1_000);
+    let proof = ctx.dummy_proof();
+    let bogus = BytesN::from_array(&ctx.env, &[0x11u8; 32]);
+
+    ctx.client.submit_attestation(&oracles[0], &project.id, &proof);
+    let result = ctx
+        .client
+        .try_submit_attestation(&oracles[1], &project.id, &bogus);
+    assert_eq!(result, Err(Ok(Error::VerificationFailed.into())));
+
+    assert_eq!(ctx.client.get_attestation_count(&project.id, &proof), 1);
+    assert_eq!(ctx.client.get_attestation_count(&project.id, &bogus), 0);
+    assert_eq!(ctx.client.get_project(&project.id).status, ProjectStatus::Funding);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn test_released_milestone_cannot_be_attested() {
+    let ctx = TestContext::new();
+    let oracles = add_oracles(&ctx, 2);
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let first = BytesN::from_array(&ctx.env, &[1u8; 32]);
+    let milestones = Vec::from_array(
+        &ctx.env,
+        [
+            Milestone {
+                proof_hash: first.clone(),
+                share_bps: 4_000,
+            },
+            Milestone {
+                proof_hash: BytesN::from_array(&ctx.env, &[2u8; 32]),
+                share_bps: 6_000,
+            },
+        ],
+    );
+    let project = ctx.register_project_with_milestones(&tokens, 1_000, &milestones);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_000);
+    ctx.client.submit_attestation(&oracles[0], &project.id, &first);
+    assert!(ctx.client.get_project(&project.id).is_milestone_released(0));
+
+    ctx.client.submit_attestation(&oracles[1], &project.id, &first);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #28)")]
+fn test_same_oracle_cannot_attest_twice() {
+    let ctx = TestContext::new();
+    ctx.client.set_oracle_quorum(&ctx.admin, &2);
+
+    let (project, _, _) = ctx.setup_project(1_000);
+    let proof = ctx.dummy_proof();
+
+    ctx.client.submit_attestation(&ctx.oracle, &project.id, &proof);
+    ctx.client.submit_attestation(&ctx.oracle, &project.id, &proof);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_non_oracle_cannot_attest() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+    let stranger = ctx.generate_address();
+
+    ctx.client.submit_attestation(&stranger, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+fn test_milestone_release_waits_for_quorum() {
+    let ctx = TestContext::new();
+    let oracles = add_oracles(&ctx, 2);
+    ctx.client.set_oracle_quorum(&ctx.admin, &2);
+
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let first = BytesN::from_array(&ctx.env, &[1u8; 32]);
+    let milestones = Vec::from_array(
+        &ctx.env,
+        [
+            Milestone {
+                proof_hash: first.clone(),
+                share_bps: 4_000,
+            },
+            Milestone {
+                proof_hash: BytesN::from_array(&ctx.env, &[2u8; 32]),
+                share_bps: 6_000,
+            },
+        ],
+    );
+    let project = ctx.register_project_with_milestones(&tokens, 1_000, &milestones);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_000);
+
+    ctx.client.verify_milestone(&oracles[0], &project.id, &0, &first);
+    assert_eq!(token.balance(&ctx.manager), 0);
+
+    ctx.client.submit_attestation(&oracles[1], &project.id, &first);
+    assert_eq!(token.balance(&ctx.manager), 400);
+    assert!(ctx.client.get_project(&project.id).is_milestone_released(0));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_admin_cannot_set_quorum() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+
+    ctx.client.set_oracle_quorum(&admin, &2);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #42)")]
+fn test_quorum_change_requires_timelock_once_projects_exist() {
+    let ctx = TestContext::new();
+    ctx.setup_project(1_000);
+
+    ctx.client.set_oracle_quorum(&ctx.admin, &1);
+}
+
+#[test]
+fn test_quorum_change_through_governance() {
+    let ctx = TestContext::new();
+    ctx.setup_project(1_000);
+
+    ctx.govern(&GovAction::SetOracleQuorum(3));
+    assert_eq!(ctx.client.get_oracle_quorum(), 3);
+}
+
+#[test]
+fn test_revoked_oracle_attestation_stops_counting() {
+    let ctx = TestContext::new();
+    let oracles = add_oracles(&ctx, 3);
+    ctx.client.set_oracle_quorum(&ctx.admin, &2);
+
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_000);
+
+    let proof = ctx.dummy_proof();
+    ctx.client.submit_attestation(&oracles[0], &project.id, &proof);
+    ctx.client.revoke_role(&ctx.admin, &oracles[0]);
+
+    // The revoked oracle's attestation no longer counts towards the quorum.
+    ctx.client.submit_attestation(&oracles[1], &project.id, &proof);
+    assert_eq!(ctx.client.get_project(&project.id).status, ProjectStatus::Active);
+    assert_eq!(token.balance(&ctx.manager), 0);
+
+    ctx.client.submit_attestation(&oracles[2], &project.id, &proof);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
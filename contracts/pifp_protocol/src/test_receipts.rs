@@ -0,0 +1,135 @@
+extern crate std;
+
+use soroban_sdk::{BytesN, String};
+
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_deposit_issues_receipt() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+
+    ctx.client.deposit(&project.id, &donator, &token.address, &200);
+
+    let receipts = ctx.client.get_receipts(&donator, &0, &10);
+    assert_eq!(receipts.len(), 1);
+    let receipt = receipts.get(0).unwrap();
+    assert_eq!(receipt.project_id, project.id);
+    assert_eq!(receipt.token, token.address);
+    assert_eq!(receipt.amount, 200);
+    assert_eq!(receipt.first_ledger, ctx.env.ledger().sequence());
+    assert_eq!(ctx.client.balance(&donator), 1);
+}
+
+#[test]
+fn test_repeat_donations_update_one_receipt() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client.deposit(&project.id, &donator, &token.address, &200);
+    let first_ledger = ctx.env.ledger().sequence();
+
+    ctx.jump_ledgers(5);
+    ctx.client.deposit(&project.id, &donator, &token.address, &300);
+
+    let receipts = ctx.client.get_receipts(&donator, &0, &10);
+    assert_eq!(receipts.len(), 1);
+    let receipt = receipts.get(0).unwrap();
+    assert_eq!(receipt.amount, 500);
+    assert_eq!(receipt.first_ledger, first_ledger);
+    assert_eq!(receipt.last_ledger, first_ledger + 5);
+}
+
+#[test]
+fn test_receipts_are_paginated_per_project() {
+    let ctx = TestContext::new();
+    let donator = ctx.generate_address();
+    for _ in 0..3 {
+        let (project, token, sac) = ctx.setup_project(1_000);
+        sac.mint(&donator, &100);
+        ctx.client.deposit(&project.id, &donator, &token.address, &100);
+    }
+
+    assert_eq!(ctx.client.balance(&donator), 3);
+    let page = ctx.client.get_receipts(&donator, &2, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().project_id, 2);
+}
+
+#[test]
+fn test_receipt_survives_refund() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+
+    ctx.jump_time(86_401);
+    ctx.client.refund(&donator, &project.id, &token.address);
+
+    let receipt = ctx.client.get_receipts(&donator, &0, &10).get(0).unwrap();
+    assert_eq!(receipt.amount, 100);
+    assert_eq!(receipt.refunded, 100);
+    assert_eq!(ctx.client.balance(&donator), 1);
+}
+
+#[test]
+fn test_withdrawal_is_recorded_on_receipt() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &300);
+    ctx.client.deposit(&project.id, &donator, &token.address, &300);
+
+    ctx.client
+        .withdraw_pledge(&donator, &project.id, &token.address, &120);
+
+    let receipt = ctx.client.get_receipts(&donator, &0, &10).get(0).unwrap();
+    assert_eq!(receipt.amount, 300);
+    assert_eq!(receipt.refunded, 120);
+}
+
+#[test]
+fn test_pushed_refund_is_recorded_on_receipt() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+
+    ctx.jump_time(86_401);
+    ctx.client.expire_project(&project.id);
+    ctx.client.process_refunds(&project.id, &10);
+
+    let receipt = ctx.client.get_receipts(&donator, &0, &10).get(0).unwrap();
+    assert_eq!(receipt.refunded, 100);
+}
+
+#[test]
+fn test_committed_donation_has_no_receipt_until_revealed() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let funder = ctx.generate_address();
+    sac.mint(&funder, &100);
+    let commitment = BytesN::from_array(&ctx.env, &[7u8; 32]);
+
+    ctx.client
+        .deposit_committed(&project.id, &funder, &token.address, &100, &commitment);
+
+    assert_eq!(ctx.client.balance(&funder), 0);
+}
+
+#[test]
+fn test_sep41_metadata() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.decimals(), 0);
+    assert_eq!(
+        ctx.client.name(),
+        String::from_str(&ctx.env, "PIFP Donation Receipt")
+    );
+    assert_eq!(ctx.client.symbol(), String::from_str(&ctx.env, "PIFPR"));
+    assert_eq!(ctx.client.balance(&ctx.generate_address()), 0);
+}
@@ -0,0 +1,188 @@
+extern crate std;
+
+use soroban_sdk::{token, Address};
+
+use crate::{test_utils::TestContext, DonationLimits, GovAction, OverfundPolicy, PauseFlags};
+
+const HOUR: u64 = 3_600;
+
+fn approve(ctx: &TestContext, token: &token::Client, donator: &Address, amount: i128) {
+    let expiration = ctx.env.ledger().sequence() + 100_000;
+    token.approve(donator, &ctx.client.address, &amount, &expiration);
+}
+
+#[test]
+fn test_instalments_are_booked_like_deposits() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    approve(&ctx, &token, &donator, 1_000);
+
+    ctx.client
+        .create_recurring_pledge(&donator, &project.id, &token.address, &100, &HOUR, &5);
+
+    assert_eq!(ctx.client.collect_pledges(&project.id), 1);
+    // Nothing new is due within the same period.
+    assert_eq!(ctx.client.collect_pledges(&project.id), 0);
+
+    ctx.jump_time(HOUR);
+    assert_eq!(ctx.client.collect_pledges(&project.id), 1);
+
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 200);
+    assert_eq!(token.balance(&donator), 800);
+    assert_eq!(ctx.client.get_project(&project.id).donation_count, 1);
+}
+
+#[test]
+fn test_overdue_instalments_are_capped_at_max_periods() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    approve(&ctx, &token, &donator, 1_000);
+
+    let id = ctx
+        .client
+        .create_recurring_pledge(&donator, &project.id, &token.address, &100, &HOUR, &3);
+
+    ctx.jump_time(10 * HOUR);
+    ctx.client.collect_pledges(&project.id);
+
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 300);
+    assert_eq!(ctx.client.get_recurring_pledge(&id), None);
+}
+
+#[test]
+fn test_hard_cap_collects_only_whole_instalments() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(250);
+    ctx.client.set_donation_limits(
+        &ctx.manager,
+        &project.id,
+        &DonationLimits {
+            min_donation: 0,
+            max_per_donor: 0,
+            hard_cap: 250,
+            overflow: OverfundPolicy::RefundExcess,
+        },
+    );
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    approve(&ctx, &token, &donator, 1_000);
+
+    let id = ctx
+        .client
+        .create_recurring_pledge(&donator, &project.id, &token.address, &100, &HOUR, &5);
+
+    // Four instalments are due but only 250 fits under the cap: two whole
+    // instalments are collected and the schedule advances by two periods.
+    ctx.jump_time(3 * HOUR);
+    assert_eq!(ctx.client.collect_pledges(&project.id), 1);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 200);
+    let pledge = ctx.client.get_recurring_pledge(&id).unwrap();
+    assert_eq!(pledge.collected_periods, 2);
+    assert_eq!(pledge.next_due, ctx.env.ledger().timestamp() - HOUR);
+
+    // The remaining headroom cannot take a whole instalment.
+    assert_eq!(ctx.client.collect_pledges(&project.id), 0);
+    assert_eq!(ctx.client.get_recurring_pledge(&id), None);
+    assert_eq!(token.balance(&donator), 800);
+}
+
+#[test]
+fn test_pledge_lapses_when_allowance_runs_out() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    approve(&ctx, &token, &donator, 150);
+
+    let id = ctx
+        .client
+        .create_recurring_pledge(&donator, &project.id, &token.address, &100, &HOUR, &5);
+    ctx.client.collect_pledges(&project.id);
+
+    ctx.jump_time(HOUR);
+    assert_eq!(ctx.client.collect_pledges(&project.id), 0);
+    assert_eq!(ctx.client.get_recurring_pledge(&id), None);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+}
+
+#[test]
+fn test_cancelled_pledge_is_not_collected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    approve(&ctx, &token, &donator, 1_000);
+
+    let id = ctx
+        .client
+        .create_recurring_pledge(&donator, &project.id, &token.address, &100, &HOUR, &5);
+    ctx.client.cancel_recurring_pledge(&donator, &id);
+
+    assert_eq!(ctx.client.collect_pledges(&project.id), 0);
+    assert_eq!(token.balance(&donator), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_only_donor_can_cancel() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(1_000_000);
+    let donator = ctx.generate_address();
+
+    let id = ctx
+        .client
+        .create_recurring_pledge(&donator, &project.id, &token.address, &100, &HOUR, &5);
+    ctx.client
+        .cancel_recurring_pledge(&ctx.generate_address(), &id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #52)")]
+fn test_zero_period_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(1_000_000);
+    let donator = ctx.generate_address();
+
+    ctx.client
+        .create_recurring_pledge(&donator, &project.id, &token.address, &100, &0, &5);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #64)")]
+fn test_frozen_project_halts_collection() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    approve(&ctx, &token, &donator, 1_000);
+
+    ctx.client
+        .create_recurring_pledge(&donator, &project.id, &token.address, &100, &HOUR, &5);
+    ctx.client.freeze_project(&ctx.admin, &project.id);
+
+    ctx.client.collect_pledges(&project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #19)")]
+fn test_deposit_pause_halts_collection() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    approve(&ctx, &token, &donator, 1_000);
+
+    ctx.client
+        .create_recurring_pledge(&donator, &project.id, &token.address, &100, &HOUR, &5);
+    let flags = PauseFlags {
+        deposits: true,
+        ..PauseFlags::default()
+    };
+    ctx.govern(&GovAction::SetPauseFlags(flags));
+
+    ctx.client.collect_pledges(&project.id);
+}
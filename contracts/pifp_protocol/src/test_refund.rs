@@ -2,7 +2,7 @@ extern crate std;
 
 use soroban_sdk::{
     testutils::{Address as _, Ledger},
-    token, Address, Bytes, BytesN, Env,
+    token, Address, Bytes, BytesN, Env, Vec,
 };
 
 use crate::{PifpProtocol, PifpProtocolClient, ProjectStatus, Role};
@@ -25,8 +25,13 @@ fn setup_with_init() -> (Env, PifpProtocolClient<'static>, Address) {
     (env, client, super_admin)
 }
 
-fn create_token<'a>(env: &Env, admin: &Address) -> token::Client<'a> {
+fn create_token<'a>(
+    env: &Env,
+    client: &PifpProtocolClient,
+    admin: &Address,
+) -> token::Client<'a> {
     let addr = env.register_stellar_asset_contract_v2(admin.clone());
+    client.add_supported_token(admin, &addr.address(), &0, &7);
     token::Client::new(env, &addr.address())
 }
 
@@ -43,8 +48,7 @@ fn test_refund_success_after_expiry() {
     let (env, client, super_admin) = setup_with_init();
     let creator = Address::generate(&env);
     let donator = Address::generate(&env);
-    let token_admin = Address::generate(&env);
-    let token = create_token(&env, &token_admin);
+    let token = create_token(&env, &client, &super_admin);
     let deadline = env.ledger().timestamp() + 100;
 
     client.grant_role(&super_admin, &creator, &Role::ProjectManager);
@@ -56,6 +60,8 @@ fn test_refund_success_after_expiry() {
         &dummy_proof(&env),
         &dummy_metadata_uri(&env),
         &deadline,
+        &false,
+        &Vec::new(&env),
     );
 
     let token_sac = token::StellarAssetClient::new(&env, &token.address);
@@ -93,8 +99,7 @@ fn test_refund_fails_when_not_expired() {
     let (env, client, super_admin) = setup_with_init();
     let creator = Address::generate(&env);
     let donator = Address::generate(&env);
-    let token_admin = Address::generate(&env);
-    let token = create_token(&env, &token_admin);
+    let token = create_token(&env, &client, &super_admin);
     let deadline = env.ledger().timestamp() + 1000;
 
     client.grant_role(&super_admin, &creator, &Role::ProjectManager);
@@ -106,6 +111,8 @@ fn test_refund_fails_when_not_expired() {
         &dummy_proof(&env),
         &dummy_metadata_uri(&env),
         &deadline,
+        &false,
+        &Vec::new(&env),
     );
 
     let token_sac = token::StellarAssetClient::new(&env, &token.address);
@@ -121,8 +128,7 @@ fn test_refund_double_refund_fails() {
     let (env, client, super_admin) = setup_with_init();
     let creator = Address::generate(&env);
     let donator = Address::generate(&env);
-    let token_admin = Address::generate(&env);
-    let token = create_token(&env, &token_admin);
+    let token = create_token(&env, &client, &super_admin);
     let deadline = env.ledger().timestamp() + 100;
 
     client.grant_role(&super_admin, &creator, &Role::ProjectManager);
@@ -134,6 +140,8 @@ fn test_refund_double_refund_fails() {
         &dummy_proof(&env),
         &dummy_metadata_uri(&env),
         &deadline,
+        &false,
+        &Vec::new(&env),
     );
 
     let token_sac = token::StellarAssetClient::new(&env, &token.address);
@@ -156,8 +164,7 @@ fn test_refund_wrong_donator_fails() {
     let creator = Address::generate(&env);
     let donator = Address::generate(&env);
     let attacker = Address::generate(&env);
-    let token_admin = Address::generate(&env);
-    let token = create_token(&env, &token_admin);
+    let token = create_token(&env, &client, &super_admin);
     let deadline = env.ledger().timestamp() + 100;
 
     client.grant_role(&super_admin, &creator, &Role::ProjectManager);
@@ -169,6 +176,8 @@ fn test_refund_wrong_donator_fails() {
         &dummy_proof(&env),
         &dummy_metadata_uri(&env),
         &deadline,
+        &false,
+        &Vec::new(&env),
     );
 
     let token_sac = token::StellarAssetClient::new(&env, &token.address);
@@ -188,8 +197,7 @@ fn test_refund_success_after_cancellation() {
     let (env, client, super_admin) = setup_with_init();
     let creator = Address::generate(&env);
     let donator = Address::generate(&env);
-    let token_admin = Address::generate(&env);
-    let token = create_token(&env, &token_admin);
+    let token = create_token(&env, &client, &super_admin);
     let deadline = env.ledger().timestamp() + 1_000;
 
     client.grant_role(&super_admin, &creator, &Role::ProjectManager);
@@ -201,6 +209,8 @@ fn test_refund_success_after_cancellation() {
         &dummy_proof(&env),
         &dummy_metadata_uri(&env),
         &deadline,
+        &false,
+        &Vec::new(&env),
     );
 
     let token_sac = token::StellarAssetClient::new(&env, &token.address);
@@ -231,8 +241,7 @@ fn test_refund_distribution_after_cancellation_multi_donor() {
     let creator = Address::generate(&env);
     let donator_a = Address::generate(&env);
     let donator_b = Address::generate(&env);
-    let token_admin = Address::generate(&env);
-    let token = create_token(&env, &token_admin);
+    let token = create_token(&env, &client, &super_admin);
     let deadline = env.ledger().timestamp() + 1_000;
 
     client.grant_role(&super_admin, &creator, &Role::ProjectManager);
@@ -244,6 +253,8 @@ fn test_refund_distribution_after_cancellation_multi_donor() {
         &dummy_proof(&env),
         &dummy_metadata_uri(&env),
         &deadline,
+        &false,
+        &Vec::new(&env),
     );
 
     let token_sac = token::StellarAssetClient::new(&env, &token.address);
@@ -0,0 +1,101 @@
+extern crate std;
+
+use soroban_sdk::{BytesN, Env};
+
+use crate::{test_utils::TestContext, ProjectStatus, Role};
+
+fn reason(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[4u8; 32])
+}
+
+#[test]
+fn test_rejection_unlocks_refunds_before_deadline() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_000);
+
+    ctx.client
+        .reject_verification(&ctx.oracle, &project.id, &reason(&ctx.env));
+
+    let project = ctx.client.get_project(&project.id);
+    assert_eq!(project.status, ProjectStatus::Failed);
+    assert!(project.refund_expiry > ctx.env.ledger().timestamp());
+
+    ctx.client.refund(&donator, &project.id, &token.address);
+    assert_eq!(token.balance(&donator), 1_000);
+}
+
+#[test]
+fn test_rejection_waits_for_quorum() {
+    let ctx = TestContext::new();
+    ctx.client.set_oracle_quorum(&ctx.admin, &2);
+    let second = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &second, &Role::Oracle);
+    let (project, _, _) = ctx.setup_project(1_000);
+
+    ctx.client
+        .reject_verification(&ctx.oracle, &project.id, &reason(&ctx.env));
+    assert_eq!(ctx.client.get_rejection_count(&project.id), 1);
+    assert_eq!(ctx.client.get_project(&project.id).status, ProjectStatus::Funding);
+
+    ctx.client
+        .reject_verification(&second, &project.id, &reason(&ctx.env));
+    assert_eq!(ctx.client.get_project(&project.id).status, ProjectStatus::Failed);
+}
+
+#[test]
+fn test_revoked_oracle_rejection_stops_counting() {
+    let ctx = TestContext::new();
+    ctx.client.set_oracle_quorum(&ctx.admin, &2);
+    let second = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &second, &Role::Oracle);
+    let (project, _, _) = ctx.setup_project(1_000);
+
+    ctx.client
+        .reject_verification(&ctx.oracle, &project.id, &reason(&ctx.env));
+    ctx.client.revoke_role(&ctx.admin, &ctx.oracle);
+    assert_eq!(ctx.client.get_rejection_count(&project.id), 0);
+
+    // Only one rejecter still holds the Oracle role, short of the quorum.
+    ctx.client
+        .reject_verification(&second, &project.id, &reason(&ctx.env));
+    assert_eq!(ctx.client.get_rejection_count(&project.id), 1);
+    assert_eq!(ctx.client.get_project(&project.id).status, ProjectStatus::Funding);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #28)")]
+fn test_oracle_cannot_reject_twice() {
+    let ctx = TestContext::new();
+    ctx.client.set_oracle_quorum(&ctx.admin, &2);
+    let (project, _, _) = ctx.setup_project(1_000);
+
+    ctx.client
+        .reject_verification(&ctx.oracle, &project.id, &reason(&ctx.env));
+    ctx.client
+        .reject_verification(&ctx.oracle, &project.id, &reason(&ctx.env));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_failed_project_cannot_be_verified() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+    ctx.client
+        .reject_verification(&ctx.oracle, &project.id, &reason(&ctx.env));
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_only_oracle_can_reject() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+
+    ctx.client
+        .reject_verification(&ctx.manager, &project.id, &reason(&ctx.env));
+}
@@ -0,0 +1,142 @@
+extern crate std;
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{BytesN, Env};
+
+use crate::{test_utils::TestContext, ProjectStatus};
+
+fn signing_key() -> SigningKey {
+    SigningKey::from_bytes(&[7u8; 32])
+}
+
+fn register_key(ctx: &TestContext, key: &SigningKey) {
+    let public_key = BytesN::from_array(&ctx.env, &key.verifying_key().to_bytes());
+    ctx.client.set_oracle_key(&ctx.admin, &ctx.oracle, &public_key);
+}
+
+fn sign(
+    ctx: &TestContext,
+    key: &SigningKey,
+    project_id: u64,
+    proof_hash: &BytesN<32>,
+    nonce: u64,
+    expiry: u64,
+) -> BytesN<64> {
+    let payload = ctx
+        .client
+        .get_attestation_payload(&project_id, proof_hash, &nonce, &expiry);
+    let message: std::vec::Vec<u8> = payload.iter().collect();
+    BytesN::from_array(&ctx.env, &key.sign(&message).to_bytes())
+}
+
+fn expiry(env: &Env) -> u64 {
+    env.ledger().timestamp() + 600
+}
+
+#[test]
+fn test_relayed_signed_attestation_releases_funds() {
+    let ctx = TestContext::new();
+    let key = signing_key();
+    register_key(&ctx, &key);
+
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_000);
+
+    let proof = ctx.dummy_proof();
+    let expiry = expiry(&ctx.env);
+    let signature = sign(&ctx, &key, project.id, &proof, 1, expiry);
+
+    ctx.client
+        .submit_signed_attestation(&ctx.oracle, &project.id, &proof, &1, &expiry, &signature);
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+    assert_eq!(token.balance(&ctx.manager), 1_000);
+}
+
+#[test]
+#[should_panic]
+fn test_signature_for_other_project_rejected() {
+    let ctx = TestContext::new();
+    let key = signing_key();
+    register_key(&ctx, &key);
+
+    let (first, _, _) = ctx.setup_project(1_000);
+    let (second, _, _) = ctx.setup_project(1_000);
+
+    let proof = ctx.dummy_proof();
+    let expiry = expiry(&ctx.env);
+    let signature = sign(&ctx, &key, first.id, &proof, 1, expiry);
+
+    ctx.client
+        .submit_signed_attestation(&ctx.oracle, &second.id, &proof, &1, &expiry, &signature);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #31)")]
+fn test_nonce_cannot_be_reused() {
+    let ctx = TestContext::new();
+    let key = signing_key();
+    register_key(&ctx, &key);
+    ctx.client.set_oracle_quorum(&ctx.admin, &2);
+
+    let (first, _, _) = ctx.setup_project(1_000);
+    let (second, _, _) = ctx.setup_project(1_000);
+    let proof = ctx.dummy_proof();
+    let expiry = expiry(&ctx.env);
+
+    let signature = sign(&ctx, &key, first.id, &proof, 5, expiry);
+    ctx.client
+        .submit_signed_attestation(&ctx.oracle, &first.id, &proof, &5, &expiry, &signature);
+
+    let signature = sign(&ctx, &key, second.id, &proof, 5, expiry);
+    ctx.client
+        .submit_signed_attestation(&ctx.oracle, &second.id, &proof, &5, &expiry, &signature);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #32)")]
+fn test_expired_attestation_rejected() {
+    let ctx = TestContext::new();
+    let key = signing_key();
+    register_key(&ctx, &key);
+
+    let (project, _, _) = ctx.setup_project(1_000);
+    let proof = ctx.dummy_proof();
+    let expiry = expiry(&ctx.env);
+    let signature = sign(&ctx, &key, project.id, &proof, 1, expiry);
+
+    ctx.jump_time(601);
+    ctx.client
+        .submit_signed_attestation(&ctx.oracle, &project.id, &proof, &1, &expiry, &signature);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #30)")]
+fn test_oracle_without_key_rejected() {
+    let ctx = TestContext::new();
+    let key = signing_key();
+
+    let (project, _, _) = ctx.setup_project(1_000);
+    let proof = ctx.dummy_proof();
+    let expiry = expiry(&ctx.env);
+    let signature = sign(&ctx, &key, project.id, &proof, 1, expiry);
+
+    ctx.client
+        .submit_signed_attestation(&ctx.oracle, &project.id, &proof, &1, &expiry, &signature);
+}
+
+#[test]
+fn test_revoking_oracle_drops_key() {
+    let ctx = TestContext::new();
+    let key = signing_key();
+    register_key(&ctx, &key);
+    assert!(ctx.client.get_oracle_key(&ctx.oracle).is_some());
+
+    ctx.client.revoke_role(&ctx.admin, &ctx.oracle);
+    assert_eq!(ctx.client.get_oracle_key(&ctx.oracle), None);
+}
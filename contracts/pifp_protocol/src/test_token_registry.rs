@@ -0,0 +1,101 @@
+extern crate std;
+
+use soroban_sdk::{Address, Vec};
+
+use crate::{test_utils::TestContext, SupportedToken};
+
+fn unlisted_token(ctx: &TestContext) -> Address {
+    ctx.env
+        .register_stellar_asset_contract_v2(ctx.admin.clone())
+        .address()
+}
+
+#[test]
+fn test_list_supported_tokens() {
+    let ctx = TestContext::new();
+    let (first, _) = ctx.create_token();
+    let (second, _) = ctx.create_token();
+
+    // Re-adding a token updates its settings in place.
+    ctx.client
+        .add_supported_token(&ctx.admin, &first.address, &500, &6);
+    let tokens = ctx.client.list_supported_tokens();
+    assert_eq!(tokens.len(), 2);
+    assert_eq!(
+        tokens.get(0).unwrap(),
+        SupportedToken {
+            token: first.address.clone(),
+            min_donation: 500,
+            decimals: 6,
+        }
+    );
+
+    ctx.client.remove_supported_token(&ctx.admin, &first.address);
+    let tokens = ctx.client.list_supported_tokens();
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens.get(0).unwrap().token, second.address);
+    assert_eq!(ctx.client.get_supported_token(&first.address), None);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #69)")]
+fn test_unlisted_token_cannot_be_accepted() {
+    let ctx = TestContext::new();
+    let tokens = Vec::from_array(&ctx.env, [unlisted_token(&ctx)]);
+    ctx.register_project(&tokens, 1_000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #53)")]
+fn test_registry_min_donation_enforced() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    ctx.client
+        .add_supported_token(&ctx.admin, &token.address, &100, &7);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &99);
+    ctx.client.deposit(&project.id, &donator, &token.address, &99);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #69)")]
+fn test_removed_token_rejects_deposits() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    ctx.client.remove_supported_token(&ctx.admin, &token.address);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+}
+
+#[test]
+fn test_removed_token_stays_refundable() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+
+    ctx.client.remove_supported_token(&ctx.admin, &token.address);
+    ctx.jump_time(86_401);
+    ctx.client.refund(&donator, &project.id, &token.address);
+    assert_eq!(token.balance(&donator), 100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_manager_cannot_add_token() {
+    let ctx = TestContext::new();
+    let token = unlisted_token(&ctx);
+    ctx.client.add_supported_token(&ctx.manager, &token, &0, &7);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #69)")]
+fn test_remove_unlisted_token_rejected() {
+    let ctx = TestContext::new();
+    let token = unlisted_token(&ctx);
+    ctx.client.remove_supported_token(&ctx.admin, &token);
+}
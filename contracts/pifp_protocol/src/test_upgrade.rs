@@ -0,0 +1,178 @@
+extern crate std;
+
+use soroban_sdk::BytesN;
+
+use crate::{
+    storage::{DataKey, ProjectConfigV0, ProjectConfigV1, ProjectStateV0},
+    test_utils::TestContext,
+    DonationLimits, ProjectStatus, StatusCounts,
+};
+
+fn wasm_hash(ctx: &TestContext) -> BytesN<32> {
+    BytesN::from_array(&ctx.env, &[0x42u8; 32])
+}
+
+#[test]
+fn test_propose_upgrade_sets_eta() {
+    let ctx = TestContext::new();
+    let eta = ctx.client.propose_upgrade(&ctx.admin, &wasm_hash(&ctx));
+
+    let pending = ctx.client.get_pending_upgrade().unwrap();
+    assert_eq!(pending.wasm_hash, wasm_hash(&ctx));
+    assert_eq!(pending.eta, eta);
+    assert_eq!(eta, ctx.env.ledger().timestamp() + 7 * 24 * 60 * 60);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_non_super_admin_cannot_propose_upgrade() {
+    let ctx = TestContext::new();
+    ctx.client.propose_upgrade(&ctx.oracle, &wasm_hash(&ctx));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #38)")]
+fn test_second_proposal_rejected_while_pending() {
+    let ctx = TestContext::new();
+    ctx.client.propose_upgrade(&ctx.admin, &wasm_hash(&ctx));
+    ctx.client.propose_upgrade(&ctx.admin, &wasm_hash(&ctx));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #39)")]
+fn test_execute_before_delay_rejected() {
+    let ctx = TestContext::new();
+    ctx.client.propose_upgrade(&ctx.admin, &wasm_hash(&ctx));
+    ctx.jump_time(7 * 24 * 60 * 60 - 1);
+    ctx.client.execute_upgrade(&ctx.admin);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #37)")]
+fn test_cancelled_upgrade_cannot_execute() {
+    let ctx = TestContext::new();
+    ctx.client.propose_upgrade(&ctx.admin, &wasm_hash(&ctx));
+    ctx.client.cancel_upgrade(&ctx.admin);
+    assert_eq!(ctx.client.get_pending_upgrade(), None);
+
+    ctx.jump_time(7 * 24 * 60 * 60);
+    ctx.client.execute_upgrade(&ctx.admin);
+}
+
+#[test]
+fn test_fresh_deployment_is_at_current_schema() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.schema_version(), 2);
+    assert!(ctx.client.migrate(&ctx.admin, &10));
+}
+
+#[test]
+fn test_migrate_upgrades_v0_entries_in_batches() {
+    let ctx = TestContext::new();
+    let (first, _, _) = ctx.setup_project(1_000);
+    let (second, _, _) = ctx.setup_project(1_000);
+
+    // Rewrite both projects in the v0 layout and drop the v1 indexes, as
+    // they would look on a deployment that predates schema versioning.
+    ctx.env.as_contract(&ctx.client.address, || {
+        let storage = ctx.env.storage();
+        for project in [&first, &second] {
+            storage.persistent().set(
+                &DataKey::ProjConfig(project.id),
+                &ProjectConfigV0 {
+                    id: project.id,
+                    creator: project.creator.clone(),
+                    accepted_tokens: project.accepted_tokens.clone(),
+                    goal: project.goal,
+                    proof_hash: project.proof_hash.clone(),
+                    deadline: project.deadline,
+                    is_private: project.is_private,
+                    metadata_uri: project.metadata_uri.clone(),
+                },
+            );
+            storage.persistent().set(
+                &DataKey::ProjState(project.id),
+                &ProjectStateV0 {
+                    status: ProjectStatus::Funding,
+                    donation_count: 0,
+                    refund_expiry: 0,
+                },
+            );
+        }
+        storage.instance().remove(&DataKey::SchemaVersion);
+        storage
+            .instance()
+            .remove(&DataKey::StatusCount(ProjectStatus::Funding));
+        storage
+            .persistent()
+            .remove(&DataKey::CreatorProjectCount(ctx.manager.clone()));
+    });
+    assert_eq!(ctx.client.schema_version(), 0);
+
+    assert!(!ctx.client.migrate(&ctx.admin, &1));
+    assert_eq!(ctx.client.schema_version(), 0);
+    assert!(ctx.client.migrate(&ctx.admin, &1));
+    assert_eq!(ctx.client.schema_version(), 2);
+
+    let migrated = ctx.client.get_project(&second.id);
+    assert_eq!(migrated.milestones.len(), 0);
+    assert_eq!(migrated.released_milestones, 0);
+    assert_eq!(ctx.client.get_creator_project_count(&ctx.manager), 2);
+    assert_eq!(
+        ctx.client.count_by_status(),
+        StatusCounts {
+            funding: 2,
+            active: 0,
+            completed: 0,
+            expired: 0,
+            cancelled: 0,
+            pending_release: 0,
+            failed: 0,
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #40)")]
+fn test_register_blocked_until_migrated() {
+    let ctx = TestContext::new();
+    ctx.env.as_contract(&ctx.client.address, || {
+        ctx.env.storage().instance().remove(&DataKey::SchemaVersion);
+    });
+    ctx.setup_project(1_000);
+}
+
+#[test]
+fn test_migrate_adds_limits_and_merkle_root_to_v1_entries() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+
+    ctx.env.as_contract(&ctx.client.address, || {
+        let storage = ctx.env.storage();
+        storage.persistent().set(
+            &DataKey::ProjConfig(project.id),
+            &ProjectConfigV1 {
+                id: project.id,
+                creator: project.creator.clone(),
+                accepted_tokens: project.accepted_tokens.clone(),
+                goal: project.goal,
+                proof_hash: project.proof_hash.clone(),
+                deadline: project.deadline,
+                is_private: project.is_private,
+                metadata_uri: project.metadata_uri.clone(),
+                milestones: project.milestones.clone(),
+            },
+        );
+        storage.instance().set(&DataKey::SchemaVersion, &1u32);
+    });
+
+    assert!(ctx.client.migrate(&ctx.admin, &10));
+    assert_eq!(ctx.client.schema_version(), 2);
+
+    let migrated = ctx.client.get_project(&project.id);
+    assert_eq!(migrated.limits, DonationLimits::default());
+    assert_eq!(migrated.merkle_root, None);
+    // v1 indexes are left untouched.
+    assert_eq!(ctx.client.get_creator_project_count(&ctx.manager), 1);
+    assert_eq!(ctx.client.count_by_status().funding, 1);
+}
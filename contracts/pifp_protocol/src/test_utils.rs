@@ -5,7 +5,10 @@ use soroban_sdk::{
     token, Address, Bytes, BytesN, Env, Vec,
 };
 
-use crate::{types::Project, PifpProtocol, PifpProtocolClient, Role};
+use crate::{
+    types::{GovAction, Milestone, Project},
+    PifpProtocol, PifpProtocolClient, Role, GOVERNANCE_DELAY,
+};
 
 pub struct TestContext {
     pub env: Env,
@@ -50,6 +53,7 @@ impl TestContext {
         let addr = self
             .env
             .register_stellar_asset_contract_v2(self.admin.clone());
+        self.client.add_supported_token(&self.admin, &addr.address(), &0, &7);
         (
             token::Client::new(&self.env, &addr.address()),
             token::StellarAssetClient::new(&self.env, &addr.address()),
@@ -66,16 +70,23 @@ impl TestContext {
     ) {
         let (token, sac) = self.create_token();
         let tokens = Vec::from_array(&self.env, [token.address.clone()]);
-        let project = self.register_project(&tokens, goal, false);
+        let project = self.register_project(&tokens, goal);
         (project, token, sac)
     }
 
-    pub fn register_project(&self, tokens: &Vec<Address>, goal: i128, is_private: bool) -> Project {
+    pub fn register_project(&self, tokens: &Vec<Address>, goal: i128) -> Project {
+        self.register_project_with_milestones(tokens, goal, &Vec::new(&self.env))
+    }
+
+    pub fn register_project_with_milestones(
+        &self,
+        tokens: &Vec<Address>,
+        goal: i128,
+        milestones: &Vec<Milestone>,
+    ) -> Project {
         let proof_hash = self.dummy_proof();
         let metadata_uri = self.dummy_metadata_uri();
         let deadline = self.env.ledger().timestamp() + 86400;
-        self.client
-            .register_project(&self.manager, tokens, &goal, &proof_hash, &deadline, &is_private)
         self.client.register_project(
             &self.manager,
             tokens,
@@ -83,6 +94,8 @@ impl TestContext {
             &proof_hash,
             &metadata_uri,
             &deadline,
+            &false,
+            milestones,
         )
     }
 
@@ -100,7 +113,21 @@ impl TestContext {
         self.env.ledger().set(ledger);
     }
 
+    pub fn jump_ledgers(&self, ledgers: u32) {
+        let mut ledger = self.env.ledger().get();
+        ledger.sequence_number += ledgers;
+        self.env.ledger().set(ledger);
+    }
+
     pub fn generate_address(&self) -> Address {
         Address::generate(&self.env)
     }
+
+    /// Queue `action` as the SuperAdmin, wait out the governance delay and
+    /// execute it.
+    pub fn govern(&self, action: &GovAction) {
+        let id = self.client.propose_action(&self.admin, action);
+        self.jump_time(GOVERNANCE_DELAY);
+        self.client.execute_proposal(&id);
+    }
 }
@@ -1,94 +1,83 @@
-#![cfg(test)]
+extern crate std;
 
-use crate::test_utils::{create_token, setup_test};
-use crate::{Error, ProjectStatus, Role};
-use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Vec};
+use soroban_sdk::Vec;
+
+use crate::test_utils::TestContext;
+
+fn register_private_project(ctx: &TestContext, tokens: &Vec<soroban_sdk::Address>) -> u64 {
+    let deadline = ctx.env.ledger().timestamp() + 86_400;
+    ctx.client
+        .register_project(
+            &ctx.manager,
+            tokens,
+            &1000,
+            &ctx.dummy_proof(),
+            &ctx.dummy_metadata_uri(),
+            &deadline,
+            &true, // is_private
+            &Vec::new(&ctx.env),
+        )
+        .id
+}
 
 #[test]
 fn test_whitelist_funding_restricted() {
-    let (env, client, admin) = setup_test();
-    let creator = Address::generate(&env);
-    let donor = Address::generate(&env);
-    let token = create_token(&env, &admin);
-    let accepted_tokens = Vec::from_array(&env, [token.address.clone()]);
-    
-    client.grant_role(&admin, &creator, &Role::ProjectManager);
-    
-    // Register a private project
-    let project = client.register_project(
-        &creator,
-        &accepted_tokens,
-        &1000,
-        &[0u8; 32].into(),
-        &(env.ledger().timestamp() + 10000),
-        &true, // is_private
-    );
-    
+    let ctx = TestContext::new();
+    let donor = ctx.generate_address();
+    let (token, sac) = ctx.create_token();
+    let project_id =
+        register_private_project(&ctx, &Vec::from_array(&ctx.env, [token.address.clone()]));
+
     // Attempt deposit from non-whitelisted donor
-    token.mint(&donor, &500);
-    let result = client.try_deposit(&project.id, &donor, &token.address, &500);
-    
+    sac.mint(&donor, &500);
+    let result = ctx
+        .client
+        .try_deposit(&project_id, &donor, &token.address, &500);
+
     assert!(result.is_err());
-    // Error::NotWhitelisted = 26
+    // Error::NotWhitelisted = 72
 }
 
 #[test]
 fn test_whitelist_funding_allowed() {
-    let (env, client, admin) = setup_test();
-    let creator = Address::generate(&env);
-    let donor = Address::generate(&env);
-    let token = create_token(&env, &admin);
-    let accepted_tokens = Vec::from_array(&env, [token.address.clone()]);
-    
-    client.grant_role(&admin, &creator, &Role::ProjectManager);
-    
-    let project = client.register_project(
-        &creator,
-        &accepted_tokens,
-        &1000,
-        &[0u8; 32].into(),
-        &(env.ledger().timestamp() + 10000),
-        &true,
-    );
-    
+    let ctx = TestContext::new();
+    let donor = ctx.generate_address();
+    let (token, sac) = ctx.create_token();
+    let project_id =
+        register_private_project(&ctx, &Vec::from_array(&ctx.env, [token.address.clone()]));
+
     // Add donor to whitelist
-    client.add_to_whitelist(&creator, &project.id, &donor);
-    
+    ctx.client
+        .add_to_whitelist(&ctx.manager, &project_id, &donor);
+
     // Deposit should now work
-    token.mint(&donor, &500);
-    client.deposit(&project.id, &donor, &token.address, &500);
-    
-    let balance = client.get_balance(&project.id, &token.address);
+    sac.mint(&donor, &500);
+    ctx.client
+        .deposit(&project_id, &donor, &token.address, &500);
+
+    let balance = ctx.client.get_balance(&project_id, &token.address);
     assert_eq!(balance, 500);
 }
 
 #[test]
 fn test_whitelist_management_auth() {
-    let (env, client, admin) = setup_test();
-    let creator = Address::generate(&env);
-    let stranger = Address::generate(&env);
-    let donor = Address::generate(&env);
-    let token = create_token(&env, &admin);
-    let accepted_tokens = Vec::from_array(&env, [token.address.clone()]);
-    
-    client.grant_role(&admin, &creator, &Role::ProjectManager);
-    
-    let project = client.register_project(
-        &creator,
-        &accepted_tokens,
-        &1000,
-        &[0u8; 32].into(),
-        &(env.ledger().timestamp() + 10000),
-        &true,
-    );
-    
+    let ctx = TestContext::new();
+    let stranger = ctx.generate_address();
+    let donor = ctx.generate_address();
+    let (token, _) = ctx.create_token();
+    let project_id =
+        register_private_project(&ctx, &Vec::from_array(&ctx.env, [token.address.clone()]));
+
     // Stranger cannot add to whitelist
-    let result = client.try_add_to_whitelist(&stranger, &project.id, &donor);
+    let result = ctx
+        .client
+        .try_add_to_whitelist(&stranger, &project_id, &donor);
     assert!(result.is_err());
-    
+
     // Admin CAN add to whitelist
-    client.add_to_whitelist(&admin, &project.id, &donor);
-    
+    ctx.client.add_to_whitelist(&ctx.admin, &project_id, &donor);
+
     // Creator can remove
-    client.remove_from_whitelist(&creator, &project.id, &donor);
+    ctx.client
+        .remove_from_whitelist(&ctx.manager, &project_id, &donor);
 }
@@ -0,0 +1,93 @@
+extern crate std;
+
+use crate::{test_utils::TestContext, ProjectStatus};
+
+#[test]
+fn test_partial_withdrawal_keeps_donor_counted() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client.deposit(&project.id, &donator, &token.address, &500);
+
+    ctx.client
+        .withdraw_pledge(&donator, &project.id, &token.address, &200);
+
+    assert_eq!(token.balance(&donator), 200);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 300);
+    assert_eq!(ctx.client.get_project(&project.id).donation_count, 1);
+}
+
+#[test]
+fn test_full_withdrawal_decrements_donation_count() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client.deposit(&project.id, &donator, &token.address, &500);
+
+    ctx.client
+        .withdraw_pledge(&donator, &project.id, &token.address, &500);
+
+    assert_eq!(token.balance(&donator), 500);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+    assert_eq!(ctx.client.get_project(&project.id).donation_count, 0);
+
+    // Depositing again counts the donor afresh.
+    ctx.client.deposit(&project.id, &donator, &token.address, &100);
+    assert_eq!(ctx.client.get_project(&project.id).donation_count, 1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_cannot_withdraw_more_than_donated() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client.deposit(&project.id, &donator, &token.address, &300);
+
+    ctx.client
+        .withdraw_pledge(&donator, &project.id, &token.address, &301);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #46)")]
+fn test_withdrawal_locked_near_goal() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &900);
+    ctx.client.deposit(&project.id, &donator, &token.address, &900);
+
+    ctx.client
+        .withdraw_pledge(&donator, &project.id, &token.address, &100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #46)")]
+fn test_withdrawal_locked_once_active() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client.deposit(&project.id, &donator, &token.address, &1_000);
+    assert_eq!(ctx.client.get_project(&project.id).status, ProjectStatus::Active);
+
+    ctx.client
+        .withdraw_pledge(&donator, &project.id, &token.address, &100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #14)")]
+fn test_withdrawal_after_deadline_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client.deposit(&project.id, &donator, &token.address, &500);
+
+    ctx.jump_time(86_401);
+    ctx.client
+        .withdraw_pledge(&donator, &project.id, &token.address, &100);
+}
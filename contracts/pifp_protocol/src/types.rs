@@ -23,13 +23,29 @@
 //!     └──► Expired
 //! Active ──► Expired
 //! Active ──► Cancelled
+//! Funding / Active ──► PendingRelease ──► Completed
+//! Funding / Active ──► Failed
 //! ```
 //!
+//! When a dispute window is configured, a verified release first moves the
+//! project to `PendingRelease`. Finalizing it continues to `Completed` (or
+//! back to `Funding` / `Active` after a non-final milestone); an Auditor
+//! dispute returns it to `Funding` / `Active` for re-verification, or
+//! cancels it so donors can refund.
+//!
+//! Milestone projects stay in `Funding` / `Active` while tranches are released
+//! one by one and only move to `Completed` once the last milestone paid out.
+//!
+//! When the oracle quorum rejects a project's proof, it moves to `Failed` and
+//! donors can refund straight away instead of waiting for the deadline.
+//!
 //! Backward transitions and transitions out of terminal states (`Completed`,
-//! `Expired`, `Cancelled`) are rejected by lifecycle entrypoints.
+//! `Expired`, `Cancelled`, `Failed`) are rejected by lifecycle entrypoints.
 
 use soroban_sdk::{contracttype, Address, Bytes, BytesN, Vec};
 
+use crate::rbac::Role;
+
 /// Current lifecycle state of a funding project.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -45,6 +61,52 @@ pub enum ProjectStatus {
     /// Project was manually cancelled after becoming active.
     /// Remaining donor balances stay refundable.
     Cancelled,
+    /// Proof verified; the release waits out the dispute window.
+    PendingRelease,
+    /// The oracle quorum rejected the proof of impact.
+    /// Remaining donor balances stay refundable.
+    Failed,
+}
+
+/// A single proof-gated tranche of a project's funding.
+///
+/// Milestones are supplied as an ordered list at registration. Each one
+/// carries its own proof hash and the share of the raised balance it unlocks.
+/// The shares of all milestones of a project must sum to exactly 10 000 bps.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Milestone {
+    /// Content hash of the proof artifacts for this milestone.
+    pub proof_hash: BytesN<32>,
+    /// Share of the raised balance released by this milestone, in basis points.
+    pub share_bps: u32,
+}
+
+/// What `deposit` does with a donation that would push a project past its
+/// hard cap.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum OverfundPolicy {
+    /// Reject the whole donation.
+    #[default]
+    Reject,
+    /// Accept the part that fits under the cap; the excess is never taken
+    /// from the donor.
+    RefundExcess,
+}
+
+/// Optional per-project contribution limits. A limit of `0` is not enforced.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DonationLimits {
+    /// Smallest accepted single donation, in the donated token's units.
+    pub min_donation: i128,
+    /// Largest balance a single donor may hold in one accepted token.
+    pub max_per_donor: i128,
+    /// Maximum funded value, in goal units (see `Project::goal`).
+    pub hard_cap: i128,
+    /// Handling of donations that would exceed `hard_cap`.
+    pub overflow: OverfundPolicy,
 }
 
 /// Immutable project configuration, written once at registration.
@@ -62,6 +124,12 @@ pub struct ProjectConfig {
     pub deadline: u64,
     pub is_private: bool,
     pub metadata_uri: Bytes,
+    /// Ordered milestone tranches. Empty for single-proof projects.
+    pub milestones: Vec<Milestone>,
+    /// Contribution limits; set by the creator before the first donation.
+    pub limits: DonationLimits,
+    /// Root of a sha256 Merkle tree of allowlisted donors for private projects.
+    pub merkle_root: Option<BytesN<32>>,
 }
 
 /// Mutable project state, updated on deposits and verification.
@@ -78,6 +146,8 @@ pub struct ProjectState {
     /// when the project transitions to Expired, or `cancel_time + REFUND_WINDOW`
     /// when cancelled.  Zero while the project is still in a non-terminal state.
     pub refund_expiry: u64,
+    /// Bitmask of released milestones: bit `i` is set once milestone `i` paid out.
+    pub released_milestones: u32,
 }
 
 /// Full on-chain representation of a funding project.
@@ -89,14 +159,17 @@ pub struct ProjectState {
 pub struct Project {
     /// Auto-incremented unique ID.
     pub id: u64,
-    /// Address that registered and will receive released funds.
+    /// Address that owns the project. Receives released funds unless the
+    /// project has beneficiaries.
     pub creator: Address,
     /// Ordered list of SAC token addresses this project accepts.
     /// Set once at registration; cannot be changed after creation.
     /// Length: 1–10 tokens.
     pub accepted_tokens: soroban_sdk::Vec<Address>,
     /// Funding goal expressed in the *first* accepted token's units.
-    /// Used as a reference denominator; cross-token goals require off-chain logic.
+    /// Balances of the other accepted tokens count towards it at the
+    /// admin-set conversion rates (see `set_token_rate`); tokens without a
+    /// rate, or all other tokens if the first has none, are not counted.
     pub goal: i128,
     /// Content hash (e.g. IPFS CID digest) of proof artifacts.
     pub proof_hash: soroban_sdk::BytesN<32>,
@@ -114,6 +187,15 @@ pub struct Project {
     /// Ledger timestamp after which donors can no longer refund and the
     /// creator may reclaim unclaimed funds.  Zero while non-terminal.
     pub refund_expiry: u64,
+    /// Ordered milestone tranches. Empty for single-proof projects, which
+    /// release everything through `verify_and_release`.
+    pub milestones: Vec<Milestone>,
+    /// Bitmask of released milestones: bit `i` is set once milestone `i` paid out.
+    pub released_milestones: u32,
+    /// Per-donation, per-donor and hard-cap limits enforced by `deposit`.
+    pub limits: DonationLimits,
+    /// Merkle root of the allowlist accepted by `deposit_with_proof`.
+    pub merkle_root: Option<BytesN<32>>,
 }
 
 impl Project {
@@ -126,6 +208,51 @@ impl Project {
         }
         false
     }
+
+    /// Return `true` if milestone `index` has already been released.
+    pub fn is_milestone_released(&self, index: u32) -> bool {
+        index < 32 && self.released_milestones & (1u32 << index) != 0
+    }
+}
+
+/// One entry of a project's metadata history.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataVersion {
+    /// 0 for the URI set at registration, then incremented on every update.
+    pub version: u32,
+    pub metadata_uri: Bytes,
+    /// Ledger timestamp of the update; 0 for the registration entry.
+    pub updated_at: u64,
+}
+
+/// Non-transferable record of a donor's contributions to one project in
+/// one token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonationReceipt {
+    pub project_id: u64,
+    pub token: Address,
+    /// Total donated in `token`.
+    pub amount: i128,
+    /// Total returned to the donor by refunds and withdrawals; the net
+    /// donation is `amount - refunded`.
+    pub refunded: i128,
+    /// Ledger sequence of the first donation.
+    pub first_ledger: u32,
+    /// Ledger sequence of the most recent donation.
+    pub last_ledger: u32,
+}
+
+/// A donation recorded only behind a hash commitment.
+///
+/// Stored instead of a `DonatorBalance` entry so the donor's address never
+/// appears in contract storage or events until they choose to reveal it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommittedDeposit {
+    pub token: Address,
+    pub amount: i128,
 }
 
 /// Snapshot of all balances for a project — returned by `get_balances`.
@@ -143,6 +270,207 @@ pub struct ProjectBalances {
     pub project_id: u64,
     pub balances: Vec<TokenBalance>,
 }
+
+/// Number of projects in each lifecycle state — returned by `count_by_status`.
+///
+/// Counts reflect stored statuses: a project past its deadline is only
+/// counted as `Expired` once an entry point has lazily expired it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatusCounts {
+    pub funding: u32,
+    pub active: u32,
+    pub completed: u32,
+    pub expired: u32,
+    pub cancelled: u32,
+    pub pending_release: u32,
+    pub failed: u32,
+}
+
+/// A contract upgrade waiting out its timelock.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingUpgrade {
+    /// Hash of the already-uploaded WASM the contract will switch to.
+    pub wasm_hash: BytesN<32>,
+    /// Ledger timestamp from which `execute_upgrade` may be called.
+    pub eta: u64,
+}
+
+/// A verified release waiting out the dispute window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedRelease {
+    /// Milestone to release, or `None` for a single-proof project.
+    pub milestone: Option<u32>,
+    /// Oracle whose attestation completed the quorum.
+    pub oracle: Address,
+    pub proof_hash: BytesN<32>,
+    /// Ledger timestamp from which `finalize_release` may be called.
+    pub release_after: u64,
+    /// Status the project returns to if the release is disputed, or after a
+    /// non-final milestone is released.
+    pub resume_status: ProjectStatus,
+}
+
+/// A quadratic-funding matching round.
+///
+/// Sponsors fund `pool` in `token`; donations in `token` to the eligible
+/// projects between `start` and `end` are matched quadratically when the
+/// round is finalized.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchingRound {
+    pub id: u64,
+    /// Address that created the round; receives any unallocated pool.
+    pub creator: Address,
+    pub token: Address,
+    /// Total sponsor funds available for matching.
+    pub pool: i128,
+    /// Ledger timestamp from which donations are matched.
+    pub start: u64,
+    /// Ledger timestamp at which matching stops and the round can be finalized.
+    pub end: u64,
+    pub project_ids: Vec<u64>,
+    pub finalized: bool,
+}
+
+/// Running quadratic-funding tally of one project in one round.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RoundTally {
+    /// Sum over donors of `isqrt(contribution)`.
+    pub sqrt_sum: i128,
+    /// Sum of all matched contributions.
+    pub total: i128,
+}
+
+/// A recurring donation pulled from the donor's token allowance.
+///
+/// The donor `approve`s the contract as spender; `collect_pledges` then
+/// transfers `amount` once per elapsed `period`, at most `max_periods` times.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecurringPledge {
+    pub id: u64,
+    pub project_id: u64,
+    pub donator: Address,
+    pub token: Address,
+    /// Amount collected per period.
+    pub amount: i128,
+    /// Seconds between instalments.
+    pub period: u64,
+    pub max_periods: u32,
+    /// Instalments collected so far.
+    pub collected_periods: u32,
+    /// Ledger timestamp at which the next instalment becomes collectable.
+    pub next_due: u64,
+    /// Merkle allowlist proof the pledge was created with, re-checked
+    /// against the project's current root on every collection.
+    pub allowlist_proof: Option<Vec<BytesN<32>>>,
+}
+
+/// One recipient's share of the platform fee.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeSplit {
+    pub recipient: Address,
+    /// Share of each fee, in basis points of the fee (not of the payout).
+    pub share_bps: u32,
+}
+
+/// One recipient's share of a project's released funds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Beneficiary {
+    pub recipient: Address,
+    /// Share of each payout after fees, in basis points.
+    pub share_bps: u32,
+}
+
+/// An administrative action that takes effect only after the governance delay.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GovAction {
+    /// Replace the global protocol configuration (fee recipient and fee).
+    UpdateProtocolConfig(ProtocolConfig),
+    /// Grant a role to an address.
+    GrantRole(Address, Role),
+    /// Grant a role to an address until the given ledger sequence.
+    GrantRoleUntil(Address, Role, u32),
+    /// Revoke any role from an address.
+    RevokeRole(Address),
+    /// Pause (`true`) or unpause (`false`) the protocol.
+    SetPaused(bool),
+    /// Replace the fee splits; an empty list sends fees to `fee_recipient`.
+    SetFeeSplits(Vec<FeeSplit>),
+    /// Set (`Some`) or clear (`None`) a project's fee override.
+    SetProjectFee(u64, Option<u32>),
+    /// Set the dispute window in seconds; `0` releases funds immediately.
+    SetDisputeWindow(u64),
+    /// Replace the per-operation pause flags.
+    SetPauseFlags(PauseFlags),
+    /// Set the number of oracle attestations required to release funds.
+    SetOracleQuorum(u32),
+}
+
+/// Per-operation pause switches, set alongside the global pause.
+///
+/// Each flag halts one class of operations on its own; the global pause
+/// still halts everything it did before. Refunds ignore the global pause and
+/// are only halted by `refunds`.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PauseFlags {
+    /// Deposits, committed deposits and reveals, recurring pledges and round
+    /// funding.
+    pub deposits: bool,
+    /// Project registration.
+    pub registrations: bool,
+    /// Attestations, verification and finalization of queued releases.
+    pub releases: bool,
+    /// Donor refunds.
+    pub refunds: bool,
+}
+
+/// An asset in the protocol's token registry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SupportedToken {
+    pub token: Address,
+    /// Smallest amount accepted in a single donation of this token.
+    pub min_donation: i128,
+    /// Number of decimals front-ends use to display amounts.
+    pub decimals: u32,
+}
+
+/// Progress of an unfinished `bump_project` pass over a project's donors,
+/// recurring pledges and committed donations, walked in that order.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BumpProgress {
+    /// Position in the project's donor list where the next batch starts.
+    pub next_donor: u32,
+    /// Position in the project's pledge list where the next batch starts.
+    pub next_pledge: u32,
+    /// Position in the project's commitment list where the next batch starts.
+    pub next_commitment: u32,
+    /// Ledger sequence at which the pass started.
+    pub started_ledger: u32,
+}
+
+/// A queued governance action.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    pub id: u64,
+    pub action: GovAction,
+    /// Address that queued the action; its authority is re-checked on execution.
+    pub proposer: Address,
+    /// Ledger timestamp from which the action may be executed.
+    pub eta: u64,
+}
+
 /// Global protocol configuration managed by the SuperAdmin.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]